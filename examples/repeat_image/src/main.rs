@@ -1,16 +1,14 @@
 use parking_lot::RwLock;
-use std::{collections::HashMap, env, sync::Arc};
+use std::{collections::HashMap, env};
 use telexide_fork::{
     api::types::{SendMessage, SendPhoto},
     model::{MessageContent, UpdateContent},
     prelude::*,
 };
-use typemap::Key as TypeMapKey;
 
-struct HashMapKey;
-impl TypeMapKey for HashMapKey {
-    type Value = Arc<RwLock<HashMap<i64, i64>>>;
-}
+/// the users that asked to repeat their next image, by chat
+#[derive(Default)]
+struct Requests(RwLock<HashMap<i64, i64>>);
 
 #[command(description = "repeat the next image")]
 async fn repeat(context: Context, message: Message) -> CommandResult {
@@ -26,9 +24,7 @@ async fn repeat(context: Context, message: Message) -> CommandResult {
         ))
         .await?;
 
-    let mut guard = context.data.write();
-    let map = guard.get_mut::<HashMapKey>().expect("no hashmap").clone();
-    map.write().insert(
+    context.get::<Requests>().0.write().insert(
         message.chat.get_id(),
         message.from.as_ref().expect("no author").id,
     );
@@ -59,9 +55,8 @@ async fn handle_next(context: Context, update: Update) {
     }
 
     {
-        let mut guard = context.data.write();
-        let maplock = guard.get_mut::<HashMapKey>().expect("no hashmap").clone();
-        let mut map = maplock.write();
+        let requests = context.get::<Requests>();
+        let mut map = requests.0.write();
 
         let key = match map.get(&message.chat.get_id()) {
             Some(u) if *u != message.from.as_ref().expect("no author").id => return,
@@ -97,12 +92,8 @@ async fn main() -> telexide_fork::Result<()> {
         .set_token(&token)
         .set_framework(create_framework!(&bot_name, repeat))
         .add_handler_func(handle_next)
+        .add_extension(Requests::default())
         .build();
 
-    {
-        let mut data = client.data.write();
-        data.insert::<HashMapKey>(Arc::new(RwLock::new(HashMap::new())));
-    }
-
     client.start().await
 }
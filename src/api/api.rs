@@ -128,6 +128,137 @@ pub trait API: Sync {
         self.get(APIEndpoint::GetMyCommands, None).await?.into()
     }
 
+    /// Use this method to get the current list of the bot's commands for the
+    /// given scope and user language. Returns a `Vec<`[`BotCommand`]`>` on
+    /// success. If commands aren't set, an empty list is returned.
+    async fn get_my_commands_with(&self, data: GetMyCommands) -> Result<Vec<BotCommand>> {
+        self.get(
+            APIEndpoint::GetMyCommands,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to delete the list of the bot's commands for the given
+    /// scope and user language. After deletion, higher level commands will be
+    /// shown to affected users. Returns True on success.
+    async fn delete_my_commands(&self, data: DeleteMyCommands) -> Result<bool> {
+        self.post(
+            APIEndpoint::DeleteMyCommands,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change the default administrator rights requested by
+    /// the bot when it's added as an administrator to groups or channels.
+    /// These rights will be suggested to users, but they are free to modify
+    /// the list before adding the bot. Returns True on success.
+    async fn set_my_default_administrator_rights(
+        &self,
+        data: SetMyDefaultAdministratorRights,
+    ) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetMyDefaultAdministratorRights,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get the current default administrator rights of the
+    /// bot. Returns [`ChatAdministratorRights`] on success.
+    async fn get_my_default_administrator_rights(
+        &self,
+        data: GetMyDefaultAdministratorRights,
+    ) -> Result<ChatAdministratorRights> {
+        self.get(
+            APIEndpoint::GetMyDefaultAdministratorRights,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change the bot's name. Returns True on success.
+    async fn set_my_name(&self, data: SetMyName) -> Result<bool> {
+        self.post(APIEndpoint::SetMyName, Some(serde_json::to_value(data)?))
+            .await?
+            .into()
+    }
+
+    /// Use this method to get the current bot name for the given user
+    /// language. Returns [`BotName`] on success.
+    async fn get_my_name(&self, data: GetMyName) -> Result<BotName> {
+        self.get(APIEndpoint::GetMyName, Some(serde_json::to_value(data)?))
+            .await?
+            .into()
+    }
+
+    /// Use this method to change the bot's description, which is shown in the
+    /// chat with the bot if the chat is empty. Returns True on success.
+    async fn set_my_description(&self, data: SetMyDescription) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetMyDescription,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get the current bot description for the given user
+    /// language. Returns [`BotDescription`] on success.
+    async fn get_my_description(&self, data: GetMyDescription) -> Result<BotDescription> {
+        self.get(
+            APIEndpoint::GetMyDescription,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change the bot's short description, which is shown
+    /// on the bot's profile page and is sent together with the link when
+    /// users share the bot. Returns True on success.
+    async fn set_my_short_description(&self, data: SetMyShortDescription) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetMyShortDescription,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get the current bot short description for the given
+    /// user language. Returns [`BotShortDescription`] on success.
+    async fn get_my_short_description(
+        &self,
+        data: GetMyShortDescription,
+    ) -> Result<BotShortDescription> {
+        self.get(
+            APIEndpoint::GetMyShortDescription,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get information about the connection of the bot with
+    /// a business account. Returns a [`BusinessConnection`] object on success.
+    async fn get_business_connection(
+        &self,
+        data: GetBusinessConnection,
+    ) -> Result<BusinessConnection> {
+        self.get(
+            APIEndpoint::GetBusinessConnection,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to forward messages of any kind. On success, the sent
     /// [`Message`] is returned.
     async fn forward_message(&self, data: ForwardMessage) -> Result<Message> {
@@ -335,6 +466,28 @@ pub trait API: Sync {
         .into()
     }
 
+    /// Use this method to send paid media, which can only be viewed after
+    /// paying the given amount of Telegram Stars. On success, the sent
+    /// [`Message`] is returned.
+    async fn send_paid_media(&self, data: SendPaidMedia) -> Result<Message> {
+        let mut files = Vec::new();
+        for media in &data.media {
+            if let InputFile::File(f) = media.get_media() {
+                files.push(f.clone());
+            }
+        }
+
+        files.dedup_by(|f1, f2| f1 == f2);
+
+        self.post_file(
+            APIEndpoint::SendPaidMedia,
+            Some(serde_json::to_value(&data)?),
+            Some(files),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to send a point on the map. On success, the sent
     /// [`Message`] is returned.
     async fn send_location(&self, data: SendLocation) -> Result<Message> {
@@ -679,6 +832,30 @@ pub trait API: Sync {
         .into()
     }
 
+    /// Use this method to approve a chat join request. The bot must be an
+    /// administrator in the chat for this to work and must have the
+    /// `can_invite_users` administrator right. Returns True on success.
+    async fn approve_chat_join_request(&self, data: ApproveChatJoinRequest) -> Result<bool> {
+        self.post(
+            APIEndpoint::ApproveChatJoinRequest,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to decline a chat join request. The bot must be an
+    /// administrator in the chat for this to work and must have the
+    /// `can_invite_users` administrator right. Returns True on success.
+    async fn decline_chat_join_request(&self, data: DeclineChatJoinRequest) -> Result<bool> {
+        self.post(
+            APIEndpoint::DeclineChatJoinRequest,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to set a new profile photo for the chat. Photos can't be
     /// changed for private chats. The bot must be an administrator in the
     /// chat for this to work and must have the appropriate admin rights.
@@ -867,6 +1044,190 @@ pub trait API: Sync {
         .into()
     }
 
+    /// Use this method to create a topic in a forum supergroup chat. The bot
+    /// must be an administrator in the chat for this to work and must have the
+    /// `can_manage_topics` administrator rights. Returns information about the
+    /// created topic as a [`ForumTopic`] object.
+    async fn create_forum_topic(&self, data: CreateForumTopic) -> Result<ForumTopic> {
+        self.post(
+            APIEndpoint::CreateForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to edit name and icon of a topic in a forum supergroup
+    /// chat. The bot must be an administrator in the chat for this to work and
+    /// must have `can_manage_topics` administrator rights, unless it is the
+    /// creator of the topic. Returns True on success.
+    async fn edit_forum_topic(&self, data: EditForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::EditForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to close an open topic in a forum supergroup chat. The
+    /// bot must be an administrator in the chat for this to work and must have
+    /// the `can_manage_topics` administrator rights, unless it is the creator of
+    /// the topic. Returns True on success.
+    async fn close_forum_topic(&self, data: CloseForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::CloseForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to reopen a closed topic in a forum supergroup chat. The
+    /// bot must be an administrator in the chat for this to work and must have
+    /// the `can_manage_topics` administrator rights, unless it is the creator of
+    /// the topic. Returns True on success.
+    async fn reopen_forum_topic(&self, data: ReopenForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::ReopenForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to delete a forum topic along with all its messages in
+    /// a forum supergroup chat. The bot must be an administrator in the chat
+    /// for this to work and must have the `can_delete_messages` administrator
+    /// rights. Returns True on success.
+    async fn delete_forum_topic(&self, data: DeleteForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::DeleteForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to clear the list of pinned messages in a forum topic.
+    /// The bot must be an administrator in the chat for this to work and must
+    /// have the `can_pin_messages` administrator right in the supergroup.
+    /// Returns True on success.
+    async fn unpin_all_forum_topic_messages(
+        &self,
+        data: UnpinAllForumTopicMessages,
+    ) -> Result<bool> {
+        self.post(
+            APIEndpoint::UnpinAllForumTopicMessages,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to edit the name of the 'General' topic in a forum
+    /// supergroup chat. The bot must be an administrator in the chat for this
+    /// to work and must have `can_manage_topics` administrator rights. Returns
+    /// True on success.
+    async fn edit_general_forum_topic(&self, data: EditGeneralForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::EditGeneralForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to close an open 'General' topic in a forum supergroup
+    /// chat. The bot must be an administrator in the chat for this to work and
+    /// must have the `can_manage_topics` administrator rights. Returns True on
+    /// success.
+    async fn close_general_forum_topic(&self, data: CloseGeneralForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::CloseGeneralForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to reopen a closed 'General' topic in a forum
+    /// supergroup chat. The bot must be an administrator in the chat for this
+    /// to work and must have the `can_manage_topics` administrator rights. The
+    /// topic will be automatically unhidden if it was hidden. Returns True on
+    /// success.
+    async fn reopen_general_forum_topic(&self, data: ReopenGeneralForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::ReopenGeneralForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to hide the 'General' topic in a forum supergroup chat.
+    /// The bot must be an administrator in the chat for this to work and must
+    /// have the `can_manage_topics` administrator rights. The topic will be
+    /// automatically closed if it was open. Returns True on success.
+    async fn hide_general_forum_topic(&self, data: HideGeneralForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::HideGeneralForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to unhide the 'General' topic in a forum supergroup
+    /// chat. The bot must be an administrator in the chat for this to work and
+    /// must have the `can_manage_topics` administrator rights. Returns True on
+    /// success.
+    async fn unhide_general_forum_topic(&self, data: UnhideGeneralForumTopic) -> Result<bool> {
+        self.post(
+            APIEndpoint::UnhideGeneralForumTopic,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to clear the list of pinned messages in a 'General'
+    /// forum topic. The bot must be an administrator in the chat for this to
+    /// work and must have the `can_pin_messages` administrator right in the
+    /// supergroup. Returns True on success.
+    async fn unpin_all_general_forum_topic_messages(
+        &self,
+        data: UnpinAllGeneralForumTopicMessages,
+    ) -> Result<bool> {
+        self.post(
+            APIEndpoint::UnpinAllGeneralForumTopicMessages,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get custom emoji stickers, which can be used as a
+    /// forum topic icon by any user. Returns a Vec of [`Sticker`] objects.
+    async fn get_forum_topic_icon_stickers(&self) -> Result<Vec<Sticker>> {
+        self.get(APIEndpoint::GetForumTopicIconStickers, None)
+            .await?
+            .into()
+    }
+
+    /// Use this method to change the chosen reactions on a message. Service
+    /// messages can't be reacted to. Automatically forwarded messages from a
+    /// channel to its discussion group have the same available reactions as
+    /// messages in the channel. Returns True on success.
+    async fn set_message_reaction(&self, data: SetMessageReaction) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetMessageReaction,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to send answers to callback queries sent from [inline keyboards](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating).
     /// The answer will be displayed to the user as a notification at the top of
     /// the chat screen or as an alert. On success, True is returned.
@@ -879,8 +1240,8 @@ pub trait API: Sync {
         .into()
     }
 
-    /// Use this method to send static .WEBP or animated .TGS stickers. On
-    /// success, the sent [Message] is returned.
+    /// Use this method to send static .WEBP, animated .TGS, or video .WEBM
+    /// stickers. On success, the sent [Message] is returned.
     async fn send_sticker(&self, data: SendSticker) -> Result<Message> {
         match &data.sticker {
             InputFile::String(_) => self
@@ -909,11 +1270,30 @@ pub trait API: Sync {
         .into()
     }
 
-    /// Use this method to upload a .PNG file with a sticker for later use in
-    /// createNewStickerSet and addStickerToSet methods (can be used
-    /// multiple times). Returns the uploaded [File] on success.
+    /// Use this method to get information about custom emoji stickers by their
+    /// identifiers. Returns a Vec of [`Sticker`] objects.
+    async fn get_custom_emoji_stickers(
+        &self,
+        data: GetCustomEmojiStickers,
+    ) -> Result<Vec<Sticker>> {
+        self.post(
+            APIEndpoint::GetCustomEmojiStickers,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to upload a file with a sticker for later use in the
+    /// [`create_new_sticker_set`], [`add_sticker_to_set`] and
+    /// [`replace_sticker_in_set`] methods (the file can be used multiple
+    /// times). Returns the uploaded [File] on success.
+    ///
+    /// [`create_new_sticker_set`]: #method.create_new_sticker_set
+    /// [`add_sticker_to_set`]: #method.add_sticker_to_set
+    /// [`replace_sticker_in_set`]: #method.replace_sticker_in_set
     async fn upload_sticker_file(&self, data: UploadStickerFile) -> Result<File> {
-        match &data.png_sticker {
+        match &data.sticker {
             InputFile::File(f) => self
                 .post_file(
                     APIEndpoint::UploadStickerFile,
@@ -931,37 +1311,23 @@ pub trait API: Sync {
 
     /// Use this method to create a new sticker set owned by a user.
     /// The bot will be able to edit the sticker set thus created.
-    /// You must use exactly one of the fields png_sticker or tgs_sticker.
     /// Returns True on success.
     async fn create_new_sticker_set(&self, data: CreateNewStickerSet) -> Result<bool> {
-        if (data.png_sticker.is_some() && data.tgs_sticker.is_some())
-            || (data.png_sticker.is_none() && data.tgs_sticker.is_none())
-        {
+        if data.stickers.is_empty() || data.stickers.len() > 50 {
             return Err(TelegramError::InvalidArgument(
-                "You must use exactly one of the fields png_sticker or tgs_sticker".to_owned(),
+                "A sticker set has to be created with 1-50 stickers".to_owned(),
             )
             .into());
         }
 
         let mut files = Vec::new();
-
-        if data.png_sticker.is_some() {
-            if let InputFile::File(f) = data.png_sticker.as_ref().unwrap() {
+        for sticker in &data.stickers {
+            if let InputFile::File(f) = &sticker.sticker {
                 files.push(f.clone());
             }
         }
 
-        if data.tgs_sticker.is_some() {
-            match data.tgs_sticker.as_ref().unwrap() {
-                InputFile::File(f) => files.push(f.clone()),
-                InputFile::String(_) => {
-                    return Err(TelegramError::InvalidArgument(
-                        "tgs_sticker only accepts files, not urls/ids".to_owned(),
-                    )
-                    .into())
-                }
-            }
-        }
+        files.dedup_by(|f1, f2| f1 == f2);
 
         self.post_file(
             APIEndpoint::CreateNewStickerSet,
@@ -973,44 +1339,41 @@ pub trait API: Sync {
     }
 
     /// Use this method to add a new sticker to a set created by the bot.
-    /// You must use exactly one of the fields png_sticker or tgs_sticker.
-    /// Animated stickers can be added to animated sticker sets and only to
-    /// them. Animated sticker sets can have up to 50 stickers. Static
-    /// sticker sets can have up to 120 stickers. Returns True on success.
+    /// Emoji sticker sets can have up to 200 stickers. Other sticker sets can
+    /// have up to 120 stickers. Returns True on success.
     async fn add_sticker_to_set(&self, data: AddStickerToSet) -> Result<bool> {
-        if (data.png_sticker.is_some() && data.tgs_sticker.is_some())
-            || (data.png_sticker.is_none() && data.tgs_sticker.is_none())
-        {
-            return Err(TelegramError::InvalidArgument(
-                "You must use exactly one of the fields png_sticker or tgs_sticker.".to_owned(),
-            )
-            .into());
-        }
+        let files = match &data.sticker.sticker {
+            InputFile::File(f) => Some(vec![f.clone()]),
+            InputFile::String(_) => None,
+        };
 
-        let mut files = Vec::new();
-
-        if data.png_sticker.is_some() {
-            if let InputFile::File(f) = data.png_sticker.as_ref().unwrap() {
-                files.push(f.clone());
-            }
-        }
+        self.post_file(
+            APIEndpoint::AddStickerToSet,
+            Some(serde_json::to_value(&data)?),
+            files,
+        )
+        .await?
+        .into()
+    }
 
-        if data.tgs_sticker.is_some() {
-            match data.tgs_sticker.as_ref().unwrap() {
-                InputFile::File(f) => files.push(f.clone()),
-                InputFile::String(_) => {
-                    return Err(TelegramError::InvalidArgument(
-                        "tgs_sticker only accepts files, not urls/ids.".to_owned(),
-                    )
-                    .into())
-                }
-            }
-        }
+    /// Use this method to replace an existing sticker in a sticker set with a
+    /// new one. The method is equivalent to calling [`delete_sticker_from_set`],
+    /// then [`add_sticker_to_set`], then [`set_sticker_position_in_set`].
+    /// Returns True on success.
+    ///
+    /// [`delete_sticker_from_set`]: #method.delete_sticker_from_set
+    /// [`add_sticker_to_set`]: #method.add_sticker_to_set
+    /// [`set_sticker_position_in_set`]: #method.set_sticker_position_in_set
+    async fn replace_sticker_in_set(&self, data: ReplaceStickerInSet) -> Result<bool> {
+        let files = match &data.sticker.sticker {
+            InputFile::File(f) => Some(vec![f.clone()]),
+            InputFile::String(_) => None,
+        };
 
         self.post_file(
-            APIEndpoint::AddStickerToSet,
+            APIEndpoint::ReplaceStickerInSet,
             Some(serde_json::to_value(&data)?),
-            Some(files),
+            files,
         )
         .await?
         .into()
@@ -1038,21 +1401,68 @@ pub trait API: Sync {
         .into()
     }
 
-    /// Use this method to set the thumbnail of a sticker set.
-    /// Animated thumbnails can be set for animated sticker sets only. Returns
-    /// True on success.
-    async fn set_sticker_set_thumb(&self, data: SetStickerSetThumb) -> Result<bool> {
-        match &data.thumb {
+    /// Use this method to change the list of emoji assigned to a regular or
+    /// custom emoji sticker. The sticker must belong to a sticker set created
+    /// by the bot. Returns True on success.
+    async fn set_sticker_emoji_list(&self, data: SetStickerEmojiList) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetStickerEmojiList,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change search keywords assigned to a regular or
+    /// custom emoji sticker. The sticker must belong to a sticker set created
+    /// by the bot. Returns True on success.
+    async fn set_sticker_keywords(&self, data: SetStickerKeywords) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetStickerKeywords,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change the mask position of a mask sticker. The
+    /// sticker must belong to a sticker set that was created by the bot.
+    /// Returns True on success.
+    async fn set_sticker_mask_position(&self, data: SetStickerMaskPosition) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetStickerMaskPosition,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to set the title of a created sticker set. Returns True
+    /// on success.
+    async fn set_sticker_set_title(&self, data: SetStickerSetTitle) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetStickerSetTitle,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to set the thumbnail of a regular or mask sticker set.
+    /// The format of the thumbnail file must match the format of the stickers
+    /// in the set. Returns True on success.
+    async fn set_sticker_set_thumbnail(&self, data: SetStickerSetThumbnail) -> Result<bool> {
+        match &data.thumbnail {
             Some(InputFile::String(_)) | None => self
                 .post(
-                    APIEndpoint::SetStickerSetThumb,
+                    APIEndpoint::SetStickerSetThumbnail,
                     Some(serde_json::to_value(&data)?),
                 )
                 .await?
                 .into(),
             Some(InputFile::File(f)) => self
                 .post_file(
-                    APIEndpoint::SetStickerSetThumb,
+                    APIEndpoint::SetStickerSetThumbnail,
                     Some(serde_json::to_value(&data)?),
                     Some(vec![f.clone()]),
                 )
@@ -1061,6 +1471,28 @@ pub trait API: Sync {
         }
     }
 
+    /// Use this method to set the thumbnail of a sticker set, it calls
+    /// [`set_sticker_set_thumbnail`] with the data converted into a
+    /// [`SetStickerSetThumbnail`].
+    ///
+    /// [`set_sticker_set_thumbnail`]: #method.set_sticker_set_thumbnail
+    #[deprecated(note = "use `set_sticker_set_thumbnail`")]
+    #[allow(deprecated)]
+    async fn set_sticker_set_thumb(&self, data: SetStickerSetThumb) -> Result<bool> {
+        self.set_sticker_set_thumbnail(data.into()).await
+    }
+
+    /// Use this method to delete a sticker set that was created by the bot.
+    /// Returns True on success.
+    async fn delete_sticker_set(&self, data: DeleteStickerSet) -> Result<bool> {
+        self.post(
+            APIEndpoint::DeleteStickerSet,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to send answers to an inline query. On success, True is
     /// returned. No more than 50 results per query are allowed.
     async fn answer_inline_query(&self, data: AnswerInlineQuery) -> Result<bool> {
@@ -1079,6 +1511,44 @@ pub trait API: Sync {
         .into()
     }
 
+    /// Use this method to set the result of an interaction with a [Web App]
+    /// and send a corresponding message on behalf of the user to the chat from
+    /// which the query originated. On success, a [`SentWebAppMessage`] object
+    /// is returned.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    async fn answer_web_app_query(&self, data: AnswerWebAppQuery) -> Result<SentWebAppMessage> {
+        self.post(
+            APIEndpoint::AnswerWebAppQuery,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to change the bot's menu button in a private chat, or
+    /// the default menu button. Returns True on success.
+    async fn set_chat_menu_button(&self, data: SetChatMenuButton) -> Result<bool> {
+        self.post(
+            APIEndpoint::SetChatMenuButton,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Use this method to get the current value of the bot's menu button in a
+    /// private chat, or the default menu button. Returns [`MenuButton`] on
+    /// success.
+    async fn get_chat_menu_button(&self, data: GetChatMenuButton) -> Result<MenuButton> {
+        self.get(
+            APIEndpoint::GetChatMenuButton,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to send invoices. On success, the sent [Message] is
     /// returned.
     async fn send_invoice(&self, data: SendInvoice) -> Result<Message> {
@@ -1115,6 +1585,41 @@ pub trait API: Sync {
         .into()
     }
 
+    /// Use this method to create a link for an invoice. Returns the created
+    /// invoice link as String on success.
+    async fn create_invoice_link(&self, data: CreateInvoiceLink) -> Result<String> {
+        self.post(
+            APIEndpoint::CreateInvoiceLink,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Refunds a successful payment in [Telegram Stars]. Returns True on
+    /// success.
+    ///
+    /// [Telegram Stars]: https://t.me/BotNews/90
+    async fn refund_star_payment(&self, data: RefundStarPayment) -> Result<bool> {
+        self.post(
+            APIEndpoint::RefundStarPayment,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
+    /// Returns the bot's Telegram Star transactions in chronological order. On
+    /// success, returns a [`StarTransactions`] object.
+    async fn get_star_transactions(&self, data: GetStarTransactions) -> Result<StarTransactions> {
+        self.get(
+            APIEndpoint::GetStarTransactions,
+            Some(serde_json::to_value(data)?),
+        )
+        .await?
+        .into()
+    }
+
     /// Use this method to send a game. On success, the sent [Message] is
     /// returned.
     async fn send_game(&self, data: SendGame) -> Result<Message> {
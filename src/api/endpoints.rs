@@ -10,6 +10,16 @@ pub enum APIEndpoint {
     SendMessage,
     SetMyCommands,
     GetMyCommands,
+    DeleteMyCommands,
+    SetMyDefaultAdministratorRights,
+    GetMyDefaultAdministratorRights,
+    SetMyName,
+    GetMyName,
+    SetMyDescription,
+    GetMyDescription,
+    SetMyShortDescription,
+    GetMyShortDescription,
+    GetBusinessConnection,
     ForwardMessage,
     CopyMessage,
     SendPhoto,
@@ -20,6 +30,7 @@ pub enum APIEndpoint {
     SendVoice,
     SendVideoNote,
     SendMediaGroup,
+    SendPaidMedia,
     SendLocation,
     EditMessageLiveLocation,
     StopMessageLiveLocation,
@@ -40,6 +51,8 @@ pub enum APIEndpoint {
     CreateChatInviteLink,
     EditChatInviteLink,
     RevokeChatInviteLink,
+    ApproveChatJoinRequest,
+    DeclineChatJoinRequest,
     SetChatPhoto,
     DeleteChatPhoto,
     SetChatTitle,
@@ -54,6 +67,23 @@ pub enum APIEndpoint {
     GetChatMember,
     SetChatStickerSet,
     DeleteChatStickerSet,
+    CreateForumTopic,
+    EditForumTopic,
+    CloseForumTopic,
+    ReopenForumTopic,
+    DeleteForumTopic,
+    UnpinAllForumTopicMessages,
+    EditGeneralForumTopic,
+    CloseGeneralForumTopic,
+    ReopenGeneralForumTopic,
+    HideGeneralForumTopic,
+    UnhideGeneralForumTopic,
+    UnpinAllGeneralForumTopicMessages,
+    GetForumTopicIconStickers,
+    SetMessageReaction,
+    AnswerWebAppQuery,
+    SetChatMenuButton,
+    GetChatMenuButton,
     AnswerCallbackQuery,
     EditMessageText,
     EditMessageCaption,
@@ -63,16 +93,26 @@ pub enum APIEndpoint {
     DeleteMessage,
     SendSticker,
     GetStickerSet,
+    GetCustomEmojiStickers,
     UploadStickerFile,
     CreateNewStickerSet,
     AddStickerToSet,
     SetStickerPositionInSet,
     DeleteStickerFromSet,
-    SetStickerSetThumb,
+    ReplaceStickerInSet,
+    SetStickerEmojiList,
+    SetStickerKeywords,
+    SetStickerMaskPosition,
+    SetStickerSetTitle,
+    SetStickerSetThumbnail,
+    DeleteStickerSet,
     AnswerInlineQuery,
     SendInvoice,
     AnswerShippingQuery,
     AnswerPreCheckoutQuery,
+    CreateInvoiceLink,
+    RefundStarPayment,
+    GetStarTransactions,
     SendGame,
     SetGameScore,
     GetGameHighScores,
@@ -84,6 +124,7 @@ pub enum APIEndpoint {
 }
 
 impl APIEndpoint {
+    #[allow(clippy::too_many_lines)]
     pub fn as_str(&self) -> &str {
         match *self {
             Self::GetUpdates => "getUpdates",
@@ -93,6 +134,16 @@ impl APIEndpoint {
             Self::SendMessage => "sendMessage",
             Self::SetMyCommands => "setMyCommands",
             Self::GetMyCommands => "getMyCommands",
+            Self::DeleteMyCommands => "deleteMyCommands",
+            Self::SetMyDefaultAdministratorRights => "setMyDefaultAdministratorRights",
+            Self::GetMyDefaultAdministratorRights => "getMyDefaultAdministratorRights",
+            Self::SetMyName => "setMyName",
+            Self::GetMyName => "getMyName",
+            Self::SetMyDescription => "setMyDescription",
+            Self::GetMyDescription => "getMyDescription",
+            Self::SetMyShortDescription => "setMyShortDescription",
+            Self::GetMyShortDescription => "getMyShortDescription",
+            Self::GetBusinessConnection => "getBusinessConnection",
             Self::CopyMessage => "copyMessage",
             Self::ForwardMessage => "forwardMessage",
             Self::SendPhoto => "sendPhoto",
@@ -103,6 +154,7 @@ impl APIEndpoint {
             Self::SendVoice => "sendVoice",
             Self::SendVideoNote => "sendVideoNote",
             Self::SendMediaGroup => "sendMediaGroup",
+            Self::SendPaidMedia => "sendPaidMedia",
             Self::SendLocation => "sendLocation",
             Self::EditMessageLiveLocation => "editMessageLiveLocation",
             Self::StopMessageLiveLocation => "stopMessageLiveLocation",
@@ -123,6 +175,8 @@ impl APIEndpoint {
             Self::CreateChatInviteLink => "createChatInviteLink",
             Self::EditChatInviteLink => "editChatInviteLink",
             Self::RevokeChatInviteLink => "revokeChatInviteLink",
+            Self::ApproveChatJoinRequest => "approveChatJoinRequest",
+            Self::DeclineChatJoinRequest => "declineChatJoinRequest",
             Self::SetChatPhoto => "setChatPhoto",
             Self::DeleteChatPhoto => "deleteChatPhoto",
             Self::SetChatTitle => "setChatTitle",
@@ -137,6 +191,23 @@ impl APIEndpoint {
             Self::GetChatMember => "getChatMember",
             Self::SetChatStickerSet => "setChatStickerSet",
             Self::DeleteChatStickerSet => "deleteChatStickerSet",
+            Self::CreateForumTopic => "createForumTopic",
+            Self::EditForumTopic => "editForumTopic",
+            Self::CloseForumTopic => "closeForumTopic",
+            Self::ReopenForumTopic => "reopenForumTopic",
+            Self::DeleteForumTopic => "deleteForumTopic",
+            Self::UnpinAllForumTopicMessages => "unpinAllForumTopicMessages",
+            Self::EditGeneralForumTopic => "editGeneralForumTopic",
+            Self::CloseGeneralForumTopic => "closeGeneralForumTopic",
+            Self::ReopenGeneralForumTopic => "reopenGeneralForumTopic",
+            Self::HideGeneralForumTopic => "hideGeneralForumTopic",
+            Self::UnhideGeneralForumTopic => "unhideGeneralForumTopic",
+            Self::UnpinAllGeneralForumTopicMessages => "unpinAllGeneralForumTopicMessages",
+            Self::GetForumTopicIconStickers => "getForumTopicIconStickers",
+            Self::SetMessageReaction => "setMessageReaction",
+            Self::AnswerWebAppQuery => "answerWebAppQuery",
+            Self::SetChatMenuButton => "setChatMenuButton",
+            Self::GetChatMenuButton => "getChatMenuButton",
             Self::AnswerCallbackQuery => "answerCallbackQuery",
             Self::EditMessageText => "editMessageText",
             Self::EditMessageCaption => "editMessageCaption",
@@ -146,12 +217,19 @@ impl APIEndpoint {
             Self::DeleteMessage => "deleteMessage",
             Self::SendSticker => "sendSticker",
             Self::GetStickerSet => "getStickerSet",
+            Self::GetCustomEmojiStickers => "getCustomEmojiStickers",
             Self::UploadStickerFile => "uploadStickerFile",
             Self::CreateNewStickerSet => "createNewStickerSet",
             Self::AddStickerToSet => "addStickerToSet",
             Self::SetStickerPositionInSet => "setStickerPositionInSet",
             Self::DeleteStickerFromSet => "deleteStickerFromSet",
-            Self::SetStickerSetThumb => "setStickerSetThumb",
+            Self::ReplaceStickerInSet => "replaceStickerInSet",
+            Self::SetStickerEmojiList => "setStickerEmojiList",
+            Self::SetStickerKeywords => "setStickerKeywords",
+            Self::SetStickerMaskPosition => "setStickerMaskPosition",
+            Self::SetStickerSetTitle => "setStickerSetTitle",
+            Self::SetStickerSetThumbnail => "setStickerSetThumbnail",
+            Self::DeleteStickerSet => "deleteStickerSet",
             Self::AnswerInlineQuery => "answerInlineQuery",
             Self::SendGame => "sendGame",
             Self::SetGameScore => "setGameScore",
@@ -159,6 +237,9 @@ impl APIEndpoint {
             Self::SendInvoice => "sendInvoice",
             Self::AnswerShippingQuery => "answerShippingQuery",
             Self::AnswerPreCheckoutQuery => "answerPreCheckoutQuery",
+            Self::CreateInvoiceLink => "createInvoiceLink",
+            Self::RefundStarPayment => "refundStarPayment",
+            Self::GetStarTransactions => "getStarTransactions",
             Self::SetWebhook => "setWebHook",
             Self::SetPassportDataErrors => "setPassportDataErrors",
             Self::DeleteWebhook => "deleteWebhook",
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// struct for holding data needed to call
+/// [`get_business_connection`]
+///
+/// [`get_business_connection`]:
+/// ../../api/trait.API.html#method.get_business_connection
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GetBusinessConnection {
+    /// Unique identifier of the business connection
+    pub business_connection_id: String,
+}
+
+impl GetBusinessConnection {
+    /// function to create a new `GetBusinessConnection` object
+    pub fn new(business_connection_id: &str) -> Self {
+        Self {
+            business_connection_id: business_connection_id.to_owned(),
+        }
+    }
+}
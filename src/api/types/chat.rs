@@ -1,5 +1,5 @@
 use super::InputFile;
-use crate::model::{utils::unix_date_formatting, Chat, ChatPermissions};
+use crate::model::{utils::unix_date_formatting, Chat, ChatJoinRequest, ChatPermissions};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -364,6 +364,13 @@ pub struct CreateChatInviteLink {
     /// Maximum number of users that can be members of the chat simultaneously
     /// after joining the chat via this invite link; 1-99999
     pub member_limit: Option<i32>,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// True, if users joining the chat via the link need to be approved by
+    /// chat administrators. If True, `member_limit` can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
 }
 
 /// struct for holding data needed to call [`edit_chat_invite_link`]
@@ -381,6 +388,13 @@ pub struct EditChatInviteLink {
     /// Maximum number of users that can be members of the chat simultaneously
     /// after joining the chat via this invite link; 1-99999
     pub member_limit: Option<i32>,
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// True, if users joining the chat via the link need to be approved by
+    /// chat administrators. If True, `member_limit` can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
 }
 
 /// struct for holding data needed to call [`revoke_chat_invite_link`]
@@ -395,6 +409,224 @@ pub struct RevokeChatInviteLink {
     pub invite_link: String,
 }
 
+/// struct for holding data needed to call [`approve_chat_join_request`]
+///
+/// [`approve_chat_join_request`]:
+/// ../../api/trait.API.html#method.approve_chat_join_request
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ApproveChatJoinRequest {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// struct for holding data needed to call [`decline_chat_join_request`]
+///
+/// [`decline_chat_join_request`]:
+/// ../../api/trait.API.html#method.decline_chat_join_request
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeclineChatJoinRequest {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`create_forum_topic`]
+///
+/// [`create_forum_topic`]:
+/// ../../api/trait.API.html#method.create_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CreateForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Topic name, 1-128 characters
+    pub name: String,
+    /// Color of the topic icon in RGB format. Currently, must be one of
+    /// 7322096 (0x6FB9F0), 16766590 (0xFFD67E), 13338331 (0xCB86DB), 9367192
+    /// (0x8EEE98), 16749490 (0xFF93B2), or 16478047 (0xFB6F5F)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<i64>,
+    /// Unique identifier of the custom emoji shown as the topic icon. Use
+    /// [`get_forum_topic_icon_stickers`] to get all allowed custom emoji
+    /// identifiers.
+    ///
+    /// [`get_forum_topic_icon_stickers`]:
+    /// ../../api/trait.API.html#method.get_forum_topic_icon_stickers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+impl CreateForumTopic {
+    /// function to create a new `CreateForumTopic` object, setting all
+    /// optional fields to None
+    pub fn new(chat_id: i64, name: &str) -> Self {
+        Self {
+            chat_id,
+            name: name.to_owned(),
+            icon_color: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+}
+
+/// struct for holding data needed to call
+/// [`edit_forum_topic`]
+///
+/// [`edit_forum_topic`]:
+/// ../../api/trait.API.html#method.edit_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EditForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+    /// New topic name, 0-128 characters. If not specified or empty, the
+    /// current name of the topic will be kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New unique identifier of the custom emoji shown as the topic icon. Pass
+    /// an empty string to remove the icon. If not specified, the current icon
+    /// will be kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+impl EditForumTopic {
+    /// function to create a new `EditForumTopic` object, setting all
+    /// optional fields to None
+    pub fn new(chat_id: i64, message_thread_id: i64) -> Self {
+        Self {
+            chat_id,
+            message_thread_id,
+            name: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+}
+
+/// struct for holding data needed to call
+/// [`close_forum_topic`]
+///
+/// [`close_forum_topic`]:
+/// ../../api/trait.API.html#method.close_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CloseForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`reopen_forum_topic`]
+///
+/// [`reopen_forum_topic`]:
+/// ../../api/trait.API.html#method.reopen_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReopenForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`delete_forum_topic`]
+///
+/// [`delete_forum_topic`]:
+/// ../../api/trait.API.html#method.delete_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeleteForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`unpin_all_forum_topic_messages`]
+///
+/// [`unpin_all_forum_topic_messages`]:
+/// ../../api/trait.API.html#method.unpin_all_forum_topic_messages
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnpinAllForumTopicMessages {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread of the forum topic
+    pub message_thread_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`edit_general_forum_topic`]
+///
+/// [`edit_general_forum_topic`]:
+/// ../../api/trait.API.html#method.edit_general_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EditGeneralForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// New topic name, 1-128 characters
+    pub name: String,
+}
+
+/// struct for holding data needed to call
+/// [`close_general_forum_topic`]
+///
+/// [`close_general_forum_topic`]:
+/// ../../api/trait.API.html#method.close_general_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CloseGeneralForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`reopen_general_forum_topic`]
+///
+/// [`reopen_general_forum_topic`]:
+/// ../../api/trait.API.html#method.reopen_general_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReopenGeneralForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`hide_general_forum_topic`]
+///
+/// [`hide_general_forum_topic`]:
+/// ../../api/trait.API.html#method.hide_general_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HideGeneralForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`unhide_general_forum_topic`]
+///
+/// [`unhide_general_forum_topic`]:
+/// ../../api/trait.API.html#method.unhide_general_forum_topic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnhideGeneralForumTopic {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+}
+
+/// struct for holding data needed to call
+/// [`unpin_all_general_forum_topic_messages`]
+///
+/// [`unpin_all_general_forum_topic_messages`]:
+/// ../../api/trait.API.html#method.unpin_all_general_forum_topic_messages
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnpinAllGeneralForumTopicMessages {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+}
+
 macro_rules! impl_from_chat {
     ($name:ident) => {
         impl From<Chat> for $name {
@@ -415,3 +647,24 @@ impl_from_chat!(GetChat);
 impl_from_chat!(GetChatAdministrators);
 impl_from_chat!(GetChatMembersCount);
 impl_from_chat!(DeleteChatStickerSet);
+impl_from_chat!(CloseGeneralForumTopic);
+impl_from_chat!(ReopenGeneralForumTopic);
+impl_from_chat!(HideGeneralForumTopic);
+impl_from_chat!(UnhideGeneralForumTopic);
+impl_from_chat!(UnpinAllGeneralForumTopicMessages);
+
+macro_rules! impl_from_join_request {
+    ($name:ident) => {
+        impl From<&ChatJoinRequest> for $name {
+            fn from(request: &ChatJoinRequest) -> Self {
+                Self {
+                    chat_id: request.chat.get_id(),
+                    user_id: request.from.id,
+                }
+            }
+        }
+    };
+}
+
+impl_from_join_request!(ApproveChatJoinRequest);
+impl_from_join_request!(DeclineChatJoinRequest);
@@ -1,4 +1,7 @@
-use crate::{framework::types::TelegramCommand, model::BotCommand};
+use crate::{
+    framework::{types::TelegramCommand, Framework, HELP_COMMAND_OPTIONS},
+    model::BotCommand,
+};
 use serde::{Deserialize, Serialize};
 
 /// struct for holding data needed to call
@@ -9,11 +12,163 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SetMyCommands {
     pub commands: Vec<BotCommand>,
+    /// The scope of users for which the commands are relevant. Defaults to
+    /// [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code. If empty, commands will be
+    /// applied to all users from the given scope, for whose language there
+    /// are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl SetMyCommands {
+    /// Groups the non-hidden commands by their scope and language, creating
+    /// one `SetMyCommands` for every combination of the two. The default
+    /// scope is always the first one, even when it has no commands, so the
+    /// commands registered earlier get cleared.
+    ///
+    /// Since telegram only shows the commands of the most specific scope and
+    /// language of a user, every list also contains the commands of the
+    /// default scope and of its scope without a language.
+    pub fn from_commands(commands: &[TelegramCommand]) -> Vec<Self> {
+        let mut res = Self::group_commands(commands);
+        Self::merge_fallbacks(&mut res);
+        res
+    }
+
+    /// Groups all non-hidden commands of the framework, including those in
+    /// groups and the built-in help command, by their scope and language,
+    /// like [`from_commands`] does. Groups with a prefix are registered as a
+    /// single command.
+    ///
+    /// [`from_commands`]: #method.from_commands
+    pub fn from_framework(framework: &Framework) -> Vec<Self> {
+        let mut res = Self::group_commands(framework.get_commands());
+
+        for group in framework.get_groups() {
+            match group.get_bot_command() {
+                Some(command) => {
+                    Self::add_bot_command(&mut res, command, &BotCommandScope::Default, None);
+                },
+                None => {
+                    for command in group.commands {
+                        Self::add_command(&mut res, command);
+                    }
+                },
+            }
+        }
+
+        if framework.has_help() {
+            Self::add_bot_command(
+                &mut res,
+                BotCommand {
+                    command: HELP_COMMAND_OPTIONS.name.to_owned(),
+                    description: HELP_COMMAND_OPTIONS.description.to_owned(),
+                },
+                &BotCommandScope::Default,
+                None,
+            );
+        }
+
+        Self::merge_fallbacks(&mut res);
+        res
+    }
+
+    fn group_commands(commands: &[TelegramCommand]) -> Vec<Self> {
+        let mut res = vec![Self {
+            commands: Vec::new(),
+            scope: Some(BotCommandScope::Default),
+            language_code: None,
+        }];
+        for command in commands {
+            Self::add_command(&mut res, command);
+        }
+        res
+    }
+
+    // adds the commands of the lists a list replaces for its users to it,
+    // skipping the ones it overrides
+    fn merge_fallbacks(res: &mut [Self]) {
+        let grouped = res.to_vec();
+        for list in res.iter_mut() {
+            let mut merged: Vec<BotCommand> = Vec::new();
+            for fallback in grouped.iter().filter(|f| list.falls_back_to(f)) {
+                for command in &fallback.commands {
+                    let exists = merged
+                        .iter()
+                        .chain(&list.commands)
+                        .any(|c| c.command == command.command);
+                    if !exists {
+                        merged.push(command.clone());
+                    }
+                }
+            }
+            merged.append(&mut list.commands);
+            list.commands = merged;
+        }
+    }
+
+    // returns true if the other list is shown to the users of this list when
+    // this one isn't registered
+    fn falls_back_to(&self, other: &Self) -> bool {
+        let same = self.scope == other.scope && self.language_code == other.language_code;
+        let scope = other.scope == self.scope || other.scope == Some(BotCommandScope::Default);
+        let language = other.language_code == self.language_code || other.language_code.is_none();
+        !same && scope && language
+    }
+
+    fn add_command(res: &mut Vec<Self>, command: &TelegramCommand) {
+        if command.options.hidden {
+            return;
+        }
+
+        let scopes: &[BotCommandScope] = if command.options.scopes.is_empty() {
+            &[BotCommandScope::Default]
+        } else {
+            command.options.scopes
+        };
+
+        for scope in scopes {
+            Self::add_bot_command(
+                res,
+                command.get_bot_command(),
+                scope,
+                command.options.language_code,
+            );
+        }
+    }
+
+    fn add_bot_command(
+        res: &mut Vec<Self>,
+        command: BotCommand,
+        scope: &BotCommandScope,
+        language_code: Option<&str>,
+    ) {
+        let scope = Some(scope.clone());
+        let language_code = language_code.map(ToOwned::to_owned);
+        match res
+            .iter_mut()
+            .find(|s| s.scope == scope && s.language_code == language_code)
+        {
+            Some(s) => s.commands.push(command),
+            None => res.push(Self {
+                commands: vec![command],
+                scope,
+                language_code,
+            }),
+        }
+    }
 }
 
 impl From<Vec<BotCommand>> for SetMyCommands {
     fn from(commands: Vec<BotCommand>) -> Self {
-        Self { commands }
+        Self {
+            commands,
+            scope: None,
+            language_code: None,
+        }
     }
 }
 
@@ -22,8 +177,123 @@ impl From<&Vec<TelegramCommand>> for SetMyCommands {
         Self {
             commands: commands
                 .iter()
+                .filter(|c| !c.options.hidden)
                 .map(TelegramCommand::get_bot_command)
                 .collect(),
+            scope: None,
+            language_code: None,
         }
     }
 }
+
+/// struct for holding data needed to call
+/// [`get_my_commands_with`]
+///
+/// [`get_my_commands_with`]:
+/// ../../api/trait.API.html#method.get_my_commands_with
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct GetMyCommands {
+    /// The scope of users to get the commands for. Defaults to
+    /// [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+/// struct for holding data needed to call
+/// [`delete_my_commands`]
+///
+/// [`delete_my_commands`]:
+/// ../../api/trait.API.html#method.delete_my_commands
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct DeleteMyCommands {
+    /// The scope of users for which the commands are relevant. Defaults to
+    /// [`BotCommandScope::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// A two-letter ISO 639-1 language code. If empty, commands will be
+    /// applied to all users from the given scope, for whose language there
+    /// are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl DeleteMyCommands {
+    /// Gets the lists of commands that have to be deleted when registering
+    /// the given ones, which are the previously registered lists and those of
+    /// the scopes covering all chats of a type that are not registered
+    /// anymore
+    pub fn stale(registered: &[SetMyCommands], previous: Vec<Self>) -> Vec<Self> {
+        let broad = vec![
+            BotCommandScope::AllPrivateChats,
+            BotCommandScope::AllGroupChats,
+            BotCommandScope::AllChatAdministrators,
+        ]
+        .into_iter()
+        .map(|scope| Self {
+            scope: Some(scope),
+            language_code: None,
+        });
+
+        let mut res: Vec<Self> = Vec::new();
+        for list in broad.chain(previous) {
+            let declared = registered
+                .iter()
+                .any(|r| r.scope == list.scope && r.language_code == list.language_code);
+            if !declared && !res.contains(&list) {
+                res.push(list);
+            }
+        }
+        res
+    }
+}
+
+impl From<&SetMyCommands> for DeleteMyCommands {
+    fn from(commands: &SetMyCommands) -> Self {
+        Self {
+            scope: commands.scope.clone(),
+            language_code: commands.language_code.clone(),
+        }
+    }
+}
+
+/// This object represents the scope to which bot commands are applied.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "type")]
+pub enum BotCommandScope {
+    /// The default scope of bot commands. Default commands are used if no
+    /// commands with a narrower scope are specified for the user.
+    #[serde(rename = "default")]
+    Default,
+    /// Covers all private chats.
+    #[serde(rename = "all_private_chats")]
+    AllPrivateChats,
+    /// Covers all group and supergroup chats.
+    #[serde(rename = "all_group_chats")]
+    AllGroupChats,
+    /// Covers all group and supergroup chat administrators.
+    #[serde(rename = "all_chat_administrators")]
+    AllChatAdministrators,
+    /// Covers a specific chat.
+    #[serde(rename = "chat")]
+    Chat {
+        /// Unique identifier for the target chat
+        chat_id: i64,
+    },
+    /// Covers all administrators of a specific group or supergroup chat.
+    #[serde(rename = "chat_administrators")]
+    ChatAdministrators {
+        /// Unique identifier for the target chat
+        chat_id: i64,
+    },
+    /// Covers a specific member of a group or supergroup chat.
+    #[serde(rename = "chat_member")]
+    ChatMember {
+        /// Unique identifier for the target chat
+        chat_id: i64,
+        /// Unique identifier of the target user
+        user_id: i64,
+    },
+}
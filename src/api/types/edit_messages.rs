@@ -1,5 +1,7 @@
 use super::InputMedia;
-use crate::model::{InlineKeyboardMarkup, Message, MessageEntity, ParseMode};
+use crate::model::{
+    InlineKeyboardMarkup, LinkPreviewOptions, Message, MessageEntity, ParseMode, Text,
+};
 use serde::{Deserialize, Serialize};
 
 /// struct for holding data needed to call
@@ -9,6 +11,10 @@ use serde::{Deserialize, Serialize};
 /// ../../api/trait.API.html#method.edit_message_text
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EditMessageText {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -30,6 +36,9 @@ pub struct EditMessageText {
     pub entities: Option<Vec<MessageEntity>>,
     /// Disables link previews for links in this message
     pub disable_web_page_preview: bool,
+    /// Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
     /// Inline keyboard
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
@@ -38,6 +47,7 @@ pub struct EditMessageText {
 impl EditMessageText {
     pub fn new(chat_id: i64, message_id: i64, new_text: &str) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: Some(chat_id),
             message_id: Some(message_id),
             text: new_text.to_owned(),
@@ -45,12 +55,14 @@ impl EditMessageText {
             parse_mode: None,
             entities: None,
             disable_web_page_preview: false,
+            link_preview_options: None,
             reply_markup: None,
         }
     }
 
     pub fn from_message(message: &Message, new_text: &str) -> Self {
         Self {
+            business_connection_id: message.business_connection_id.clone(),
             chat_id: Some(message.chat.get_id()),
             message_id: Some(message.message_id),
             text: new_text.to_owned(),
@@ -58,9 +70,35 @@ impl EditMessageText {
             parse_mode: None,
             entities: None,
             disable_web_page_preview: false,
+            link_preview_options: None,
             reply_markup: None,
         }
     }
+
+    pub fn from_inline_message(inline_message_id: &str, new_text: &str) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            text: new_text.to_owned(),
+            inline_message_id: Some(inline_message_id.to_owned()),
+            parse_mode: None,
+            entities: None,
+            disable_web_page_preview: false,
+            link_preview_options: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Sets the new text along with the entities describing its formatting,
+    /// removing the parse mode
+    pub fn set_formatted_text(&mut self, text: &Text) -> &mut Self {
+        let (text, entities) = text.to_entities();
+        self.text = text;
+        self.entities = Some(entities).filter(|e| !e.is_empty());
+        self.parse_mode = None;
+        self
+    }
 }
 
 /// struct for holding data needed to call
@@ -70,6 +108,10 @@ impl EditMessageText {
 /// ../../api/trait.API.html#method.edit_message_caption
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EditMessageCaption {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -98,6 +140,7 @@ pub struct EditMessageCaption {
 impl EditMessageCaption {
     pub fn new(chat_id: i64, message_id: i64, new_text: Option<&str>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: Some(chat_id),
             message_id: Some(message_id),
             caption: new_text.map(ToString::to_string),
@@ -110,6 +153,7 @@ impl EditMessageCaption {
 
     pub fn from_message(message: &Message, new_text: Option<&str>) -> Self {
         Self {
+            business_connection_id: message.business_connection_id.clone(),
             chat_id: Some(message.chat.get_id()),
             message_id: Some(message.message_id),
             caption: new_text.map(ToString::to_string),
@@ -119,6 +163,16 @@ impl EditMessageCaption {
             reply_markup: None,
         }
     }
+
+    /// Sets the new caption along with the entities describing its
+    /// formatting, removing the parse mode
+    pub fn set_formatted_caption(&mut self, caption: &Text) -> &mut Self {
+        let (caption, entities) = caption.to_entities();
+        self.caption = Some(caption);
+        self.caption_entities = Some(entities).filter(|e| !e.is_empty());
+        self.parse_mode = None;
+        self
+    }
 }
 
 /// struct for holding data needed to call
@@ -128,6 +182,10 @@ impl EditMessageCaption {
 /// ../../api/trait.API.html#method.edit_message_media
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EditMessageMedia {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -147,6 +205,7 @@ pub struct EditMessageMedia {
 impl EditMessageMedia {
     pub fn new(chat_id: i64, message_id: i64, new_media: &InputMedia) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: Some(chat_id),
             message_id: Some(message_id),
             media: new_media.clone(),
@@ -157,6 +216,7 @@ impl EditMessageMedia {
 
     pub fn from_message(message: &Message, new_media: &InputMedia) -> Self {
         Self {
+            business_connection_id: message.business_connection_id.clone(),
             chat_id: Some(message.chat.get_id()),
             message_id: Some(message.message_id),
             media: new_media.clone(),
@@ -173,6 +233,10 @@ impl EditMessageMedia {
 /// ../../api/trait.API.html#method.edit_message_reply_markup
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EditMessageReplyMarkup {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -190,6 +254,7 @@ pub struct EditMessageReplyMarkup {
 impl EditMessageReplyMarkup {
     pub fn new(chat_id: i64, message_id: i64, new_markup: Option<&InlineKeyboardMarkup>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: Some(chat_id),
             message_id: Some(message_id),
             inline_message_id: None,
@@ -199,12 +264,26 @@ impl EditMessageReplyMarkup {
 
     pub fn from_message(message: &Message, new_markup: Option<&InlineKeyboardMarkup>) -> Self {
         Self {
+            business_connection_id: message.business_connection_id.clone(),
             chat_id: Some(message.chat.get_id()),
             message_id: Some(message.message_id),
             inline_message_id: None,
             reply_markup: new_markup.cloned(),
         }
     }
+
+    pub fn from_inline_message(
+        inline_message_id: &str,
+        new_markup: Option<&InlineKeyboardMarkup>,
+    ) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id: None,
+            message_id: None,
+            inline_message_id: Some(inline_message_id.to_owned()),
+            reply_markup: new_markup.cloned(),
+        }
+    }
 }
 
 /// struct for holding data needed to call
@@ -214,6 +293,10 @@ impl EditMessageReplyMarkup {
 /// ../../api/trait.API.html#method.stop_poll
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StopPoll {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message with the poll was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
     /// Identifier of the message to edit
@@ -226,6 +309,7 @@ pub struct StopPoll {
 impl StopPoll {
     pub fn new(chat_id: i64, message_id: i64) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
             message_id,
             reply_markup: None,
@@ -234,6 +318,7 @@ impl StopPoll {
 
     pub fn from_message(message: &Message) -> Self {
         Self {
+            business_connection_id: message.business_connection_id.clone(),
             chat_id: message.chat.get_id(),
             message_id: message.message_id,
             reply_markup: None,
@@ -277,6 +362,10 @@ impl DeleteMessage {
 /// ../../api/trait.API.html#method.edit_message_live_location
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EditMessageLiveLocation {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -313,6 +402,10 @@ pub struct EditMessageLiveLocation {
 /// ../../api/trait.API.html#method.edit_message_live_location
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StopMessageLiveLocation {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message to be edited was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
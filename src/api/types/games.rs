@@ -1,3 +1,4 @@
+use super::ReplyParameters;
 use crate::model::ReplyMarkup;
 use serde::{Deserialize, Serialize};
 
@@ -8,17 +9,36 @@ use serde::{Deserialize, Serialize};
 /// ../../api/trait.API.html#method.send_game
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendGame {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Short name of the game, serves as the unique identifier for the game.
     /// Set up your games via Botfather.
     pub game_short_name: String,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
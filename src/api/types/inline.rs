@@ -1,5 +1,5 @@
 use crate::model::{InlineKeyboardMarkup, LabeledPrice, MessageEntity, ParseMode};
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 /// struct for holding data needed to call
 /// [`answer_inline_query`]
@@ -48,38 +48,120 @@ pub struct AnswerInlineQuery {
 }
 
 /// This object represents one result of an inline query.
+///
+/// The cached variants use files that are already stored on the Telegram
+/// servers by their `file_id`, they have the same `type` as the variants
+/// using a url and are told apart by their `*_file_id` field.
 #[allow(clippy::large_enum_variant)]
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type")]
 pub enum InlineQueryResult {
     #[serde(rename = "article")]
     Article(InlineQueryResultArticle),
     #[serde(rename = "audio")]
     Audio(InlineQueryResultAudio),
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio),
     #[serde(rename = "contact")]
     Contact(InlineQueryResultContact),
     #[serde(rename = "game")]
     Game(InlineQueryResultGame),
     #[serde(rename = "document")]
     Document(InlineQueryResultDocument),
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument),
     #[serde(rename = "gif")]
     Gif(InlineQueryResultGif),
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif),
     #[serde(rename = "location")]
     Location(InlineQueryResultLocation),
     #[serde(rename = "mpeg4_gif")]
     Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
     #[serde(rename = "photo")]
     Photo(InlineQueryResultPhoto),
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker),
     #[serde(rename = "venue")]
     Venue(InlineQueryResultVenue),
     #[serde(rename = "video")]
     Video(InlineQueryResultVideo),
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo),
     #[serde(rename = "voice")]
     Voice(InlineQueryResultVoice),
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice),
 }
 
-// TODO: add support for the cached types too. Add enum with url and cache
-// variant?
+impl<'de> Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<InlineQueryResult, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const TYPES: &[&str] = &[
+            "article",
+            "audio",
+            "contact",
+            "game",
+            "document",
+            "gif",
+            "location",
+            "mpeg4_gif",
+            "photo",
+            "sticker",
+            "venue",
+            "video",
+            "voice",
+        ];
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("type"))?
+            .to_owned();
+        let cached = value
+            .as_object()
+            .is_some_and(|o| o.keys().any(|k| k.ends_with("_file_id")));
+
+        macro_rules! result {
+            ($variant:ident) => {
+                serde_json::from_value(value)
+                    .map(InlineQueryResult::$variant)
+                    .map_err(de::Error::custom)
+            };
+        }
+
+        match (kind.as_str(), cached) {
+            ("article", _) => result!(Article),
+            ("audio", false) => result!(Audio),
+            ("audio", true) => result!(CachedAudio),
+            ("contact", _) => result!(Contact),
+            ("game", _) => result!(Game),
+            ("document", false) => result!(Document),
+            ("document", true) => result!(CachedDocument),
+            ("gif", false) => result!(Gif),
+            ("gif", true) => result!(CachedGif),
+            ("location", _) => result!(Location),
+            ("mpeg4_gif", false) => result!(Mpeg4Gif),
+            ("mpeg4_gif", true) => result!(CachedMpeg4Gif),
+            ("photo", false) => result!(Photo),
+            ("photo", true) => result!(CachedPhoto),
+            ("sticker", _) => result!(CachedSticker),
+            ("venue", _) => result!(Venue),
+            ("video", false) => result!(Video),
+            ("video", true) => result!(CachedVideo),
+            ("voice", false) => result!(Voice),
+            ("voice", true) => result!(CachedVoice),
+            (other, _) => Err(de::Error::unknown_variant(other, TYPES)),
+        }
+    }
+}
 
 /// Represents a link to an article or web page.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -568,6 +650,461 @@ pub struct InlineQueryResultGame {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+/// Represents a link to a photo stored on the Telegram servers. By default,
+/// this photo will be sent by the user with an optional caption.
+/// Alternatively, you can use `input_message_content` to send a message with
+/// the specified content instead of the photo.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedPhoto {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier of the photo
+    pub photo_file_id: String,
+    /// Title of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Caption of the photo to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedPhoto {
+    pub fn new(id: &str, photo_file_id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            photo_file_id: photo_file_id.to_owned(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    pub fn set_description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+}
+
+/// Represents a link to an animated GIF file stored on the Telegram servers. By
+/// default, this animated GIF file will be sent by the user with an optional
+/// caption. Alternatively, you can use `input_message_content` to send a
+/// message with the specified content instead of the animation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedGif {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the GIF file
+    pub gif_file_id: String,
+    /// Title of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Caption of the GIF file to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedGif {
+    pub fn new(id: &str, gif_file_id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            gif_file_id: gif_file_id.to_owned(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+}
+
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without
+/// sound) stored on the Telegram servers. By default, this animated MPEG-4
+/// file will be sent by the user with an optional caption. Alternatively, you
+/// can use `input_message_content` to send a message with the specified
+/// content instead of the animation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedMpeg4Gif {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the MP4 file
+    pub mpeg4_file_id: String,
+    /// Title of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Caption of the MPEG-4 file to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedMpeg4Gif {
+    pub fn new(id: &str, mpeg4_file_id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            mpeg4_file_id: mpeg4_file_id.to_owned(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+}
+
+/// Represents a link to a sticker stored on the Telegram servers. By default,
+/// this sticker will be sent by the user. Alternatively, you can use
+/// `input_message_content` to send a message with the specified content
+/// instead of the sticker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedSticker {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier of the sticker
+    pub sticker_file_id: String,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedSticker {
+    pub fn new(id: &str, sticker_file_id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            sticker_file_id: sticker_file_id.to_owned(),
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Represents a link to a file stored on the Telegram servers. By default,
+/// this file will be sent by the user with an optional caption.
+/// Alternatively, you can use `input_message_content` to send a message with
+/// the specified content instead of the file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedDocument {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Title for the result
+    pub title: String,
+    /// A valid file identifier for the file
+    pub document_file_id: String,
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Caption of the document to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedDocument {
+    pub fn new(id: &str, document_file_id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            title: title.to_owned(),
+            document_file_id: document_file_id.to_owned(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn set_description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+}
+
+/// Represents a link to a video file stored on the Telegram servers. By
+/// default, this video file will be sent by the user with an optional
+/// caption. Alternatively, you can use `input_message_content` to send a
+/// message with the specified content instead of the video.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedVideo {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the video file
+    pub video_file_id: String,
+    /// Title for the result
+    pub title: String,
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Caption of the video to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedVideo {
+    pub fn new(id: &str, video_file_id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            video_file_id: video_file_id.to_owned(),
+            title: title.to_owned(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn set_description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+}
+
+/// Represents a link to a voice message stored on the Telegram servers. By
+/// default, this voice message will be sent by the user. Alternatively, you
+/// can use `input_message_content` to send a message with the specified
+/// content instead of the voice message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedVoice {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the voice message
+    pub voice_file_id: String,
+    /// Voice message title
+    pub title: String,
+    /// Caption of the voice message to be sent, 0-1024 characters after
+    /// entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the voice message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedVoice {
+    pub fn new(id: &str, voice_file_id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            voice_file_id: voice_file_id.to_owned(),
+            title: title.to_owned(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Represents a link to an MP3 audio file stored on the Telegram servers. By
+/// default, this audio file will be sent by the user. Alternatively, you can
+/// use `input_message_content` to send a message with the specified content
+/// instead of the audio.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InlineQueryResultCachedAudio {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// A valid file identifier for the audio file
+    pub audio_file_id: String,
+    /// Caption of the audio to be sent, 0-1024 characters after entities
+    /// parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in your bot's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedAudio {
+    pub fn new(id: &str, audio_file_id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            audio_file_id: audio_file_id.to_owned(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+macro_rules! impl_cached_result {
+    ($name:ident, $variant:ident) => {
+        impl $name {
+            pub fn set_reply_markup(&mut self, markup: &InlineKeyboardMarkup) -> &mut Self {
+                self.reply_markup = Some(markup.clone());
+                self
+            }
+
+            pub fn set_input_message_content(
+                &mut self,
+                content: &InputMessageContent,
+            ) -> &mut Self {
+                self.input_message_content = Some(content.clone());
+                self
+            }
+        }
+
+        impl From<$name> for InlineQueryResult {
+            fn from(result: $name) -> Self {
+                Self::$variant(result)
+            }
+        }
+    };
+    ($name:ident, $variant:ident, captioned) => {
+        impl_cached_result!($name, $variant);
+
+        impl $name {
+            pub fn set_caption(&mut self, caption: &str) -> &mut Self {
+                self.caption = Some(caption.to_owned());
+                self
+            }
+
+            pub fn set_parse_mode(&mut self, mode: &ParseMode) -> &mut Self {
+                self.parse_mode = Some(mode.clone());
+                self
+            }
+
+            pub fn set_caption_entities(&mut self, entities: &[MessageEntity]) -> &mut Self {
+                self.caption_entities = Some(entities.to_vec());
+                self
+            }
+        }
+    };
+}
+
+impl_cached_result!(InlineQueryResultCachedPhoto, CachedPhoto, captioned);
+impl_cached_result!(InlineQueryResultCachedGif, CachedGif, captioned);
+impl_cached_result!(InlineQueryResultCachedMpeg4Gif, CachedMpeg4Gif, captioned);
+impl_cached_result!(InlineQueryResultCachedSticker, CachedSticker);
+impl_cached_result!(InlineQueryResultCachedDocument, CachedDocument, captioned);
+impl_cached_result!(InlineQueryResultCachedVideo, CachedVideo, captioned);
+impl_cached_result!(InlineQueryResultCachedVoice, CachedVoice, captioned);
+impl_cached_result!(InlineQueryResultCachedAudio, CachedAudio, captioned);
+
 /// This object represents the content of a message to be sent as a result of an
 /// inline query.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
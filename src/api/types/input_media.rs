@@ -37,6 +37,12 @@ pub struct InputMediaPhoto {
     /// specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the photo needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -57,6 +63,12 @@ pub struct InputMediaVideo {
     /// specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the video needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Duration of the video in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
@@ -90,6 +102,12 @@ pub struct InputMediaAnimation {
     /// specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the animation needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Duration of the animation in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
@@ -167,3 +185,51 @@ impl InputMedia {
         }
     }
 }
+
+/// This object describes the paid media to be sent
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum InputPaidMedia {
+    #[serde(rename = "photo")]
+    Photo(InputPaidMediaPhoto),
+    #[serde(rename = "video")]
+    Video(InputPaidMediaVideo),
+}
+
+/// The paid media to send is a photo.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InputPaidMediaPhoto {
+    /// File to send. Pass a `file_id` to send a file that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL for Telegram to get a
+    /// file from the Internet
+    pub media: InputFile,
+}
+
+/// The paid media to send is a video.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InputPaidMediaVideo {
+    /// File to send. Pass a `file_id` to send a file that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL for Telegram to get a
+    /// file from the Internet
+    pub media: InputFile,
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    /// Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+    /// If the uploaded video is suitable for streaming
+    pub supports_streaming: bool,
+}
+
+impl InputPaidMedia {
+    pub fn get_media(&self) -> &InputFile {
+        match self {
+            InputPaidMedia::Photo(m) => &m.media,
+            InputPaidMedia::Video(m) => &m.media,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! This modules provides all the objects describing the payloads to be send to
 //! the different telegram API endpoints
 
+mod bot;
+mod business;
 mod chat;
 mod commands;
 mod edit_messages;
@@ -10,11 +12,15 @@ mod input_media;
 mod other;
 mod passport;
 mod payments;
+mod reactions;
 mod send_messages;
 mod stickers;
 mod updates;
+mod web_app;
 mod webhooks;
 
+pub use bot::*;
+pub use business::*;
 pub use chat::*;
 pub use commands::*;
 pub use edit_messages::*;
@@ -24,7 +30,9 @@ pub use input_media::*;
 pub use other::*;
 pub use passport::*;
 pub use payments::*;
+pub use reactions::*;
 pub use send_messages::*;
 pub use stickers::*;
 pub use updates::{GetUpdates, UpdateType};
+pub use web_app::*;
 pub use webhooks::*;
@@ -1,6 +1,10 @@
+use super::ReplyParameters;
 use crate::model::{LabeledPrice, ReplyMarkup, ShippingOption};
 use serde::{Deserialize, Serialize};
 
+/// The currency code of payments in Telegram Stars
+pub const STARS_CURRENCY: &str = "XTR";
+
 /// struct for holding data needed to call
 /// [`send_invoice`]
 ///
@@ -10,6 +14,10 @@ use serde::{Deserialize, Serialize};
 pub struct SendInvoice {
     /// Unique identifier for the target private chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Product name, 1-32 characters
     pub title: String,
     /// Product description, 1-255 characters
@@ -17,8 +25,10 @@ pub struct SendInvoice {
     /// Bot-defined invoice payload, 1-128 bytes.
     /// This will not be displayed to the user, use for your internal processes.
     pub payload: String,
-    /// Payments provider token, obtained via [Botfather](https://t.me/botfather)
-    pub provider_token: String,
+    /// Payments provider token, obtained via [Botfather](https://t.me/botfather).
+    /// Pass None for payments in [Telegram Stars](https://t.me/BotNews/90)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
     /// The maximum accepted amount for tips in the smallest units of the currency (integer, not
     /// float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See
     /// the exp parameter in [currencies.json](https://core.telegram.org/bots/payments/currencies.json),
@@ -38,7 +48,8 @@ pub struct SendInvoice {
     /// start parameter.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_parameter: Option<String>,
-    /// Three-letter ISO 4217 currency code
+    /// Three-letter ISO 4217 currency code, or `XTR` for payments in Telegram
+    /// Stars
     pub currency: String,
     /// Price breakdown, a list of components (e.g. product price, tax,
     /// discount, delivery cost, delivery tax, bonus, etc.)
@@ -80,14 +91,212 @@ pub struct SendInvoice {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl SendInvoice {
+    /// function to create a new `SendInvoice` object, setting all optional
+    /// fields to None
+    pub fn new(
+        chat_id: i64,
+        title: &str,
+        description: &str,
+        payload: &str,
+        currency: &str,
+        prices: Vec<LabeledPrice>,
+    ) -> Self {
+        Self {
+            chat_id,
+            message_thread_id: None,
+            title: title.to_owned(),
+            description: description.to_owned(),
+            payload: payload.to_owned(),
+            provider_token: None,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            start_parameter: None,
+            currency: currency.to_owned(),
+            prices,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+            reply_to_message_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// function to create a new `SendInvoice` object for a payment of the
+    /// given amount of Telegram Stars, setting all optional fields to None
+    pub fn stars(chat_id: i64, title: &str, description: &str, payload: &str, amount: i64) -> Self {
+        Self::new(
+            chat_id,
+            title,
+            description,
+            payload,
+            STARS_CURRENCY,
+            vec![LabeledPrice {
+                label: title.to_owned(),
+                amount,
+            }],
+        )
+    }
+}
+
+/// struct for holding data needed to call
+/// [`create_invoice_link`]
+///
+/// [`create_invoice_link`]:
+/// ../../api/trait.API.html#method.create_invoice_link
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CreateInvoiceLink {
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes.
+    /// This will not be displayed to the user, use for your internal processes.
+    pub payload: String,
+    /// Payments provider token, obtained via [Botfather](https://t.me/botfather).
+    /// Pass None for payments in [Telegram Stars](https://t.me/BotNews/90)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, or `XTR` for payments in Telegram
+    /// Stars
+    pub currency: String,
+    /// Price breakdown, a list of components (e.g. product price, tax,
+    /// discount, delivery cost, delivery tax, bonus, etc.). Must contain
+    /// exactly one item for payments in Telegram Stars
+    pub prices: Vec<LabeledPrice>,
+    /// The number of seconds the subscription will be active for before the
+    /// next payment. The currency must be set to `XTR` if the parameter is
+    /// used. Currently, it must always be 2592000 (30 days)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i64>,
+    /// The maximum accepted amount for tips in the smallest units of the
+    /// currency. Defaults to 0. Not supported for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+    /// A vec of suggested amounts of tips in the smallest units of the
+    /// currency. At most 4 suggested tip amounts can be specified. The
+    /// suggested tip amounts must be positive, passed in a strictly increased
+    /// order and must not exceed `max_tip_amount`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+    /// JSON-encoded data about the invoice, which will be shared with the
+    /// payment provider. A detailed description of required fields should
+    /// be provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    /// URL of the product photo for the invoice.
+    /// Can be a photo of the goods or a marketing image for a service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+    /// Pass True, if you require the user's full name to complete the order
+    pub need_name: bool,
+    /// Pass True, if you require the user's phone number to complete the order
+    pub need_phone_number: bool,
+    /// Pass True, if you require the user's email address to complete the order
+    pub need_email: bool,
+    /// Pass True, if you require the user's shipping address to complete the
+    /// order
+    pub need_shipping_address: bool,
+    /// Pass True, if user's phone number should be sent to provider
+    pub send_phone_number_to_provider: bool,
+    /// Pass True, if user's email address should be sent to provider
+    pub send_email_to_provider: bool,
+    /// Pass True, if the final price depends on the shipping method
+    pub is_flexible: bool,
+}
+
+impl CreateInvoiceLink {
+    /// function to create a new `CreateInvoiceLink` object, setting all
+    /// optional fields to None
+    pub fn new(
+        title: &str,
+        description: &str,
+        payload: &str,
+        currency: &str,
+        prices: Vec<LabeledPrice>,
+    ) -> Self {
+        Self {
+            title: title.to_owned(),
+            description: description.to_owned(),
+            payload: payload.to_owned(),
+            provider_token: None,
+            currency: currency.to_owned(),
+            prices,
+            subscription_period: None,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+        }
+    }
+
+    /// function to create a new `CreateInvoiceLink` object for a payment of
+    /// the given amount of Telegram Stars, setting all optional fields to None
+    pub fn stars(title: &str, description: &str, payload: &str, amount: i64) -> Self {
+        Self::new(
+            title,
+            description,
+            payload,
+            STARS_CURRENCY,
+            vec![LabeledPrice {
+                label: title.to_owned(),
+                amount,
+            }],
+        )
+    }
+}
+
 /// struct for holding data needed to call
 /// [`answer_shipping_query`]
 ///
@@ -135,3 +344,42 @@ pub struct AnswerPreCheckoutQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
+
+/// struct for holding data needed to call
+/// [`refund_star_payment`]
+///
+/// [`refund_star_payment`]:
+/// ../../api/trait.API.html#method.refund_star_payment
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RefundStarPayment {
+    /// Identifier of the user whose payment will be refunded
+    pub user_id: i64,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+}
+
+impl RefundStarPayment {
+    /// function to create a new `RefundStarPayment` object
+    pub fn new(user_id: i64, telegram_payment_charge_id: &str) -> Self {
+        Self {
+            user_id,
+            telegram_payment_charge_id: telegram_payment_charge_id.to_owned(),
+        }
+    }
+}
+
+/// struct for holding data needed to call
+/// [`get_star_transactions`]
+///
+/// [`get_star_transactions`]:
+/// ../../api/trait.API.html#method.get_star_transactions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct GetStarTransactions {
+    /// Number of transactions to skip in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// The maximum number of transactions to be retrieved. Values between
+    /// 1-100 are accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,62 @@
+use crate::model::{Message, ReactionType};
+use serde::{Deserialize, Serialize};
+
+/// struct for holding data needed to call
+/// [`set_message_reaction`]
+///
+/// [`set_message_reaction`]:
+/// ../../api/trait.API.html#method.set_message_reaction
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetMessageReaction {
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// Identifier of the target message
+    pub message_id: i64,
+    /// New list of reaction types to set on the message. Currently, as
+    /// non-premium users, bots can set up to one reaction per message. A
+    /// custom emoji reaction can be used if it is either already present on
+    /// the message or explicitly allowed by chat administrators. Paid
+    /// reactions can't be used by bots. Leaving it empty removes the
+    /// reactions of the bot.
+    pub reaction: Vec<ReactionType>,
+    /// Pass True to set the reaction with a big animation
+    pub is_big: bool,
+}
+
+impl SetMessageReaction {
+    /// function to create a new `SetMessageReaction` object, removing the
+    /// reactions of the bot unless a reaction is added
+    pub fn new(chat_id: i64, message_id: i64) -> Self {
+        Self {
+            chat_id,
+            message_id,
+            reaction: Vec::new(),
+            is_big: false,
+        }
+    }
+
+    /// function to create a new `SetMessageReaction` object reacting to the
+    /// given message, removing the reactions of the bot unless a reaction is
+    /// added
+    pub fn from_message(message: &Message) -> Self {
+        Self::new(message.chat.get_id(), message.message_id)
+    }
+
+    /// adds a reaction to set on the message
+    pub fn add_reaction(&mut self, reaction: ReactionType) -> &mut Self {
+        self.reaction.push(reaction);
+        self
+    }
+
+    /// sets the reaction to the given emoji
+    pub fn set_emoji(&mut self, emoji: &str) -> &mut Self {
+        self.reaction = vec![ReactionType::emoji(emoji)];
+        self
+    }
+
+    /// sets whether the reaction is set with a big animation
+    pub fn set_big(&mut self, is_big: bool) -> &mut Self {
+        self.is_big = is_big;
+        self
+    }
+}
@@ -1,8 +1,8 @@
-use super::{InputFile, InputMedia};
+use super::{InputFile, InputMedia, InputPaidMedia};
 use crate::{
     model::{
-        utils::unix_date_formatting, ChatAction, MessageEntity, ParseMode, PhotoSize, PollType,
-        ReplyMarkup,
+        utils::unix_date_formatting, ChatAction, LinkPreviewOptions, MessageEntity, ParseMode,
+        PhotoSize, PollType, ReplyMarkup, Text,
     },
     prelude::Message,
     utils::result::Result,
@@ -18,8 +18,16 @@ use std::path::Path;
 /// ../../api/trait.API.html#method.send_message
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendMessage {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Text of the message to be sen, 1-4096 characters after entities parsing
     pub text: String,
     /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
@@ -32,12 +40,26 @@ pub struct SendMessage {
     pub enitites: Option<Vec<MessageEntity>>,
     /// Disables link previews for links in this message
     pub disable_web_page_preview: bool,
+    /// Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -49,13 +71,19 @@ pub struct SendMessage {
 impl SendMessage {
     pub fn new(chat_id: i64, text: &str) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             text: text.to_owned(),
             parse_mode: None,
             enitites: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             disable_web_page_preview: false,
+            link_preview_options: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -66,8 +94,28 @@ impl SendMessage {
         self
     }
 
+    /// Sets the text along with the entities describing its formatting,
+    /// removing the parse mode
+    pub fn set_formatted_text(&mut self, text: &Text) -> &mut Self {
+        let (text, entities) = text.to_entities();
+        self.text = text;
+        self.enitites = Some(entities).filter(|e| !e.is_empty());
+        self.parse_mode = None;
+        self
+    }
+
+    /// sets the message to reply to, sending the reply to the same forum topic
+    /// or on behalf of the same business account if the message was sent to
+    /// one
     pub fn reply_to_message(&mut self, message: &Message) -> &mut Self {
         self.reply_to_message_id = Some(message.message_id);
+        if message.is_topic_message {
+            self.message_thread_id = message.message_thread_id;
+        }
+        if message.business_connection_id.is_some() {
+            self.business_connection_id
+                .clone_from(&message.business_connection_id);
+        }
         self
     }
 
@@ -76,6 +124,18 @@ impl SendMessage {
         self
     }
 
+    /// sets the message to reply to along with the quote and chat of that
+    /// message
+    pub fn set_reply_parameters(&mut self, parameters: ReplyParameters) -> &mut Self {
+        self.reply_parameters = Some(parameters);
+        self
+    }
+
+    pub fn set_link_preview_options(&mut self, options: LinkPreviewOptions) -> &mut Self {
+        self.link_preview_options = Some(options);
+        self
+    }
+
     pub fn set_reply_markup(&mut self, markup: &ReplyMarkup) -> &mut Self {
         self.reply_markup = Some(markup.clone());
         self
@@ -90,6 +150,77 @@ impl SendMessage {
         self.disable_web_page_preview = !self.disable_web_page_preview;
         self
     }
+
+    pub fn toggle_protect_content(&mut self) -> &mut Self {
+        self.protect_content = !self.protect_content;
+        self
+    }
+}
+
+/// Describes the message to reply to when sending a message, which can be
+/// in another chat and can quote part of the message
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplyParameters {
+    /// Identifier of the message that will be replied to in the current chat,
+    /// or in the chat `chat_id` if it is specified
+    pub message_id: i64,
+    /// If the message to be replied to is from a different chat, unique
+    /// identifier for the chat. Not supported for messages sent on behalf of
+    /// a business account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    /// Pass True if the message should be sent even if the specified message
+    /// to be replied to is not found. Always False for replies in another
+    /// chat or forum topic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// Quoted part of the message to be replied to; 0-1024 characters after
+    /// entities parsing. The quote must be an exact substring of the message
+    /// to be replied to, including bold, italic, underline, strikethrough,
+    /// spoiler, and `custom_emoji` entities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// Mode for parsing entities in the quote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the quote, which can be
+    /// specified instead of `quote_parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_entities: Option<Vec<MessageEntity>>,
+    /// Position of the quote in the original message in UTF-16 code units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_position: Option<i64>,
+}
+
+impl ReplyParameters {
+    /// function to create a new `ReplyParameters` object replying to the
+    /// message with the given id, setting all optional fields to None
+    pub fn new(message_id: i64) -> Self {
+        Self {
+            message_id,
+            chat_id: None,
+            allow_sending_without_reply: None,
+            quote: None,
+            quote_parse_mode: None,
+            quote_entities: None,
+            quote_position: None,
+        }
+    }
+
+    /// Creates `ReplyParameters` replying to the given message, which can be
+    /// in another chat than the one the reply is sent to
+    pub fn from_message(message: &Message) -> Self {
+        Self {
+            chat_id: Some(message.chat.get_id()),
+            ..Self::new(message.message_id)
+        }
+    }
+
+    /// Sets the quoted part of the message to reply to
+    pub fn set_quote(&mut self, quote: &str) -> &mut Self {
+        self.quote = Some(quote.to_owned());
+        self
+    }
 }
 
 /// struct for holding data needed to call
@@ -101,6 +232,10 @@ impl SendMessage {
 pub struct ForwardMessage {
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Unique identifier for the chat where the original message was sent.
     pub from_chat_id: i64,
     /// Message identifier in the chat specified in from_chat_id
@@ -108,15 +243,19 @@ pub struct ForwardMessage {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
 }
 
 impl ForwardMessage {
     pub fn new(chat_id: i64, from_chat_id: i64, message_id: i64) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id,
             message_id,
             disable_notification: false,
+            protect_content: false,
         }
     }
 
@@ -128,9 +267,11 @@ impl ForwardMessage {
     pub fn from_message(chat_id: i64, message: &Message) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id: message.chat.get_id(),
             message_id: message.message_id,
             disable_notification: false,
+            protect_content: false,
         }
     }
 }
@@ -142,6 +283,10 @@ impl ForwardMessage {
 pub struct CopyMessage {
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Unique identifier for the chat where the original message was sent.
     pub from_chat_id: i64,
     /// Message identifier in the chat specified in from_chat_id
@@ -157,12 +302,22 @@ pub struct CopyMessage {
     /// Mode for parsing entities in the new caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -175,13 +330,17 @@ impl CopyMessage {
     pub fn new(chat_id: i64, from_chat_id: i64, message_id: i64) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id,
             message_id,
             caption: None,
             caption_entities: None,
             parse_mode: None,
+            show_caption_above_media: None,
             disable_notification: false,
+            protect_content: false,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -190,13 +349,17 @@ impl CopyMessage {
     pub fn from_message(chat_id: i64, from: &Message) -> Self {
         Self {
             chat_id,
+            message_thread_id: None,
             from_chat_id: from.chat.get_id(),
             message_id: from.message_id,
             caption: None,
             caption_entities: None,
             parse_mode: None,
+            show_caption_above_media: None,
             disable_notification: false,
+            protect_content: false,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -210,8 +373,16 @@ impl CopyMessage {
 /// ../../api/trait.API.html#method.send_photo
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendPhoto {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Photo to send. Pass a file_id as String to send a photo that exists on
     /// the Telegram servers (recommended), pass an HTTP URL as a String for
     /// Telegram to get a photo from the Internet
@@ -228,12 +399,29 @@ pub struct SendPhoto {
     /// fixed-width text or inline URLs in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the media needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -245,13 +433,20 @@ pub struct SendPhoto {
 impl SendPhoto {
     pub fn new(chat_id: i64, photo: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             photo: InputFile::String(photo),
             caption: None,
             caption_entities: None,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -259,13 +454,20 @@ impl SendPhoto {
 
     pub fn from_photo_size(chat_id: i64, photo: &PhotoSize) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             photo: InputFile::String(photo.file_id.clone()),
             caption: None,
             caption_entities: None,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -273,13 +475,20 @@ impl SendPhoto {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             photo: InputFile::from_path(path)?,
             caption: None,
             caption_entities: None,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -293,8 +502,16 @@ impl SendPhoto {
 /// ../../api/trait.API.html#method.send_audio
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendAudio {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Audio to send. Pass a file_id as String to send an audio file that
     /// exists on the Telegram servers (recommended), pass an HTTP URL as a
     /// String for Telegram to get an audio file from the Internet
@@ -330,9 +547,20 @@ pub struct SendAudio {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -344,7 +572,9 @@ pub struct SendAudio {
 impl SendAudio {
     pub fn new(chat_id: i64, audio: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             audio: InputFile::String(audio),
             thumb: None,
             caption: None,
@@ -354,7 +584,10 @@ impl SendAudio {
             duration: None,
             title: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -362,7 +595,9 @@ impl SendAudio {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             audio: InputFile::from_path(path)?,
             thumb: None,
             caption: None,
@@ -372,7 +607,10 @@ impl SendAudio {
             title: None,
             parse_mode: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -386,8 +624,16 @@ impl SendAudio {
 /// ../../api/trait.API.html#method.send_document
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendDocument {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Document to send. Pass a file_id as String to send a photo that exists
     /// on the Telegram servers (recommended), pass an HTTP URL as a String
     /// for Telegram to get a document from the Internet
@@ -417,9 +663,20 @@ pub struct SendDocument {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -431,15 +688,20 @@ pub struct SendDocument {
 impl SendDocument {
     pub fn new(chat_id: i64, document: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             document: InputFile::String(document),
             thumb: None,
             caption: None,
             caption_entities: None,
             parse_mode: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             disable_content_type_detection: false,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -447,15 +709,20 @@ impl SendDocument {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             document: InputFile::from_path(path)?,
             thumb: None,
             caption: None,
             caption_entities: None,
             parse_mode: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             disable_content_type_detection: false,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -469,8 +736,16 @@ impl SendDocument {
 /// ../../api/trait.API.html#method.send_video
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendVideo {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Video to send. Pass a file_id as String to send an video file that
     /// exists on the Telegram servers (recommended), pass an HTTP URL as a
     /// String for Telegram to get an video file from the Internet
@@ -509,14 +784,31 @@ pub struct SendVideo {
     /// fixed-width text or inline URLs in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the media needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the uploaded video is suitable for streaming
     pub supports_streaming: bool,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -528,7 +820,9 @@ pub struct SendVideo {
 impl SendVideo {
     pub fn new(chat_id: i64, video: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             video: InputFile::String(video),
             thumb: None,
             caption: None,
@@ -540,8 +834,13 @@ impl SendVideo {
             title: None,
             supports_streaming: false,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -549,7 +848,9 @@ impl SendVideo {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             video: InputFile::from_path(path)?,
             thumb: None,
             caption: None,
@@ -561,8 +862,13 @@ impl SendVideo {
             title: None,
             supports_streaming: false,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -576,8 +882,16 @@ impl SendVideo {
 /// ../../api/trait.API.html#method.send_animation
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendAnimation {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Animation to send. Pass a file_id as String to send an animation file
     /// that exists on the Telegram servers (recommended), pass an HTTP URL
     /// as a String for Telegram to get an animation file from the Internet
@@ -616,12 +930,29 @@ pub struct SendAnimation {
     /// fixed-width text or inline URLs in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    /// Pass True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    /// Pass True, if the media needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -633,7 +964,9 @@ pub struct SendAnimation {
 impl SendAnimation {
     pub fn new(chat_id: i64, animation: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             animation: InputFile::String(animation),
             thumb: None,
             caption: None,
@@ -644,8 +977,13 @@ impl SendAnimation {
             performer: None,
             title: None,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -653,7 +991,9 @@ impl SendAnimation {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             animation: InputFile::from_path(path)?,
             thumb: None,
             caption: None,
@@ -664,8 +1004,13 @@ impl SendAnimation {
             performer: None,
             title: None,
             parse_mode: None,
+            show_caption_above_media: None,
+            has_spoiler: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -679,8 +1024,16 @@ impl SendAnimation {
 /// ../../api/trait.API.html#method.send_voice
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendVoice {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Voice to send. Pass a file_id as String to send an voice file that
     /// exists on the Telegram servers (recommended), pass an HTTP URL as a
     /// String for Telegram to get an voice file from the Internet
@@ -703,9 +1056,20 @@ pub struct SendVoice {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -717,14 +1081,19 @@ pub struct SendVoice {
 impl SendVoice {
     pub fn new(chat_id: i64, voice: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             voice: InputFile::String(voice),
             caption: None,
             caption_entities: None,
             duration: None,
             parse_mode: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -732,14 +1101,19 @@ impl SendVoice {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             voice: InputFile::from_path(path)?,
             duration: None,
             caption: None,
             caption_entities: None,
             parse_mode: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -753,8 +1127,16 @@ impl SendVoice {
 /// ../../api/trait.API.html#method.send_video_note
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendVideoNote {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// VideoNote to send. Pass a file_id as String to send an video_note file
     /// that exists on the Telegram servers (recommended), pass an HTTP URL
     /// as a String for Telegram to get an video_note file from the Internet
@@ -775,9 +1157,20 @@ pub struct SendVideoNote {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -789,13 +1182,18 @@ pub struct SendVideoNote {
 impl SendVideoNote {
     pub fn new(chat_id: i64, note: String) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             video_note: InputFile::String(note),
             thumb: None,
             duration: None,
             length: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         }
@@ -803,13 +1201,18 @@ impl SendVideoNote {
 
     pub fn from_file<P: AsRef<Path>>(chat_id: i64, path: P) -> Result<Self> {
         Ok(Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             video_note: InputFile::from_path(path)?,
             thumb: None,
             duration: None,
             length: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
             reply_markup: None,
         })
@@ -823,17 +1226,36 @@ impl SendVideoNote {
 /// ../../api/trait.API.html#method.send_media_group
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendMediaGroup {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Photos, videos, documents or audios as an album to be send, amount must
     /// be 2-10
     pub media: Vec<InputMedia>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -842,15 +1264,90 @@ pub struct SendMediaGroup {
 impl SendMediaGroup {
     pub fn new(chat_id: i64, media: Vec<InputMedia>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id,
+            message_thread_id: None,
             media,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             reply_to_message_id: None,
+            reply_parameters: None,
             allow_sending_without_reply: false,
         }
     }
 }
 
+/// struct for holding data needed to call
+/// [`send_paid_media`]
+///
+/// [`send_paid_media`]:
+/// ../../api/trait.API.html#method.send_paid_media
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SendPaidMedia {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// The number of Telegram Stars that must be paid to buy access to the
+    /// media; 1-10000
+    pub star_count: i64,
+    /// The paid media to be sent, amount must be 1-10
+    pub media: Vec<InputPaidMedia>,
+    /// Bot-defined paid media payload, 0-128 bytes. This will not be displayed
+    /// to the user, use it for your internal processes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    /// Media caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the media caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass True, if the caption must be shown above the message media
+    pub show_caption_above_media: bool,
+    /// Sends the message silently. Users will receive a notification with no
+    /// sound.
+    pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Description of the message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendPaidMedia {
+    /// function to create a new `SendPaidMedia` object, setting all optional
+    /// fields to None
+    pub fn new(chat_id: i64, star_count: i64, media: Vec<InputPaidMedia>) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id,
+            star_count,
+            media,
+            payload: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            disable_notification: false,
+            protect_content: false,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+}
+
 /// struct for holding data needed to call
 /// [`send_location`]
 ///
@@ -858,8 +1355,16 @@ impl SendMediaGroup {
 /// ../../api/trait.API.html#method.send_location
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendLocation {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Latitude of the location
     pub latitude: f64,
     /// Longitude of the location
@@ -879,9 +1384,20 @@ pub struct SendLocation {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -897,8 +1413,16 @@ pub struct SendLocation {
 /// ../../api/trait.API.html#method.send_venue
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendVenue {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Latitude of the venue
     pub latitude: f64,
     /// Longitude of the venue
@@ -918,9 +1442,20 @@ pub struct SendVenue {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -936,8 +1471,16 @@ pub struct SendVenue {
 /// ../../api/trait.API.html#method.send_contact
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendContact {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Contact's phone number
     pub phone_number: String,
     /// Contact's first name
@@ -951,9 +1494,20 @@ pub struct SendContact {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -969,8 +1523,16 @@ pub struct SendContact {
 /// ../../api/trait.API.html#method.send_poll
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendPoll {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Poll question, 1-255 characters
     pub question: String,
     /// A JSON-serialized list of answer options, 2-10 strings 1-300 characters
@@ -1015,9 +1577,20 @@ pub struct SendPoll {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -1033,8 +1606,16 @@ pub struct SendPoll {
 /// ../../api/trait.API.html#method.send_dice
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendDice {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Emoji on which the dice throw animation is based.
     /// Currently, must be one of “🎲”, “🎯”, “🏀”, “⚽”, “🎳”, or “🎰”.
     /// Dice can have values 1-6 for “🎲”, “🎯” and “🎳”, values 1-5 for “🏀”
@@ -1044,9 +1625,20 @@ pub struct SendDice {
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Pass True, if the message should be sent even if the specified
     /// replied-to message is not found
     pub allow_sending_without_reply: bool,
@@ -1062,8 +1654,40 @@ pub struct SendDice {
 /// ../../api/trait.API.html#method.send_chat_action
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendChatAction {
+    /// Unique identifier of the business connection on behalf of which the
+    /// action will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Type of action to broadcast.
     pub action: ChatAction,
 }
+
+macro_rules! impl_formatted_caption {
+    ($name:ident) => {
+        impl $name {
+            /// Sets the caption along with the entities describing its
+            /// formatting, removing the parse mode
+            pub fn set_formatted_caption(&mut self, caption: &Text) -> &mut Self {
+                let (caption, entities) = caption.to_entities();
+                self.caption = Some(caption);
+                self.caption_entities = Some(entities).filter(|e| !e.is_empty());
+                self.parse_mode = None;
+                self
+            }
+        }
+    };
+}
+
+impl_formatted_caption!(CopyMessage);
+impl_formatted_caption!(SendPhoto);
+impl_formatted_caption!(SendAudio);
+impl_formatted_caption!(SendDocument);
+impl_formatted_caption!(SendVideo);
+impl_formatted_caption!(SendAnimation);
+impl_formatted_caption!(SendVoice);
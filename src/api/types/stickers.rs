@@ -1,6 +1,7 @@
-use super::InputFile;
-use crate::model::{MaskPosition, ReplyMarkup};
+use super::{InputFile, ReplyParameters};
+use crate::model::{MaskPosition, ReplyMarkup, StickerFormat, StickerType};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// struct for holding data needed to call
 /// [`send_sticker`]
@@ -9,18 +10,42 @@ use serde::{Deserialize, Serialize};
 /// ../../api/trait.API.html#method.send_sticker
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SendSticker {
+    /// Unique identifier of the business connection on behalf of which the
+    /// message will be sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
     pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
     /// Sticker to send. Pass a file_id as String to send a file that exists on
     /// the Telegram servers (recommended), pass an HTTP URL as a String for
-    /// Telegram to get a .WEBP file from the Internet, or upload a new one
+    /// Telegram to get a .WEBP sticker from the Internet, or upload a new
+    /// .WEBP, .TGS, or .WEBM sticker. Video and animated stickers can't be
+    /// sent via an HTTP URL.
     pub sticker: InputFile,
+    /// Emoji associated with the sticker; only for just uploaded stickers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
     /// Sends the message silently. Users will receive a notification with no
     /// sound.
     pub disable_notification: bool,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: bool,
+    /// Unique identifier of the message effect to be added to the message;
+    /// for private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
+    /// Description of the message to reply to, replaces
+    /// `reply_to_message_id` and allows quoting and replying to messages in
+    /// other chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
@@ -46,10 +71,46 @@ pub struct GetStickerSet {
 pub struct UploadStickerFile {
     /// User identifier of sticker file owner
     pub user_id: i64,
-    /// Png image with the sticker, must be up to 512 kilobytes in size,
-    /// dimensions must not exceed 512px, and either width or height must be
-    /// exactly 512px.
-    pub png_sticker: InputFile,
+    /// A file with the sticker in .WEBP, .PNG, .TGS, or .WEBM format. See <https://core.telegram.org/stickers> for technical requirements
+    pub sticker: InputFile,
+    /// Format of the sticker
+    pub sticker_format: StickerFormat,
+}
+
+/// This object describes a sticker to be added to a sticker set.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InputSticker {
+    /// The added sticker. Pass a `file_id` as a String to send a file that
+    /// already exists on the Telegram servers, pass an HTTP URL as a String
+    /// for Telegram to get a file from the Internet, or upload a new one.
+    /// Animated and video stickers can't be uploaded via HTTP URL.
+    pub sticker: InputFile,
+    /// Format of the added sticker
+    pub format: StickerFormat,
+    /// List of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+    /// Position where the mask should be placed on faces. For `mask` stickers
+    /// only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// List of 0-20 search keywords for the sticker with total length of up
+    /// to 64 characters. For `regular` and `custom_emoji` stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+
+impl InputSticker {
+    /// function to create a new `InputSticker` object, setting all optional
+    /// fields to None
+    pub fn new<T: Into<InputFile>>(sticker: T, format: StickerFormat, emoji_list: &[&str]) -> Self {
+        Self {
+            sticker: sticker.into(),
+            format,
+            emoji_list: emoji_list.iter().map(|e| (*e).to_owned()).collect(),
+            mask_position: None,
+            keywords: None,
+        }
+    }
 }
 
 /// struct for holding data needed to call
@@ -69,24 +130,61 @@ pub struct CreateNewStickerSet {
     pub name: String,
     /// Sticker set title, 1-64 characters
     pub title: String,
-    /// PNG image with the sticker, must be up to 512 kilobytes in size,
-    /// dimensions must not exceed 512px, and either width or height must be
-    /// exactly 512px. Pass a file_id as a String to send a file that
-    /// already exists on the Telegram servers, ass an HTTP URL as a String
-    /// for Telegram to get a file from the Internet, or upload a new one
+    /// A list of 1-50 initial stickers to be added to the sticker set
+    pub stickers: Vec<InputSticker>,
+    /// Type of stickers in the set. By default, a regular sticker set is
+    /// created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub png_sticker: Option<InputFile>,
-    /// TGS animation with the sticker, uploaded using multipart/form-data.
-    /// See <https://core.telegram.org/animated_stickers#technical-requirements> for technical requirements
+    pub sticker_type: Option<StickerType>,
+    /// Pass True if stickers in the sticker set must be repainted to the color
+    /// of text when used in messages, the accent color if used as emoji status,
+    /// white on chat photos, or another appropriate color based on context;
+    /// for custom emoji sticker sets only
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tgs_sticker: Option<InputFile>,
-    /// One or more emoji corresponding to the sticker
-    pub emojis: String,
-    /// Pass True, if a set of mask stickers should be created
-    pub contains_masks: bool,
-    /// position where the mask should be placed on faces
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mask_position: Option<MaskPosition>,
+    pub needs_repainting: Option<bool>,
+}
+
+impl CreateNewStickerSet {
+    /// function to create a new `CreateNewStickerSet` object with the given
+    /// stickers, setting all optional fields to None
+    pub fn new(user_id: i64, name: &str, title: &str, stickers: Vec<InputSticker>) -> Self {
+        Self {
+            user_id,
+            name: name.to_owned(),
+            title: title.to_owned(),
+            stickers,
+            sticker_type: None,
+            needs_repainting: None,
+        }
+    }
+
+    /// function to create a new `CreateNewStickerSet` object with a single
+    /// PNG sticker, the emojis are used as a single entry of its emoji list
+    #[deprecated(note = "use `CreateNewStickerSet::new` with a static `InputSticker`")]
+    pub fn png_sticker<T: Into<InputFile>>(
+        user_id: i64,
+        name: &str,
+        title: &str,
+        png_sticker: T,
+        emojis: &str,
+    ) -> Self {
+        let sticker = InputSticker::new(png_sticker, StickerFormat::Static, &[emojis]);
+        Self::new(user_id, name, title, vec![sticker])
+    }
+
+    /// function to create a new `CreateNewStickerSet` object with a single
+    /// TGS sticker, the emojis are used as a single entry of its emoji list
+    #[deprecated(note = "use `CreateNewStickerSet::new` with an animated `InputSticker`")]
+    pub fn tgs_sticker<T: Into<InputFile>>(
+        user_id: i64,
+        name: &str,
+        title: &str,
+        tgs_sticker: T,
+        emojis: &str,
+    ) -> Self {
+        let sticker = InputSticker::new(tgs_sticker, StickerFormat::Animated, &[emojis]);
+        Self::new(user_id, name, title, vec![sticker])
+    }
 }
 
 /// struct for holding data needed to call
@@ -100,22 +198,64 @@ pub struct AddStickerToSet {
     pub user_id: i64,
     /// Name of the sticker set
     pub name: String,
-    /// PNG image with the sticker, must be up to 512 kilobytes in size,
-    /// dimensions must not exceed 512px, and either width or height must be
-    /// exactly 512px. Pass a file_id as a String to send a file that
-    /// already exists on the Telegram servers, ass an HTTP URL as a String
-    /// for Telegram to get a file from the Internet, or upload a new one
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub png_sticker: Option<InputFile>,
-    /// TGS animation with the sticker, uploaded using multipart/form-data.
-    /// See <https://core.telegram.org/animated_stickers#technical-requirements> for technical requirements
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tgs_sticker: Option<InputFile>,
-    /// One or more emoji corresponding to the sticker
-    pub emojis: String,
-    /// position where the mask should be placed on faces
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mask_position: Option<MaskPosition>,
+    /// Information about the added sticker. If exactly the same sticker had
+    /// already been added to the set, then the set isn't changed.
+    pub sticker: InputSticker,
+}
+
+impl AddStickerToSet {
+    /// function to create a new `AddStickerToSet` object
+    pub fn new(user_id: i64, name: &str, sticker: InputSticker) -> Self {
+        Self {
+            user_id,
+            name: name.to_owned(),
+            sticker,
+        }
+    }
+
+    /// function to create a new `AddStickerToSet` object adding a PNG
+    /// sticker, the emojis are used as a single entry of its emoji list
+    #[deprecated(note = "use `AddStickerToSet::new` with a static `InputSticker`")]
+    pub fn png_sticker<T: Into<InputFile>>(
+        user_id: i64,
+        name: &str,
+        png_sticker: T,
+        emojis: &str,
+    ) -> Self {
+        let sticker = InputSticker::new(png_sticker, StickerFormat::Static, &[emojis]);
+        Self::new(user_id, name, sticker)
+    }
+
+    /// function to create a new `AddStickerToSet` object adding a TGS
+    /// sticker, the emojis are used as a single entry of its emoji list
+    #[deprecated(note = "use `AddStickerToSet::new` with an animated `InputSticker`")]
+    pub fn tgs_sticker<T: Into<InputFile>>(
+        user_id: i64,
+        name: &str,
+        tgs_sticker: T,
+        emojis: &str,
+    ) -> Self {
+        let sticker = InputSticker::new(tgs_sticker, StickerFormat::Animated, &[emojis]);
+        Self::new(user_id, name, sticker)
+    }
+}
+
+/// struct for holding data needed to call
+/// [`replace_sticker_in_set`]
+///
+/// [`replace_sticker_in_set`]:
+/// ../../api/trait.API.html#method.replace_sticker_in_set
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplaceStickerInSet {
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+    /// Sticker set name
+    pub name: String,
+    /// File identifier of the replaced sticker
+    pub old_sticker: String,
+    /// Information about the added sticker. If exactly the same sticker had
+    /// already been added to the set, then the set remains unchanged.
+    pub sticker: InputSticker,
 }
 
 /// struct for holding data needed to call
@@ -142,11 +282,93 @@ pub struct DeleteStickerFromSet {
     pub sticker: String,
 }
 
+/// struct for holding data needed to call
+/// [`set_sticker_emoji_list`]
+///
+/// [`set_sticker_emoji_list`]:
+/// ../../api/trait.API.html#method.set_sticker_emoji_list
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetStickerEmojiList {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A list of 1-20 emoji associated with the sticker
+    pub emoji_list: Vec<String>,
+}
+
+/// struct for holding data needed to call
+/// [`set_sticker_keywords`]
+///
+/// [`set_sticker_keywords`]:
+/// ../../api/trait.API.html#method.set_sticker_keywords
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetStickerKeywords {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// A list of 0-20 search keywords for the sticker with total length of up
+    /// to 64 characters
+    pub keywords: Vec<String>,
+}
+
+/// struct for holding data needed to call
+/// [`set_sticker_mask_position`]
+///
+/// [`set_sticker_mask_position`]:
+/// ../../api/trait.API.html#method.set_sticker_mask_position
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetStickerMaskPosition {
+    /// File identifier of the sticker
+    pub sticker: String,
+    /// The position where the mask should be placed on faces. Omit the
+    /// parameter to remove the mask position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+
+/// struct for holding data needed to call
+/// [`set_sticker_set_title`]
+///
+/// [`set_sticker_set_title`]:
+/// ../../api/trait.API.html#method.set_sticker_set_title
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetStickerSetTitle {
+    /// Sticker set name
+    pub name: String,
+    /// Sticker set title, 1-64 characters
+    pub title: String,
+}
+
+/// struct for holding data needed to call
+/// [`set_sticker_set_thumbnail`]
+///
+/// [`set_sticker_set_thumbnail`]:
+/// ../../api/trait.API.html#method.set_sticker_set_thumbnail
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetStickerSetThumbnail {
+    /// Sticker set name
+    pub name: String,
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+    /// A .WEBP or .PNG image with the thumbnail, must be up to 128 kilobytes
+    /// in size and have a width and height of exactly 100px, or a .TGS
+    /// animation with a thumbnail up to 32 kilobytes in size, or a WEBM video
+    /// with the thumbnail up to 32 kilobytes in size; see <https://core.telegram.org/stickers> for technical requirements.
+    /// Pass a `file_id` as a String to send a file that already exists on the
+    /// Telegram servers, pass an HTTP URL as a String for Telegram to get a
+    /// file from the Internet, or upload a new one. Animated and video sticker
+    /// set thumbnails can't be uploaded via HTTP URL. If omitted, then the
+    /// thumbnail is dropped and the first sticker is used as the thumbnail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<InputFile>,
+    /// Format of the thumbnail
+    pub format: StickerFormat,
+}
+
 /// struct for holding data needed to call
 /// [`set_sticker_set_thumb`]
 ///
 /// [`set_sticker_set_thumb`]:
 /// ../../api/trait.API.html#method.set_sticker_set_thumb
+#[deprecated(note = "use `SetStickerSetThumbnail`")]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SetStickerSetThumb {
     /// Sticker set name
@@ -156,10 +378,59 @@ pub struct SetStickerSetThumb {
     /// A PNG image with the thumbnail, must be up to 128 kilobytes in size and
     /// have width and height exactly 100px, or a TGS animation with the
     /// thumbnail up to 32 kilobytes in size; see <https://core.telegram.org/animated_stickers#technical-requirements> for animated sticker technical requirements.
-    /// Pass a file_id as a String to send a file that already exists on the
+    /// Pass a `file_id` as a String to send a file that already exists on the
     /// Telegram servers, pass an HTTP URL as a String for Telegram to get a
     /// file from the Internet, or upload a new one. Animated sticker set
     /// thumbnail can't be uploaded via HTTP URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<InputFile>,
 }
+
+#[allow(deprecated)]
+impl From<SetStickerSetThumb> for SetStickerSetThumbnail {
+    /// The format of the thumbnail is taken from the file name of an uploaded
+    /// thumbnail, other thumbnails are assumed to be PNG images
+    fn from(data: SetStickerSetThumb) -> Self {
+        let animated = match &data.thumb {
+            Some(InputFile::File(f)) => f.file_name.as_deref().is_some_and(|name| {
+                Path::new(name)
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("tgs"))
+            }),
+            _ => false,
+        };
+        Self {
+            name: data.name,
+            user_id: data.user_id,
+            thumbnail: data.thumb,
+            format: if animated {
+                StickerFormat::Animated
+            } else {
+                StickerFormat::Static
+            },
+        }
+    }
+}
+
+/// struct for holding data needed to call
+/// [`delete_sticker_set`]
+///
+/// [`delete_sticker_set`]:
+/// ../../api/trait.API.html#method.delete_sticker_set
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeleteStickerSet {
+    /// Sticker set name
+    pub name: String,
+}
+
+/// struct for holding data needed to call
+/// [`get_custom_emoji_stickers`]
+///
+/// [`get_custom_emoji_stickers`]:
+/// ../../api/trait.API.html#method.get_custom_emoji_stickers
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GetCustomEmojiStickers {
+    /// A list of custom emoji identifiers. At most 200 custom emoji
+    /// identifiers can be specified.
+    pub custom_emoji_ids: Vec<String>,
+}
@@ -92,4 +92,18 @@ pub enum UpdateType {
     Poll,
     #[serde(rename = "poll_answer")]
     PollAnswer,
+    #[serde(rename = "message_reaction")]
+    MessageReaction,
+    #[serde(rename = "message_reaction_count")]
+    MessageReactionCount,
+    #[serde(rename = "chat_join_request")]
+    ChatJoinRequest,
+    #[serde(rename = "business_connection")]
+    BusinessConnection,
+    #[serde(rename = "business_message")]
+    BusinessMessage,
+    #[serde(rename = "edited_business_message")]
+    EditedBusinessMessage,
+    #[serde(rename = "deleted_business_messages")]
+    DeletedBusinessMessages,
 }
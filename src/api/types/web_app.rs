@@ -0,0 +1,55 @@
+use super::InlineQueryResult;
+use crate::model::MenuButton;
+use serde::{Deserialize, Serialize};
+
+/// struct for holding data needed to call
+/// [`answer_web_app_query`]
+///
+/// [`answer_web_app_query`]:
+/// ../../api/trait.API.html#method.answer_web_app_query
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AnswerWebAppQuery {
+    /// Unique identifier for the query to be answered
+    pub web_app_query_id: String,
+    /// An object describing the message to be sent
+    pub result: InlineQueryResult,
+}
+
+impl AnswerWebAppQuery {
+    /// function to create a new `AnswerWebAppQuery` object
+    pub fn new<T: Into<InlineQueryResult>>(web_app_query_id: &str, result: T) -> Self {
+        Self {
+            web_app_query_id: web_app_query_id.to_owned(),
+            result: result.into(),
+        }
+    }
+}
+
+/// struct for holding data needed to call
+/// [`set_chat_menu_button`]
+///
+/// [`set_chat_menu_button`]:
+/// ../../api/trait.API.html#method.set_chat_menu_button
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SetChatMenuButton {
+    /// Unique identifier for the target private chat. If not specified,
+    /// default bot's menu button will be changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    /// The new bot's menu button. Defaults to [`MenuButton::Default`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton>,
+}
+
+/// struct for holding data needed to call
+/// [`get_chat_menu_button`]
+///
+/// [`get_chat_menu_button`]:
+/// ../../api/trait.API.html#method.get_chat_menu_button
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GetChatMenuButton {
+    /// Unique identifier for the target private chat. If not specified,
+    /// default bot's menu button will be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
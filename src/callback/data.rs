@@ -0,0 +1,119 @@
+use super::encoding::Compact;
+use crate::{model::InlineKeyboardButton, utils::result::TelegramError, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The maximum length of the data of a callback button in bytes
+pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+/// Typed data of a callback button, handled by the handler added for it using
+/// [`CallbackRouter::add_handler`].
+///
+/// The data is encoded as its [`PREFIX`] followed by a `:` and its fields in
+/// a compact json form, which leaves out the field names, like
+/// `vote:[12,"up"]`. As the fields are identified by their position, the
+/// data mustn't skip any fields when serializing. Telegram only allows
+/// [`MAX_CALLBACK_DATA_LEN`] bytes of data on a button, so keep the values
+/// short.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use telexide_fork::callback::CallbackData;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Vote {
+///     poll: u32,
+///     up: bool,
+/// }
+///
+/// impl CallbackData for Vote {
+///     const PREFIX: &'static str = "vote";
+/// }
+///
+/// let data = Vote { poll: 12, up: true }.encode().unwrap();
+/// assert_eq!(data, "vote:[12,true]");
+/// assert_eq!(Vote::decode(&data), Some(Vote { poll: 12, up: true }));
+/// ```
+///
+/// [`CallbackRouter::add_handler`]: struct.CallbackRouter.html#method.add_handler
+/// [`PREFIX`]: #associatedconstant.PREFIX
+/// [`MAX_CALLBACK_DATA_LEN`]: constant.MAX_CALLBACK_DATA_LEN.html
+pub trait CallbackData: Serialize + DeserializeOwned + Send + 'static {
+    /// The prefix identifying the type of the data, it can't contain a `:`
+    const PREFIX: &'static str;
+
+    /// Encodes the data to be put on a callback button
+    fn encode(&self) -> Result<String> {
+        encode_callback_data(Self::PREFIX, self)
+    }
+
+    /// Decodes the data of a callback query, returns `None` if it has
+    /// another prefix or isn't valid
+    fn decode(data: &str) -> Option<Self> {
+        decode_callback_data(Self::PREFIX, data)
+    }
+
+    /// Creates an inline keyboard button with the given text sending the data
+    /// when it is pressed
+    fn button(&self, text: &str) -> Result<InlineKeyboardButton> {
+        Ok(InlineKeyboardButton::callback(text, &self.encode()?))
+    }
+}
+
+/// Encodes a value as callback data with the given prefix, in the form used
+/// by [`CallbackData`]. Unit values are encoded as only the prefix.
+///
+/// [`CallbackData`]: trait.CallbackData.html
+pub fn encode_callback_data<T: Serialize + ?Sized>(prefix: &str, value: &T) -> Result<String> {
+    let value = serde_json::to_string(&Compact(value))?;
+    if value == "null" {
+        prefixed_callback_data(prefix, "")
+    } else {
+        prefixed_callback_data(prefix, &value)
+    }
+}
+
+/// Decodes callback data encoded using [`encode_callback_data`] with the given
+/// prefix, returns `None` if it has another prefix or isn't valid
+///
+/// [`encode_callback_data`]: fn.encode_callback_data.html
+pub fn decode_callback_data<T: DeserializeOwned>(prefix: &str, data: &str) -> Option<T> {
+    let value = strip_callback_prefix(prefix, data)?;
+    serde_json::from_str(if value.is_empty() { "null" } else { value }).ok()
+}
+
+/// Creates callback data consisting of the prefix and the value, separated by
+/// a `:`, or of only the prefix if the value is empty. It returns an error if
+/// it's longer than [`MAX_CALLBACK_DATA_LEN`] bytes.
+///
+/// [`MAX_CALLBACK_DATA_LEN`]: constant.MAX_CALLBACK_DATA_LEN.html
+pub fn prefixed_callback_data(prefix: &str, value: &str) -> Result<String> {
+    let data = if value.is_empty() {
+        prefix.to_owned()
+    } else {
+        format!("{prefix}:{value}")
+    };
+
+    if data.len() > MAX_CALLBACK_DATA_LEN {
+        return Err(TelegramError::InvalidArgument(format!(
+            "callback data can be at most {} bytes long, got {} bytes: {}",
+            MAX_CALLBACK_DATA_LEN,
+            data.len(),
+            data
+        ))
+        .into());
+    }
+    Ok(data)
+}
+
+/// Gets the value of callback data with the given prefix, returns `None` if
+/// it has another prefix
+pub fn strip_callback_prefix<'a>(prefix: &str, data: &'a str) -> Option<&'a str> {
+    let value = data.strip_prefix(prefix)?;
+    if value.is_empty() {
+        Some(value)
+    } else {
+        value.strip_prefix(':')
+    }
+}
@@ -0,0 +1,170 @@
+use super::{ignored_button, menu_button, Menu};
+use crate::{
+    callback::{Callback, CallbackHandlerFunc},
+    client::Context,
+    framework::CommandResult,
+    model::{InlineKeyboardButton, InlineKeyboardMarkup},
+    Result,
+};
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A month of days to pick a date from, with buttons to go to the previous
+/// and next month. When a day is pressed, its date is passed on to the pick
+/// handler.
+#[derive(Clone)]
+pub struct Calendar {
+    prefix: String,
+    on_pick: CallbackHandlerFunc<NaiveDate>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+}
+
+impl Calendar {
+    /// Creates a calendar using the given prefix for the data of its buttons
+    pub fn new(prefix: &str, on_pick: CallbackHandlerFunc<NaiveDate>) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            on_pick,
+            min_date: None,
+            max_date: None,
+        }
+    }
+
+    /// Sets the first date that can be picked
+    pub fn set_min_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Sets the last date that can be picked
+    pub fn set_max_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// returns true if the date can be picked
+    #[allow(clippy::unnecessary_map_or)] // `is_none_or` needs Rust 1.82
+    pub fn allows(&self, date: NaiveDate) -> bool {
+        self.min_date.map_or(true, |min| date >= min)
+            && self.max_date.map_or(true, |max| date <= max)
+    }
+
+    /// Renders the month the given date is in
+    #[allow(clippy::unnecessary_map_or)] // `is_none_or` needs Rust 1.82
+    pub fn render(&self, date: NaiveDate) -> Result<InlineKeyboardMarkup> {
+        let first = first_of_month(date);
+        let next = first_of_next_month(first);
+
+        let mut inline_keyboard = vec![
+            vec![ignored_button(
+                &format!("{} {}", MONTHS[first.month0() as usize], first.year()),
+                &self.prefix,
+            )],
+            WEEKDAYS
+                .iter()
+                .map(|day| ignored_button(day, &self.prefix))
+                .collect(),
+        ];
+
+        let mut week = vec![
+            ignored_button(" ", &self.prefix);
+            first.weekday().num_days_from_monday() as usize
+        ];
+        for day in first.iter_days().take_while(|day| *day < next) {
+            week.push(self.day_button(day)?);
+            if week.len() == 7 {
+                inline_keyboard.push(std::mem::take(&mut week));
+            }
+        }
+        if !week.is_empty() {
+            week.resize(7, ignored_button(" ", &self.prefix));
+            inline_keyboard.push(week);
+        }
+
+        let previous = first - Duration::days(1);
+        inline_keyboard.push(vec![
+            self.month_button(
+                "‹",
+                previous,
+                self.min_date.map_or(true, |min| previous >= min),
+            )?,
+            ignored_button(" ", &self.prefix),
+            self.month_button("›", next, self.max_date.map_or(true, |max| next <= max))?,
+        ]);
+
+        Ok(InlineKeyboardMarkup { inline_keyboard })
+    }
+
+    fn day_button(&self, day: NaiveDate) -> Result<InlineKeyboardButton> {
+        if self.allows(day) {
+            menu_button(&day.day().to_string(), &self.prefix, &format!("d:{day}"))
+        } else {
+            Ok(ignored_button(" ", &self.prefix))
+        }
+    }
+
+    // creates a button showing the month the date is in, if it can be shown
+    fn month_button(
+        &self,
+        text: &str,
+        date: NaiveDate,
+        shown: bool,
+    ) -> Result<InlineKeyboardButton> {
+        if shown {
+            menu_button(text, &self.prefix, &format!("m:{}", date.format("%Y-%m")))
+        } else {
+            Ok(ignored_button(" ", &self.prefix))
+        }
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.day0()))
+}
+
+fn first_of_next_month(first: NaiveDate) -> NaiveDate {
+    first_of_month(first + Duration::days(31))
+}
+
+#[async_trait]
+impl Menu for Calendar {
+    fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    async fn handle(&self, context: Context, callback: Callback, value: String) -> CommandResult {
+        match value.split_once(':') {
+            Some(("d", date)) => {
+                let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")?;
+                if !self.allows(date) {
+                    return Ok(());
+                }
+                (self.on_pick)(context, callback, date).await
+            },
+            Some(("m", month)) => {
+                let date = NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")?;
+                callback.edit_markup(Some(&self.render(date)?)).await?;
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use super::{menu_button, Menu, MenuItem, MenuItemsFunc};
+use crate::{
+    callback::{Callback, CallbackHandlerFunc},
+    client::Context,
+    framework::CommandResult,
+    model::InlineKeyboardMarkup,
+    utils::result::TelegramError,
+    Result,
+};
+use async_trait::async_trait;
+
+/// A list of items of which any number can be selected by pressing them,
+/// with a button passing the selected items on to the done handler.
+///
+/// The selection is kept in the data of the buttons, so a checklist can have
+/// at most [`MAX_ITEMS`] items.
+///
+/// [`MAX_ITEMS`]: #associatedconstant.MAX_ITEMS
+#[derive(Clone)]
+pub struct Checklist {
+    prefix: String,
+    items: MenuItemsFunc,
+    on_done: CallbackHandlerFunc<Vec<MenuItem>>,
+    done_label: String,
+    checked_mark: String,
+    unchecked_mark: String,
+}
+
+impl Checklist {
+    /// The maximum amount of items of a checklist
+    pub const MAX_ITEMS: usize = 64;
+
+    /// Creates a checklist using the given prefix for the data of its buttons
+    pub fn new(
+        prefix: &str,
+        items: MenuItemsFunc,
+        on_done: CallbackHandlerFunc<Vec<MenuItem>>,
+    ) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            items,
+            on_done,
+            done_label: "Done".to_owned(),
+            checked_mark: "✅".to_owned(),
+            unchecked_mark: "⬜".to_owned(),
+        }
+    }
+
+    /// Sets the text of the button passing the selected items on to the done
+    /// handler
+    pub fn set_done_label(&mut self, label: &str) -> &mut Self {
+        label.clone_into(&mut self.done_label);
+        self
+    }
+
+    /// Sets the marks shown in front of the selected and unselected items
+    pub fn set_marks(&mut self, checked: &str, unchecked: &str) -> &mut Self {
+        checked.clone_into(&mut self.checked_mark);
+        unchecked.clone_into(&mut self.unchecked_mark);
+        self
+    }
+
+    /// Renders the items, with the items at the given indices selected
+    pub fn render(&self, items: &[MenuItem], selected: &[usize]) -> Result<InlineKeyboardMarkup> {
+        let selection = selected
+            .iter()
+            .filter(|&&i| i < Self::MAX_ITEMS)
+            .fold(0_u64, |selection, i| selection | (1 << i));
+        self.render_selection(items, selection)
+    }
+
+    fn render_selection(&self, items: &[MenuItem], selection: u64) -> Result<InlineKeyboardMarkup> {
+        if items.len() > Self::MAX_ITEMS {
+            return Err(TelegramError::InvalidArgument(format!(
+                "a checklist can have at most {} items, got {}",
+                Self::MAX_ITEMS,
+                items.len()
+            ))
+            .into());
+        }
+
+        let mut inline_keyboard = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            let mark = if selection & (1 << i) == 0 {
+                &self.unchecked_mark
+            } else {
+                &self.checked_mark
+            };
+            inline_keyboard.push(vec![menu_button(
+                &format!("{mark} {}", item.label),
+                &self.prefix,
+                &format!("t:{:x}", selection ^ (1 << i)),
+            )?]);
+        }
+        inline_keyboard.push(vec![menu_button(
+            &self.done_label,
+            &self.prefix,
+            &format!("d:{selection:x}"),
+        )?]);
+
+        Ok(InlineKeyboardMarkup { inline_keyboard })
+    }
+}
+
+#[async_trait]
+impl Menu for Checklist {
+    fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    async fn handle(&self, context: Context, callback: Callback, value: String) -> CommandResult {
+        let (done, selection) = match value.split_once(':') {
+            Some(("t", selection)) => (false, selection),
+            Some(("d", selection)) => (true, selection),
+            _ => return Ok(()),
+        };
+        let selection = u64::from_str_radix(selection, 16)?;
+        let items = (self.items)(context.clone(), callback.clone()).await?;
+
+        if done {
+            let selected = items
+                .into_iter()
+                .take(Self::MAX_ITEMS)
+                .enumerate()
+                .filter(|(i, _)| selection & (1 << i) != 0)
+                .map(|(_, item)| item)
+                .collect();
+            (self.on_done)(context, callback, selected).await
+        } else {
+            callback
+                .edit_markup(Some(&self.render_selection(&items, selection)?))
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::{menu_button, Menu};
+use crate::{
+    callback::{Callback, CallbackHandlerFunc},
+    client::Context,
+    framework::CommandResult,
+    model::InlineKeyboardMarkup,
+    Result,
+};
+use async_trait::async_trait;
+
+/// The answer given to a [`ConfirmDialog`]
+///
+/// [`ConfirmDialog`]: struct.ConfirmDialog.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Confirmation {
+    /// true if the confirm button was pressed, false if the cancel button was
+    pub confirmed: bool,
+    /// The payload the dialog was rendered with, identifying what is
+    /// confirmed
+    pub payload: String,
+}
+
+/// A confirm and a cancel button, passing the answer on to the answer
+/// handler. By default the buttons are removed once one of them is pressed.
+#[derive(Clone)]
+pub struct ConfirmDialog {
+    prefix: String,
+    on_answer: CallbackHandlerFunc<Confirmation>,
+    confirm_label: String,
+    cancel_label: String,
+    remove_keyboard: bool,
+}
+
+impl ConfirmDialog {
+    /// Creates a dialog using the given prefix for the data of its buttons
+    pub fn new(prefix: &str, on_answer: CallbackHandlerFunc<Confirmation>) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            on_answer,
+            confirm_label: "Confirm".to_owned(),
+            cancel_label: "Cancel".to_owned(),
+            remove_keyboard: true,
+        }
+    }
+
+    /// Sets the texts of the confirm and cancel buttons
+    pub fn set_labels(&mut self, confirm: &str, cancel: &str) -> &mut Self {
+        confirm.clone_into(&mut self.confirm_label);
+        cancel.clone_into(&mut self.cancel_label);
+        self
+    }
+
+    /// Sets whether the buttons are removed once one of them is pressed
+    pub fn set_remove_keyboard(&mut self, remove_keyboard: bool) -> &mut Self {
+        self.remove_keyboard = remove_keyboard;
+        self
+    }
+
+    /// Renders the buttons, the payload is passed on to the answer handler
+    /// and is put in the data of the buttons, so it should be short
+    pub fn render(&self, payload: &str) -> Result<InlineKeyboardMarkup> {
+        Ok(InlineKeyboardMarkup {
+            inline_keyboard: vec![vec![
+                menu_button(&self.confirm_label, &self.prefix, &format!("y:{payload}"))?,
+                menu_button(&self.cancel_label, &self.prefix, &format!("n:{payload}"))?,
+            ]],
+        })
+    }
+}
+
+#[async_trait]
+impl Menu for ConfirmDialog {
+    fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    async fn handle(&self, context: Context, callback: Callback, value: String) -> CommandResult {
+        let (confirmed, payload) = match value.split_once(':') {
+            Some(("y", payload)) => (true, payload),
+            Some(("n", payload)) => (false, payload),
+            _ => return Ok(()),
+        };
+
+        if self.remove_keyboard {
+            callback.edit_markup(None).await?;
+        }
+
+        (self.on_answer)(
+            context,
+            callback,
+            Confirmation {
+                confirmed,
+                payload: payload.to_owned(),
+            },
+        )
+        .await
+    }
+}
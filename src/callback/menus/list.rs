@@ -0,0 +1,181 @@
+use super::{ignored_button, menu_button, Menu, MenuItem, MenuItemsFunc};
+use crate::{
+    callback::{Callback, CallbackHandlerFunc},
+    client::Context,
+    framework::CommandResult,
+    model::InlineKeyboardMarkup,
+    Result,
+};
+use async_trait::async_trait;
+
+/// A list of items shown a page at a time, with buttons to go to the previous
+/// and next page. When an item is pressed, its value is passed on to the
+/// select handler.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use telexide_fork::{
+///     api::types::SendMessage,
+///     callback::{
+///         menus::{MenuItem, PaginatedList},
+///         Callback,
+///     },
+///     model::ReplyMarkup,
+///     prelude::*,
+/// };
+///
+/// #[prepare_listener]
+/// async fn fruits(_ctx: Context, _callback: Callback) -> telexide_fork::Result<Vec<MenuItem>> {
+///     Ok(vec![MenuItem::new("Apple", "apple"), MenuItem::new("Pear", "pear")])
+/// }
+///
+/// #[prepare_listener]
+/// async fn picked(_ctx: Context, callback: Callback, fruit: String) -> CommandResult {
+///     callback.answer_text(&format!("you picked {}", fruit)).await?;
+///     Ok(())
+/// }
+///
+/// fn fruit_list() -> PaginatedList {
+///     let mut list = PaginatedList::new("fruit", fruits, picked);
+///     list.set_page_size(8);
+///     list
+/// }
+///
+/// #[command(description = "pick a fruit")]
+/// async fn fruit(ctx: Context, message: Message) -> CommandResult {
+///     let items = vec![MenuItem::new("Apple", "apple"), MenuItem::new("Pear", "pear")];
+///     let markup = fruit_list().render(&items, 0)?;
+///
+///     let mut data = SendMessage::new(message.chat.get_id(), "Pick a fruit");
+///     data.set_reply_markup(&ReplyMarkup::InlineKeyboardMarkup(markup));
+///     ctx.api.send_message(data).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PaginatedList {
+    prefix: String,
+    items: MenuItemsFunc,
+    on_select: CallbackHandlerFunc<String>,
+    page_size: usize,
+    columns: usize,
+    previous_label: String,
+    next_label: String,
+}
+
+impl PaginatedList {
+    /// Creates a list using the given prefix for the data of its buttons,
+    /// which shows 5 items in a single column on every page
+    pub fn new(prefix: &str, items: MenuItemsFunc, on_select: CallbackHandlerFunc<String>) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            items,
+            on_select,
+            page_size: 5,
+            columns: 1,
+            previous_label: "«".to_owned(),
+            next_label: "»".to_owned(),
+        }
+    }
+
+    /// Sets the amount of items shown on every page
+    pub fn set_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Sets the amount of items shown next to each other on every row
+    pub fn set_columns(&mut self, columns: usize) -> &mut Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the texts of the buttons going to the previous and next page
+    pub fn set_navigation_labels(&mut self, previous: &str, next: &str) -> &mut Self {
+        previous.clone_into(&mut self.previous_label);
+        next.clone_into(&mut self.next_label);
+        self
+    }
+
+    /// get the amount of items shown on every page
+    pub fn get_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// get the amount of pages needed to show the given amount of items
+    pub fn page_count(&self, items: usize) -> usize {
+        items.div_ceil(self.page_size).max(1)
+    }
+
+    /// Renders the given page of the items, starting at 0. A page after the
+    /// last one shows the last page.
+    pub fn render(&self, items: &[MenuItem], page: usize) -> Result<InlineKeyboardMarkup> {
+        let pages = self.page_count(items.len());
+        let page = page.min(pages - 1);
+
+        let mut inline_keyboard = Vec::new();
+        for row in items
+            .iter()
+            .skip(page * self.page_size)
+            .take(self.page_size)
+            .collect::<Vec<_>>()
+            .chunks(self.columns)
+        {
+            inline_keyboard.push(
+                row.iter()
+                    .map(|item| {
+                        menu_button(&item.label, &self.prefix, &format!("s:{}", item.value))
+                    })
+                    .collect::<Result<_>>()?,
+            );
+        }
+
+        if pages > 1 {
+            let mut navigation = Vec::new();
+            if page > 0 {
+                navigation.push(menu_button(
+                    &self.previous_label,
+                    &self.prefix,
+                    &format!("p:{}", page - 1),
+                )?);
+            }
+            navigation.push(ignored_button(
+                &format!("{}/{}", page + 1, pages),
+                &self.prefix,
+            ));
+            if page + 1 < pages {
+                navigation.push(menu_button(
+                    &self.next_label,
+                    &self.prefix,
+                    &format!("p:{}", page + 1),
+                )?);
+            }
+            inline_keyboard.push(navigation);
+        }
+
+        Ok(InlineKeyboardMarkup { inline_keyboard })
+    }
+}
+
+#[async_trait]
+impl Menu for PaginatedList {
+    fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    async fn handle(&self, context: Context, callback: Callback, value: String) -> CommandResult {
+        match value.split_once(':') {
+            Some(("s", item)) => (self.on_select)(context, callback, item.to_owned()).await,
+            Some(("p", page)) => {
+                let page = page.parse()?;
+                let items = (self.items)(context, callback.clone()).await?;
+                callback
+                    .edit_markup(Some(&self.render(&items, page)?))
+                    .await?;
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Reusable inline keyboard menus, which render their markup, handle the
+//! callback queries of their buttons and edit the message in place.
+//!
+//! A menu is added to a [`CallbackRouter`] using [`add_menu`], after which its
+//! markup can be sent along with a message, like
+//! `SendMessage::new(chat_id, "Pick a fruit").set_reply_markup(...)`. The
+//! state of a menu, like the page that is shown, is kept in the data of its
+//! buttons, so menus keep working after the bot restarts.
+//!
+//! The following menus are available:
+//!
+//! | Menu              | Description                                               |
+//! |-------------------|-----------------------------------------------------------|
+//! | [`PaginatedList`] | A list of items shown a page at a time to pick one from   |
+//! | [`ConfirmDialog`] | A confirm and cancel button                               |
+//! | [`Checklist`]     | A list of items to select any number of                   |
+//! | [`Calendar`]      | A month of days to pick a date from                       |
+//!
+//! [`CallbackRouter`]: ../struct.CallbackRouter.html
+//! [`add_menu`]: ../struct.CallbackRouter.html#method.add_menu
+//! [`PaginatedList`]: struct.PaginatedList.html
+//! [`ConfirmDialog`]: struct.ConfirmDialog.html
+//! [`Checklist`]: struct.Checklist.html
+//! [`Calendar`]: struct.Calendar.html
+
+mod calendar;
+mod checklist;
+mod confirm;
+mod list;
+
+pub use calendar::Calendar;
+pub use checklist::Checklist;
+pub use confirm::{ConfirmDialog, Confirmation};
+pub use list::PaginatedList;
+
+use super::{prefixed_callback_data, Callback};
+use crate::{client::Context, framework::CommandResult, model::InlineKeyboardButton, Result};
+use async_trait::async_trait;
+use std::{future::Future, pin::Pin};
+
+// the value of the buttons that only show something, like the current page
+const IGNORED: &str = "i";
+
+/// An inline keyboard menu handling the callback queries of its buttons
+#[async_trait]
+pub trait Menu: Send + Sync + 'static {
+    /// The prefix of the data of the buttons of the menu, it can't contain a
+    /// `:`
+    fn get_prefix(&self) -> &str;
+
+    /// Handles a press on one of the buttons of the menu, the value is the
+    /// data of the button after the prefix and the `:` following it
+    async fn handle(&self, context: Context, callback: Callback, value: String) -> CommandResult;
+}
+
+/// An item of a [`PaginatedList`] or [`Checklist`]
+///
+/// [`PaginatedList`]: struct.PaginatedList.html
+/// [`Checklist`]: struct.Checklist.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuItem {
+    /// The text shown on the button of the item
+    pub label: String,
+    /// The value identifying the item, it is put in the data of the button
+    /// so it should be short
+    pub value: String,
+}
+
+impl MenuItem {
+    pub fn new(label: &str, value: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            value: value.to_owned(),
+        }
+    }
+}
+
+/// The future returned by a [`MenuItemsFunc`]
+pub type MenuItemsOutcome = Pin<Box<dyn Future<Output = Result<Vec<MenuItem>>> + Send>>;
+
+/// A function loading the items of a menu when it is rendered again after a
+/// button has been pressed, it receives a [`Context`] and the [`Callback`] of
+/// the button. Wrap an async function with `#[prepare_listener]` for easier
+/// development.
+///
+/// It has to return the same items in the same order as the ones the menu
+/// was rendered with before.
+pub type MenuItemsFunc = fn(Context, Callback) -> MenuItemsOutcome;
+
+// creates a button of a menu with the given value as the data after the prefix
+fn menu_button(text: &str, prefix: &str, value: &str) -> Result<InlineKeyboardButton> {
+    Ok(InlineKeyboardButton::callback(
+        text,
+        &prefixed_callback_data(prefix, value)?,
+    ))
+}
+
+// creates a button of a menu that does nothing when it is pressed
+fn ignored_button(text: &str, prefix: &str) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(text, &format!("{prefix}:{IGNORED}"))
+}
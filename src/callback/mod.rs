@@ -0,0 +1,22 @@
+//! Routing of callback queries, sent when a user presses a button of an
+//! inline keyboard, to typed handlers.
+//!
+//! The data of a callback button identifies what handler it is for using a
+//! prefix, like `vote` in `vote:[12,true]`. Implement [`CallbackData`] for the
+//! data of your buttons to encode it in this form, and create the buttons
+//! using [`CallbackData::button`]. Handlers for the data are added to a
+//! [`CallbackRouter`], which is set on the client using
+//! [`ClientBuilder::set_callback_router`].
+//!
+//! [`ClientBuilder::set_callback_router`]: ../client/struct.ClientBuilder.html#method.set_callback_router
+
+mod data;
+mod encoding;
+pub mod menus;
+mod router;
+
+pub use data::{
+    decode_callback_data, encode_callback_data, prefixed_callback_data, strip_callback_prefix,
+    CallbackData, MAX_CALLBACK_DATA_LEN,
+};
+pub use router::{Callback, CallbackHandlerFunc, CallbackOutcome, CallbackRouter};
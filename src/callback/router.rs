@@ -0,0 +1,358 @@
+use super::{menus::Menu, strip_callback_prefix, CallbackData};
+use crate::{
+    api::types::{AnswerCallbackQuery, EditMessageReplyMarkup, EditMessageText},
+    client::Context,
+    framework::CommandResult,
+    model::{CallbackQuery, InlineKeyboardMarkup, Message},
+    utils::result::TelegramError,
+    Result,
+};
+use log::{debug, warn};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// The future returned by a [`CallbackHandlerFunc`]
+pub type CallbackOutcome = Pin<Box<dyn Future<Output = CommandResult> + Send>>;
+
+/// A function handling callback queries with data of type `T`, it receives a
+/// [`Context`], the [`Callback`] and the decoded data and returns a pinned
+/// future. Wrap an async function with `#[prepare_listener]` for easier
+/// development.
+pub type CallbackHandlerFunc<T> = fn(Context, Callback, T) -> CallbackOutcome;
+
+type RouteHandler = Box<dyn Fn(Context, Callback, &str) -> Option<CallbackOutcome> + Send + Sync>;
+
+struct Route {
+    prefix: String,
+    handler: RouteHandler,
+}
+
+// the message a callback query can edit
+enum EditableMessage<'a> {
+    Chat(&'a Message),
+    Inline(&'a str),
+}
+
+/// Dispatches callback queries to handlers based on the prefix of their data.
+///
+/// Handlers are added for a [`CallbackData`] type using [`add_handler`],
+/// receiving the decoded data, or for a plain prefix using
+/// [`add_prefix_handler`], receiving the rest of the data as a string. The
+/// first handler added for the prefix of the data of a query handles it.
+///
+/// Telegram clients show a loading indicator until a callback query is
+/// answered, so queries that were not answered by their handler using the
+/// [`Callback`] are answered without a notification once it returns, unless
+/// this is disabled using [`set_auto_answer`]. The same goes for queries no
+/// handler has been added for.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serde::{Deserialize, Serialize};
+/// use telexide_fork::{
+///     callback::{Callback, CallbackData, CallbackRouter},
+///     prelude::*,
+/// };
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Vote {
+///     poll: u32,
+///     up: bool,
+/// }
+///
+/// impl CallbackData for Vote {
+///     const PREFIX: &'static str = "vote";
+/// }
+///
+/// #[prepare_listener]
+/// async fn vote(_ctx: Context, callback: Callback, vote: Vote) -> CommandResult {
+///     callback.answer_text(if vote.up { "upvoted" } else { "downvoted" }).await?;
+///     Ok(())
+/// }
+///
+/// let mut router = CallbackRouter::new();
+/// router.add_handler(vote);
+///
+/// let client = ClientBuilder::new()
+///     .set_token("token")
+///     .set_callback_router(std::sync::Arc::new(router))
+///     .build();
+/// ```
+///
+/// [`add_handler`]: #method.add_handler
+/// [`add_prefix_handler`]: #method.add_prefix_handler
+/// [`set_auto_answer`]: #method.set_auto_answer
+pub struct CallbackRouter {
+    routes: Vec<Route>,
+    auto_answer: bool,
+}
+
+impl CallbackRouter {
+    /// Creates a router without any handlers, which answers the queries its
+    /// handlers don't answer
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            auto_answer: true,
+        }
+    }
+
+    /// Adds a handler for the callback queries with data of type `T`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix of `T` is empty or contains a `:`
+    pub fn add_handler<T: CallbackData>(&mut self, handler: CallbackHandlerFunc<T>) -> &mut Self {
+        self.add_route(T::PREFIX, move |context, callback, data| {
+            T::decode(data).map(|data| handler(context, callback, data))
+        })
+    }
+
+    /// Adds a handler for the callback queries with data starting with the
+    /// prefix, it receives the data after the prefix and the `:` following it
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix is empty or contains a `:`
+    pub fn add_prefix_handler(
+        &mut self,
+        prefix: &str,
+        handler: CallbackHandlerFunc<String>,
+    ) -> &mut Self {
+        let owned_prefix = prefix.to_owned();
+        self.add_route(prefix, move |context, callback, data| {
+            strip_callback_prefix(&owned_prefix, data)
+                .map(|value| handler(context, callback, value.to_owned()))
+        })
+    }
+
+    fn add_route<F>(&mut self, prefix: &str, handler: F) -> &mut Self
+    where
+        F: Fn(Context, Callback, &str) -> Option<CallbackOutcome> + Send + Sync + 'static,
+    {
+        assert!(
+            !prefix.is_empty() && !prefix.contains(':'),
+            "invalid callback data prefix: {:?}",
+            prefix
+        );
+
+        self.routes.push(Route {
+            prefix: prefix.to_owned(),
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// Sets whether queries that were not answered by their handler are
+    /// answered once it returns, and queries without a handler right away,
+    /// true by default
+    pub fn set_auto_answer(&mut self, auto_answer: bool) -> &mut Self {
+        self.auto_answer = auto_answer;
+        self
+    }
+
+    /// get whether queries that were not answered by their handler are
+    /// answered once it returns
+    pub fn get_auto_answer(&self) -> bool {
+        self.auto_answer
+    }
+
+    /// get the prefixes handlers have been added for
+    pub fn get_prefixes(&self) -> Vec<&str> {
+        self.routes.iter().map(|r| r.prefix.as_str()).collect()
+    }
+
+    /// Adds a [`Menu`] handling the callback queries with data starting with
+    /// its prefix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix of the menu is empty or contains a `:`
+    ///
+    /// [`Menu`]: menus/trait.Menu.html
+    pub fn add_menu<M: Menu>(&mut self, menu: M) -> &mut Self {
+        let menu = Arc::new(menu);
+        let prefix = menu.get_prefix().to_owned();
+        self.add_route(&prefix.clone(), move |context, callback, data| {
+            let value = strip_callback_prefix(&prefix, data)?.to_owned();
+            let menu = menu.clone();
+            Some(Box::pin(async move {
+                menu.handle(context, callback, value).await
+            }))
+        })
+    }
+
+    /// Passes the query on to the handler added for the prefix of its data,
+    /// returns false if there is none
+    pub fn handle_query(&self, context: Context, query: CallbackQuery) -> bool {
+        let Some(data) = query.data.clone() else {
+            return false;
+        };
+        let Some(route) = self
+            .routes
+            .iter()
+            .find(|r| strip_callback_prefix(&r.prefix, &data).is_some())
+        else {
+            if self.auto_answer {
+                debug!("answering callback query without handler: {data}");
+                let callback = Callback::new(context, query);
+                tokio::spawn(async move {
+                    if let Err(e) = callback.answer().await {
+                        debug!("failed to answer callback query: {e}");
+                    }
+                });
+            }
+            return false;
+        };
+
+        let callback = Callback::new(context.clone(), query);
+        let outcome = (route.handler)(context, callback.clone(), &data);
+        let prefix = route.prefix.clone();
+        let auto_answer = self.auto_answer;
+
+        tokio::spawn(async move {
+            match outcome {
+                Some(outcome) => {
+                    if let Err(e) = outcome.await {
+                        warn!("callback handler {} returned error: {}", prefix, e.0);
+                    }
+                },
+                None => debug!("ignoring invalid callback data: {data}"),
+            }
+
+            if auto_answer && !callback.is_answered() {
+                if let Err(e) = callback.answer().await {
+                    debug!("failed to answer callback query: {e}");
+                }
+            }
+        });
+        true
+    }
+}
+
+/// A callback query received by a handler of the [`CallbackRouter`], used to
+/// answer it.
+///
+/// [`CallbackRouter`]: struct.CallbackRouter.html
+#[derive(Clone)]
+pub struct Callback {
+    /// The callback query that was received
+    pub query: CallbackQuery,
+    context: Context,
+    answered: Arc<AtomicBool>,
+}
+
+impl Callback {
+    pub(crate) fn new(context: Context, query: CallbackQuery) -> Self {
+        Self {
+            query,
+            context,
+            answered: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// get the message with the button that was pressed, if it is available
+    pub fn get_message(&self) -> Option<&Message> {
+        self.query.message.as_ref()
+    }
+
+    /// returns true if the query has been answered
+    pub fn is_answered(&self) -> bool {
+        self.answered.load(Ordering::Acquire)
+    }
+
+    /// Answers the query without showing anything to the user
+    pub async fn answer(&self) -> Result<bool> {
+        self.answer_with(self.new_answer()).await
+    }
+
+    /// Answers the query by showing the text as a notification at the top of
+    /// the chat
+    pub async fn answer_text(&self, text: &str) -> Result<bool> {
+        let mut answer = self.new_answer();
+        answer.text = Some(text.to_owned());
+        self.answer_with(answer).await
+    }
+
+    /// Answers the query by showing the text in an alert
+    pub async fn answer_alert(&self, text: &str) -> Result<bool> {
+        let mut answer = self.new_answer();
+        answer.text = Some(text.to_owned());
+        answer.show_alert = true;
+        self.answer_with(answer).await
+    }
+
+    /// Answers the query with the given answer, its `callback_query_id` is
+    /// set to the id of the query. Returns false without calling the api if
+    /// the query has already been answered, as it can only be answered once.
+    /// When the api returns an error the query can be answered again.
+    pub async fn answer_with(&self, mut answer: AnswerCallbackQuery) -> Result<bool> {
+        if self.is_answered() {
+            return Ok(false);
+        }
+
+        answer.callback_query_id = self.query.id.clone();
+        let answered = self.context.api.answer_callback_query(answer).await?;
+        self.answered.store(true, Ordering::Release);
+        Ok(answered)
+    }
+
+    /// Replaces the inline keyboard of the message with the button that was
+    /// pressed, removing it if the markup is `None`
+    pub async fn edit_markup(&self, markup: Option<&InlineKeyboardMarkup>) -> Result<()> {
+        let data = match self.get_editable_message()? {
+            EditableMessage::Chat(message) => EditMessageReplyMarkup::from_message(message, markup),
+            EditableMessage::Inline(id) => EditMessageReplyMarkup::from_inline_message(id, markup),
+        };
+        self.context.api.edit_message_reply_markup(data).await?;
+        Ok(())
+    }
+
+    /// Replaces the text and the inline keyboard of the message with the
+    /// button that was pressed
+    pub async fn edit_text(&self, text: &str, markup: Option<&InlineKeyboardMarkup>) -> Result<()> {
+        let mut data = match self.get_editable_message()? {
+            EditableMessage::Chat(message) => {
+                EditMessageText::new(message.chat.get_id(), message.message_id, text)
+            },
+            EditableMessage::Inline(id) => EditMessageText::from_inline_message(id, text),
+        };
+        data.reply_markup = markup.cloned();
+        self.context.api.edit_message_text(data).await?;
+        Ok(())
+    }
+
+    // gets the message with the button that was pressed, or the id of the
+    // message sent in inline mode when the button was attached to one
+    fn get_editable_message(&self) -> Result<EditableMessage<'_>> {
+        if let Some(message) = self.get_message() {
+            return Ok(EditableMessage::Chat(message));
+        }
+        if let Some(id) = &self.query.inline_message_id {
+            return Ok(EditableMessage::Inline(id));
+        }
+
+        Err(TelegramError::InvalidArgument(
+            "the message of the callback query is not available".to_owned(),
+        )
+        .into())
+    }
+
+    fn new_answer(&self) -> AnswerCallbackQuery {
+        AnswerCallbackQuery {
+            callback_query_id: self.query.id.clone(),
+            text: None,
+            show_alert: false,
+            url: None,
+            cache_time: None,
+        }
+    }
+}
@@ -1,7 +1,14 @@
-use super::{APIConnector, Client, EventHandlerFunc, RawEventHandlerFunc, WebhookOptions};
+use super::{
+    APIConnector, ChatJoinRequestHandlerFunc, Client, EventHandlerFunc, Extensions,
+    RawEventHandlerFunc, ReactionCountHandlerFunc, ReactionHandlerFunc, WebhookOptions,
+};
 use crate::{
     api::{types::UpdateType, APIClient},
+    callback::CallbackRouter,
+    dialogue::{DialogueFlow, Dialogues},
     framework::Framework,
+    inline::InlineRouter,
+    storage::{MemoryStorage, StateStorage},
 };
 
 use parking_lot::RwLock;
@@ -14,10 +21,18 @@ pub struct ClientBuilder {
     api_client: Option<Arc<Box<APIConnector>>>,
     webhook: Option<WebhookOptions>,
     framework: Option<Arc<Framework>>,
+    callback_router: Option<Arc<CallbackRouter>>,
+    inline_router: Option<Arc<InlineRouter>>,
     token: Option<String>,
     allowed_updates: Vec<UpdateType>,
     event_handler_funcs: Vec<EventHandlerFunc>,
     raw_event_handler_funcs: Vec<RawEventHandlerFunc>,
+    reaction_handler_funcs: Vec<ReactionHandlerFunc>,
+    reaction_count_handler_funcs: Vec<ReactionCountHandlerFunc>,
+    join_request_handler_funcs: Vec<ChatJoinRequestHandlerFunc>,
+    dialogue_flows: Vec<DialogueFlow>,
+    storage: Option<Arc<dyn StateStorage>>,
+    extensions: Extensions,
 }
 
 impl ClientBuilder {
@@ -29,10 +44,18 @@ impl ClientBuilder {
             hyper_client: None,
             webhook: None,
             framework: None,
+            callback_router: None,
+            inline_router: None,
             token: None,
             allowed_updates: Vec::new(),
             event_handler_funcs: Vec::new(),
             raw_event_handler_funcs: Vec::new(),
+            reaction_handler_funcs: Vec::new(),
+            reaction_count_handler_funcs: Vec::new(),
+            join_request_handler_funcs: Vec::new(),
+            dialogue_flows: Vec::new(),
+            storage: None,
+            extensions: Extensions::new(),
         }
     }
 
@@ -51,6 +74,24 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the router handling the callback queries sent when inline
+    /// keyboard buttons are pressed, see the [callback module] for more detail
+    ///
+    /// [callback module]: ../callback/index.html
+    pub fn set_callback_router(&mut self, router: Arc<CallbackRouter>) -> &mut Self {
+        self.callback_router = Some(router);
+        self
+    }
+
+    /// Sets the router handling the inline queries sent when users mention
+    /// your bot, see the [inline module] for more detail
+    ///
+    /// [inline module]: ../inline/index.html
+    pub fn set_inline_router(&mut self, router: Arc<InlineRouter>) -> &mut Self {
+        self.inline_router = Some(router);
+        self
+    }
+
     /// Sets the token to be used in authorizing the API requests of your bot
     pub fn set_token(&mut self, token: &str) -> &mut Self {
         self.token = Some(token.to_owned());
@@ -104,12 +145,138 @@ impl ClientBuilder {
         self
     }
 
+    /// Adds a [`ReactionHandlerFunc`] function for handling changes of the
+    /// reactions of users on messages.
+    ///
+    /// Telegram only sends these updates when they are explicitly allowed, so
+    /// [`UpdateType::MessageReaction`] is added to the allowed updates if any
+    /// are set, otherwise it has to be added using [`add_allowed_updates`].
+    ///
+    /// [`add_allowed_updates`]: #method.add_allowed_updates
+    pub fn add_reaction_handler_func(&mut self, handler: ReactionHandlerFunc) -> &mut Self {
+        self.reaction_handler_funcs.push(handler);
+        self
+    }
+
+    /// Adds a [`ReactionCountHandlerFunc`] function for handling changes of
+    /// the anonymous reactions on messages.
+    ///
+    /// Telegram only sends these updates when they are explicitly allowed, so
+    /// [`UpdateType::MessageReactionCount`] is added to the allowed updates if
+    /// any are set, otherwise it has to be added using
+    /// [`add_allowed_updates`].
+    ///
+    /// [`add_allowed_updates`]: #method.add_allowed_updates
+    pub fn add_reaction_count_handler_func(
+        &mut self,
+        handler: ReactionCountHandlerFunc,
+    ) -> &mut Self {
+        self.reaction_count_handler_funcs.push(handler);
+        self
+    }
+
+    /// Adds a [`ChatJoinRequestHandlerFunc`] function for handling requests
+    /// to join chats, [`UpdateType::ChatJoinRequest`] is added to the allowed
+    /// updates if any are set.
+    pub fn add_join_request_handler_func(
+        &mut self,
+        handler: ChatJoinRequestHandlerFunc,
+    ) -> &mut Self {
+        self.join_request_handler_funcs.push(handler);
+        self
+    }
+
+    /// Adds a [`DialogueFlow`] that dialogues can be started with, see the
+    /// [dialogue module] for more detail
+    ///
+    /// [dialogue module]: ../dialogue/index.html
+    pub fn add_dialogue_flow(&mut self, flow: DialogueFlow) -> &mut Self {
+        self.dialogue_flows.push(flow);
+        self
+    }
+
+    /// Sets the storage of the state of chats, users and dialogues, by default
+    /// a [`MemoryStorage`] is used. See the [storage module] for more detail
+    ///
+    /// [storage module]: ../storage/index.html
+    pub fn set_storage(&mut self, storage: Arc<dyn StateStorage>) -> &mut Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Adds a value that is shared amongst all handlers, commands and
+    /// dialogues, replacing the value of the same type added before. It can
+    /// be retrieved using [`Context::get`], see [`Extensions`] for more
+    /// detail
+    ///
+    /// [`Context::get`]: struct.Context.html#method.get
+    pub fn add_extension<T: Send + Sync + 'static>(&mut self, value: T) -> &mut Self {
+        self.extensions.insert(value);
+        self
+    }
+
     /// Creates the [`Client`] object from the settings set in the
     /// [`ClientBuilder`] object
+    #[allow(deprecated)]
     pub fn build(&mut self) -> Client {
-        if self.framework.is_some() && !self.allowed_updates.contains(&UpdateType::Message) {
-            self.allowed_updates.push(UpdateType::Message);
+        if let Some(fr) = &self.framework {
+            for update_type in fr.get_triggers().update_types() {
+                if !self.allowed_updates.contains(&update_type) {
+                    self.allowed_updates.push(update_type);
+                }
+            }
+        }
+
+        let storage = self
+            .storage
+            .clone()
+            .unwrap_or_else(|| Arc::new(MemoryStorage::new()));
+
+        let dialogues = Dialogues::with_storage(storage.clone());
+        for flow in &self.dialogue_flows {
+            dialogues.add_flow(flow.clone());
+        }
+        if self.callback_router.is_some()
+            && !self.allowed_updates.is_empty()
+            && !self.allowed_updates.contains(&UpdateType::CallbackQuery)
+        {
+            self.allowed_updates.push(UpdateType::CallbackQuery);
+        }
+        if self.inline_router.is_some()
+            && !self.allowed_updates.is_empty()
+            && !self.allowed_updates.contains(&UpdateType::InlineQuery)
+        {
+            self.allowed_updates.push(UpdateType::InlineQuery);
+        }
+        if !self.reaction_handler_funcs.is_empty()
+            && !self.allowed_updates.is_empty()
+            && !self.allowed_updates.contains(&UpdateType::MessageReaction)
+        {
+            self.allowed_updates.push(UpdateType::MessageReaction);
+        }
+        if !self.reaction_count_handler_funcs.is_empty()
+            && !self.allowed_updates.is_empty()
+            && !self
+                .allowed_updates
+                .contains(&UpdateType::MessageReactionCount)
+        {
+            self.allowed_updates.push(UpdateType::MessageReactionCount);
+        }
+        if !self.join_request_handler_funcs.is_empty()
+            && !self.allowed_updates.is_empty()
+            && !self.allowed_updates.contains(&UpdateType::ChatJoinRequest)
+        {
+            self.allowed_updates.push(UpdateType::ChatJoinRequest);
+        }
+        if dialogues.has_flows() && !self.allowed_updates.is_empty() {
+            for update_type in [UpdateType::Message, UpdateType::CallbackQuery] {
+                if !self.allowed_updates.contains(&update_type) {
+                    self.allowed_updates.push(update_type);
+                }
+            }
         }
+        let dialogues = Arc::new(dialogues);
+        let extensions = Arc::new(self.extensions.clone());
 
         self.api_client.clone().map_or_else(
             || Client {
@@ -121,8 +288,16 @@ impl ClientBuilder {
                 ))),
                 event_handlers: self.event_handler_funcs.clone(),
                 raw_event_handlers: self.raw_event_handler_funcs.clone(),
+                reaction_handlers: self.reaction_handler_funcs.clone(),
+                reaction_count_handlers: self.reaction_count_handler_funcs.clone(),
+                join_request_handlers: self.join_request_handler_funcs.clone(),
+                extensions: extensions.clone(),
                 data: Arc::new(RwLock::new(ShareMap::custom())),
+                storage: storage.clone(),
                 framework: self.framework.clone(),
+                callback_router: self.callback_router.clone(),
+                inline_router: self.inline_router.clone(),
+                dialogues: dialogues.clone(),
                 webhook_opts: self.webhook.clone(),
                 allowed_updates: self.allowed_updates.clone(),
             },
@@ -131,8 +306,16 @@ impl ClientBuilder {
                 event_handlers: self.event_handler_funcs.clone(),
                 webhook_opts: self.webhook.clone(),
                 raw_event_handlers: self.raw_event_handler_funcs.clone(),
+                reaction_handlers: self.reaction_handler_funcs.clone(),
+                reaction_count_handlers: self.reaction_count_handler_funcs.clone(),
+                join_request_handlers: self.join_request_handler_funcs.clone(),
+                extensions: extensions.clone(),
                 data: Arc::new(RwLock::new(ShareMap::custom())),
+                storage: storage.clone(),
                 framework: self.framework.clone(),
+                callback_router: self.callback_router.clone(),
+                inline_router: self.inline_router.clone(),
+                dialogues: dialogues.clone(),
                 allowed_updates: self.allowed_updates.clone(),
             },
         )
@@ -1,14 +1,19 @@
 use super::{
-    APIConnector, ClientBuilder, Context, EventHandlerFunc, RawEventHandlerFunc, UpdatesStream,
-    Webhook, WebhookOptions,
+    APIConnector, ChatJoinRequestHandlerFunc, ClientBuilder, Context, EventHandlerFunc, Extensions,
+    RawEventHandlerFunc, ReactionCountHandlerFunc, ReactionHandlerFunc, UpdatesStream, Webhook,
+    WebhookOptions,
 };
 use crate::{
     api::{
-        types::{SetWebhook, UpdateType},
+        types::{DeleteMyCommands, SetMyCommands, SetWebhook, UpdateType},
         APIClient,
     },
+    callback::CallbackRouter,
+    dialogue::{DialogueFlow, Dialogues},
     framework::Framework,
-    model::Update,
+    inline::InlineRouter,
+    model::{Update, UpdateContent},
+    storage::{MemoryStorage, StateStorage},
     Result,
 };
 use futures::StreamExt;
@@ -16,11 +21,15 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 use typemap::ShareMap;
 
+// the key under which the scopes and languages the commands were registered
+// for are kept in the storage
+const COMMAND_SCOPES_KEY: &str = "bot:command_scopes";
+
 /// The Client is the main object to manage your interaction with telegram.
 ///
 /// It handles the incoming update objects from telegram and dispatches them to
-/// your event handlers and commands, providing those with access to shared data
-/// and easy access to the telegram API itself.
+/// your event handlers and commands, providing those with access to shared
+/// extensions and easy access to the telegram API itself.
 ///
 /// # Event Handlers
 ///
@@ -56,28 +65,63 @@ pub struct Client {
     ///
     /// [`API`]: ../api/trait.API.html
     pub api_client: Arc<Box<APIConnector>>,
-    /// Your custom data that you want to be shared amongst event handlers and
-    /// commands.
+    /// Your custom values that you want to be shared amongst event handlers,
+    /// commands and dialogues, at most one of every type.
     ///
-    /// The purpose of the data field is to be accessible and persistent across
-    /// contexts; that is, data can be modified by one context, and will persist
-    /// through the future and be accessible through other contexts. This is
-    /// useful for anything that should "live" through the program: counters,
-    /// database connections, custom user caches, etc.
-    /// Therefore this ShareMap requires all types it will contain to be Send +
-    /// Sync.
+    /// The purpose of the extensions is to be accessible and persistent across
+    /// contexts. This is useful for anything that should "live" through the
+    /// program: counters, database connections, custom user caches, etc.
+    /// They are added once using [`ClientBuilder::add_extension`] or
+    /// [`add_extension`], so they can be read without any lock. Therefore all
+    /// types they contain have to be `Send + Sync`, and should use their own
+    /// interior mutability if they are changed.
     ///
-    /// When using a [`Context`], this data will be available as
-    /// [`Context::data`].
+    /// When using a [`Context`], they will be available using [`Context::get`].
     ///
-    /// Refer to the [repeat_image_bot] example for an example on using the
-    /// `data` field
+    /// Refer to the [repeat_image_bot] example for an example on using
+    /// extensions
     ///
+    /// [`ClientBuilder::add_extension`]: struct.ClientBuilder.html#method.add_extension
+    /// [`add_extension`]: #method.add_extension
+    /// [`Context::get`]: struct.Context.html#method.get
     /// [repeat_image_bot]: https://github.com/callieve/telexide/tree/master/examples/repeat_image_bot.rs
+    pub extensions: Arc<Extensions>,
+    /// The `ShareMap` that custom data was shared in before [`extensions`]
+    /// were added, it is only kept for compatibility.
+    ///
+    /// The map is behind a blocking lock, which must not be held across an
+    /// `.await`, so use the [`extensions`] instead.
+    ///
+    /// [`extensions`]: #structfield.extensions
+    #[deprecated(note = "use `ClientBuilder::add_extension` and `Context::get` instead")]
     pub data: Arc<RwLock<ShareMap>>,
+    /// The storage of the state of chats, users and dialogues, which unlike
+    /// the [`extensions`] can be kept across restarts and shared between instances
+    /// of the bot, depending on the [`StateStorage`] used. See the [storage
+    /// module] for more detail.
+    ///
+    /// When using a [`Context`], this storage will be available as
+    /// [`Context::storage`].
+    ///
+    /// [`extensions`]: #structfield.extensions
+    /// [storage module]: ../storage/index.html
+    pub storage: Arc<dyn StateStorage>,
     pub(super) event_handlers: Vec<EventHandlerFunc>,
     pub(super) raw_event_handlers: Vec<RawEventHandlerFunc>,
+    pub(super) reaction_handlers: Vec<ReactionHandlerFunc>,
+    pub(super) reaction_count_handlers: Vec<ReactionCountHandlerFunc>,
+    pub(super) join_request_handlers: Vec<ChatJoinRequestHandlerFunc>,
     pub(super) framework: Option<Arc<Framework>>,
+    pub(super) callback_router: Option<Arc<CallbackRouter>>,
+    pub(super) inline_router: Option<Arc<InlineRouter>>,
+    /// The flows and ongoing dialogues of the bot, see the [dialogue module]
+    /// for more detail.
+    ///
+    /// When using a [`Context`], they will be available as
+    /// [`Context::dialogues`].
+    ///
+    /// [dialogue module]: ../dialogue/index.html
+    pub dialogues: Arc<Dialogues>,
     pub(super) webhook_opts: Option<WebhookOptions>,
     /// The update types that you want to receive, see the documentation of
     /// [`UpdateType`] for more information
@@ -86,27 +130,45 @@ pub struct Client {
 
 impl Client {
     /// Creates a Client object with default values and no framework
+    #[allow(deprecated)]
     pub fn new<T: ToString>(token: &T) -> Self {
         Self {
             api_client: Arc::new(Box::new(APIClient::new(None, token))),
             event_handlers: Vec::new(),
             raw_event_handlers: Vec::new(),
+            reaction_handlers: Vec::new(),
+            reaction_count_handlers: Vec::new(),
+            join_request_handlers: Vec::new(),
+            extensions: Arc::new(Extensions::new()),
             data: Arc::new(RwLock::new(ShareMap::custom())),
+            storage: Arc::new(MemoryStorage::new()),
             framework: None,
+            callback_router: None,
+            inline_router: None,
+            dialogues: Arc::new(Dialogues::default()),
             webhook_opts: None,
             allowed_updates: Vec::new(),
         }
     }
 
     /// Creates a Client object with default values, but with a [`Framework`]
+    #[allow(deprecated)]
     pub fn with_framework<T: ToString>(fr: Arc<Framework>, token: &T) -> Self {
         Self {
             api_client: Arc::new(Box::new(APIClient::new(None, token))),
             event_handlers: Vec::new(),
             raw_event_handlers: Vec::new(),
+            reaction_handlers: Vec::new(),
+            reaction_count_handlers: Vec::new(),
+            join_request_handlers: Vec::new(),
+            extensions: Arc::new(Extensions::new()),
             data: Arc::new(RwLock::new(ShareMap::custom())),
+            storage: Arc::new(MemoryStorage::new()),
             webhook_opts: None,
             framework: Some(fr),
+            callback_router: None,
+            inline_router: None,
+            dialogues: Arc::new(Dialogues::default()),
             allowed_updates: Vec::new(),
         }
     }
@@ -137,18 +199,14 @@ impl Client {
     /// If using the framework, it will update your commands in telegram
     /// You have to provide your own [`UpdatesStream`] object
     pub async fn start_with_stream(&self, stream: &mut UpdatesStream) -> Result<()> {
-        if let Some(fr) = self.framework.clone() {
-            self.api_client
-                .set_my_commands(fr.get_commands().into())
-                .await?;
-        }
+        self.register_commands().await?;
 
         log::info!("starting long polling to listen for updates from telegram api");
         while let Some(poll) = stream.next().await {
             match poll {
                 Ok(update) => {
                     self.fire_handlers(update);
-                }
+                },
                 Err(err) => return Err(err),
             }
         }
@@ -161,11 +219,7 @@ impl Client {
     /// If using the framework, it will update your commands in telegram
     /// You have to provide your own [`WebhookOptions`] object
     pub async fn start_with_webhook(&self, opts: &WebhookOptions) -> Result<()> {
-        if let Some(fr) = self.framework.clone() {
-            self.api_client
-                .set_my_commands(fr.get_commands().into())
-                .await?;
-        }
+        self.register_commands().await?;
 
         if let Some(webhook_url) = &opts.url {
             self.api_client
@@ -186,7 +240,7 @@ impl Client {
             match u {
                 Ok(update) => {
                     self.fire_handlers(update);
-                }
+                },
                 Err(err) => return Err(err),
             }
         }
@@ -194,23 +248,116 @@ impl Client {
         Ok(())
     }
 
+    /// Sets the bot name of the framework to the username of the bot and
+    /// updates the commands of the framework in telegram, calling
+    /// `setMyCommands` once for every scope and language the commands are
+    /// registered for.
+    ///
+    /// The scopes and languages are kept in the storage, so the commands of
+    /// the ones that are no longer used get deleted on the next start.
+    async fn register_commands(&self) -> Result<()> {
+        if let Some(fr) = self.framework.clone() {
+            if let Some(username) = self.api_client.get_me().await?.username {
+                fr.set_bot_name(&username);
+            }
+
+            let registered = SetMyCommands::from_framework(&fr);
+            let previous = match self.storage.get(COMMAND_SCOPES_KEY).await? {
+                Some(value) => serde_json::from_value(value).unwrap_or_default(),
+                None => Vec::new(),
+            };
+            for data in DeleteMyCommands::stale(&registered, previous) {
+                self.api_client.delete_my_commands(data).await?;
+            }
+
+            let scopes: Vec<DeleteMyCommands> = registered.iter().map(Into::into).collect();
+            for data in registered {
+                self.api_client.set_my_commands(data).await?;
+            }
+            self.storage
+                .set(COMMAND_SCOPES_KEY, serde_json::to_value(scopes)?)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Subscribes an update event handler function ([`EventHandlerFunc`]) to
     /// the client and will be ran whenever a new update is received
     pub fn subscribe_handler_func(&mut self, handler: EventHandlerFunc) {
         self.event_handlers.push(handler);
     }
 
+    /// Adds a [`DialogueFlow`] that dialogues can be started with using
+    /// [`Context::start_dialogue`]
+    pub fn add_dialogue_flow(&mut self, flow: DialogueFlow) {
+        self.dialogues.add_flow(flow);
+    }
+
+    /// Adds a value to the [`extensions`], replacing the value of the same
+    /// type added before. Contexts that have already been created don't see
+    /// it.
+    ///
+    /// [`extensions`]: #structfield.extensions
+    pub fn add_extension<T: Send + Sync + 'static>(&mut self, value: T) {
+        Arc::make_mut(&mut self.extensions).insert(value);
+    }
+
     /// Subscribes a raw update event handler function ([`RawEventHandlerFunc`])
     /// to the client and will be ran whenever a new update is received
     pub fn subscribe_raw_handler(&mut self, handler: RawEventHandlerFunc) {
         self.raw_event_handlers.push(handler);
     }
 
+    /// Subscribes a reaction handler function ([`ReactionHandlerFunc`]) to the
+    /// client, it will be ran whenever a user changes their reactions on a
+    /// message.
+    ///
+    /// Telegram only sends these updates when [`UpdateType::MessageReaction`]
+    /// is explicitly in the [`allowed_updates`].
+    ///
+    /// [`allowed_updates`]: #structfield.allowed_updates
+    pub fn subscribe_reaction_handler(&mut self, handler: ReactionHandlerFunc) {
+        self.reaction_handlers.push(handler);
+    }
+
+    /// Subscribes a reaction count handler function
+    /// ([`ReactionCountHandlerFunc`]) to the client, it will be ran whenever
+    /// the anonymous reactions on a message change.
+    ///
+    /// Telegram only sends these updates when
+    /// [`UpdateType::MessageReactionCount`] is explicitly in the
+    /// [`allowed_updates`].
+    ///
+    /// [`allowed_updates`]: #structfield.allowed_updates
+    pub fn subscribe_reaction_count_handler(&mut self, handler: ReactionCountHandlerFunc) {
+        self.reaction_count_handlers.push(handler);
+    }
+
+    /// Subscribes a join request handler function
+    /// ([`ChatJoinRequestHandlerFunc`]) to the client, it will be ran whenever
+    /// a user requests to join a chat the bot can approve members of.
+    pub fn subscribe_join_request_handler(&mut self, handler: ChatJoinRequestHandlerFunc) {
+        self.join_request_handlers.push(handler);
+    }
+
+    #[allow(deprecated)]
+    fn new_context(&self) -> Context {
+        Context {
+            api: self.api_client.clone(),
+            data: self.data.clone(),
+            extensions: self.extensions.clone(),
+            dialogues: self.dialogues.clone(),
+            storage: self.storage.clone(),
+            framework: self.framework.clone(),
+        }
+    }
+
     // public only for testing purposes
     #[doc(hidden)]
     pub fn fire_handlers(&self, update: Update) {
         for h in self.raw_event_handlers.clone() {
-            let ctx = Context::new(self.api_client.clone(), self.data.clone());
+            let ctx = self.new_context();
             let u = update.clone();
             tokio::spawn(async move {
                 h(ctx, u.into()).await;
@@ -218,31 +365,92 @@ impl Client {
         }
 
         for h in self.event_handlers.clone() {
-            let ctx = Context::new(self.api_client.clone(), self.data.clone());
+            let ctx = self.new_context();
             let u = update.clone();
             tokio::spawn(async move {
                 h(ctx, u).await;
             });
         }
 
-        if self.framework.is_some() {
-            let ctx = Context::new(self.api_client.clone(), self.data.clone());
-            let fr = self.framework.clone();
-            fr.as_ref()
-                .expect("Framework needs to be set before trying to fire commands")
-                .fire_commands(ctx, update);
+        match &update.content {
+            UpdateContent::MessageReaction(reaction) => {
+                for h in self.reaction_handlers.clone() {
+                    let ctx = self.new_context();
+                    let r = reaction.clone();
+                    tokio::spawn(async move {
+                        h(ctx, r).await;
+                    });
+                }
+            },
+            UpdateContent::MessageReactionCount(count) => {
+                for h in self.reaction_count_handlers.clone() {
+                    let ctx = self.new_context();
+                    let c = count.clone();
+                    tokio::spawn(async move {
+                        h(ctx, c).await;
+                    });
+                }
+            },
+            UpdateContent::ChatJoinRequest(request) => {
+                for h in self.join_request_handlers.clone() {
+                    let ctx = self.new_context();
+                    let r = request.clone();
+                    tokio::spawn(async move {
+                        h(ctx, r).await;
+                    });
+                }
+            },
+            _ => (),
         }
+
+        let ctx = self.new_context();
+        let dialogues = self.dialogues.clone();
+        let fr = self.framework.clone();
+        let router = self.callback_router.clone();
+        let inline_router = self.inline_router.clone();
+        tokio::spawn(async move {
+            if dialogues.handle_update(ctx.clone(), update.clone()).await {
+                return;
+            }
+
+            if let (Some(router), UpdateContent::CallbackQuery(query)) = (router, &update.content) {
+                if router.handle_query(ctx.clone(), query.clone()) {
+                    return;
+                }
+            }
+
+            if let (Some(router), UpdateContent::InlineQuery(query)) =
+                (inline_router, &update.content)
+            {
+                if router.handle_query(ctx.clone(), query.clone()).await {
+                    return;
+                }
+            }
+
+            if let Some(fr) = fr {
+                fr.fire_commands(ctx, update);
+            }
+        });
     }
 }
 
 impl From<Box<APIConnector>> for Client {
+    #[allow(deprecated)]
     fn from(api: Box<APIConnector>) -> Self {
         Self {
             api_client: Arc::new(api),
             event_handlers: Vec::new(),
             raw_event_handlers: Vec::new(),
+            reaction_handlers: Vec::new(),
+            reaction_count_handlers: Vec::new(),
+            join_request_handlers: Vec::new(),
+            extensions: Arc::new(Extensions::new()),
             data: Arc::new(RwLock::new(ShareMap::custom())),
+            storage: Arc::new(MemoryStorage::new()),
             framework: None,
+            callback_router: None,
+            inline_router: None,
+            dialogues: Arc::new(Dialogues::default()),
             webhook_opts: None,
             allowed_updates: Vec::new(),
         }
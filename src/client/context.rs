@@ -1,11 +1,18 @@
-use super::APIConnector;
+use super::{APIConnector, Extensions};
+use crate::{
+    dialogue::{DialogueInput, Dialogues},
+    framework::Framework,
+    storage::{chat_key, user_key, MemoryStorage, StateStorage},
+    Result,
+};
 use parking_lot::RwLock;
+use serde::{de::DeserializeOwned, Serialize};
 use std::sync::Arc;
 use typemap::ShareMap;
 
 /// The context object is an utility object that gets passed to all event
-/// handlers, it provides access to the API client and to any custom data you
-/// have set in the data object.
+/// handlers, it provides access to the API client and to the extensions you
+/// have added to the client.
 #[derive(Clone)]
 pub struct Context {
     /// The API client, implementing the [`API`] trait
@@ -15,11 +22,139 @@ pub struct Context {
     /// A clone of [`Client::data`], see its documentation for more detail
     ///
     /// [`Client::data`]: struct.Client.html#structfield.data
+    #[deprecated(note = "use `ClientBuilder::add_extension` and `Context::get` instead")]
     pub data: Arc<RwLock<ShareMap>>,
+    /// A clone of [`Client::extensions`], see its documentation for more
+    /// detail
+    ///
+    /// [`Client::extensions`]: struct.Client.html#structfield.extensions
+    pub extensions: Arc<Extensions>,
+    /// The dialogues of the [`Client`], see the [dialogue module] for more
+    /// detail
+    ///
+    /// [`Client`]: struct.Client.html
+    /// [dialogue module]: ../dialogue/index.html
+    pub dialogues: Arc<Dialogues>,
+    /// A clone of [`Client::storage`], see its documentation for more detail
+    ///
+    /// [`Client::storage`]: struct.Client.html#structfield.storage
+    pub storage: Arc<dyn StateStorage>,
+    pub(crate) framework: Option<Arc<Framework>>,
 }
 
 impl Context {
+    #[allow(deprecated)]
     pub fn new(api: Arc<Box<APIConnector>>, data: Arc<RwLock<ShareMap>>) -> Self {
-        Self { api, data }
+        Self {
+            api,
+            data,
+            extensions: Arc::new(Extensions::new()),
+            dialogues: Arc::new(Dialogues::default()),
+            storage: Arc::new(MemoryStorage::new()),
+            framework: None,
+        }
+    }
+
+    /// Gets the extension of the given type, which has been added using
+    /// [`ClientBuilder::add_extension`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if no extension of the type has been added, use [`try_get`]
+    /// if it is optional
+    ///
+    /// [`ClientBuilder::add_extension`]: struct.ClientBuilder.html#method.add_extension
+    /// [`try_get`]: #method.try_get
+    pub fn get<T: Send + Sync + 'static>(&self) -> Arc<T> {
+        self.extensions.expect()
+    }
+
+    /// Gets the extension of the given type, if it has been added using
+    /// [`ClientBuilder::add_extension`]
+    ///
+    /// [`ClientBuilder::add_extension`]: struct.ClientBuilder.html#method.add_extension
+    pub fn try_get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.extensions.get()
+    }
+
+    /// Starts a dialogue with the user the input is from, following the flow
+    /// with the given name. See [`Dialogues::start`] for more detail
+    ///
+    /// [`Dialogues::start`]: ../dialogue/struct.Dialogues.html#method.start
+    pub fn start_dialogue(&self, input: impl Into<DialogueInput>, flow: &str) -> Result<()> {
+        self.dialogues.start(self.clone(), input, flow)
+    }
+
+    /// Gets the state with the given name of a chat from the [`storage`], if
+    /// it has been set
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn get_chat_state<T: DeserializeOwned>(
+        &self,
+        chat_id: i64,
+        name: &str,
+    ) -> Result<Option<T>> {
+        self.get_state(&chat_key(chat_id, name)).await
+    }
+
+    /// Sets the state with the given name of a chat in the [`storage`]
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn set_chat_state<T: Serialize>(
+        &self,
+        chat_id: i64,
+        name: &str,
+        value: &T,
+    ) -> Result<()> {
+        self.set_state(&chat_key(chat_id, name), value).await
+    }
+
+    /// Removes the state with the given name of a chat from the [`storage`]
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn remove_chat_state(&self, chat_id: i64, name: &str) -> Result<()> {
+        self.storage.remove(&chat_key(chat_id, name)).await
+    }
+
+    /// Gets the state with the given name of a user from the [`storage`], if
+    /// it has been set
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn get_user_state<T: DeserializeOwned>(
+        &self,
+        user_id: i64,
+        name: &str,
+    ) -> Result<Option<T>> {
+        self.get_state(&user_key(user_id, name)).await
+    }
+
+    /// Sets the state with the given name of a user in the [`storage`]
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn set_user_state<T: Serialize>(
+        &self,
+        user_id: i64,
+        name: &str,
+        value: &T,
+    ) -> Result<()> {
+        self.set_state(&user_key(user_id, name), value).await
+    }
+
+    /// Removes the state with the given name of a user from the [`storage`]
+    ///
+    /// [`storage`]: #structfield.storage
+    pub async fn remove_user_state(&self, user_id: i64, name: &str) -> Result<()> {
+        self.storage.remove(&user_key(user_id, name)).await
+    }
+
+    async fn get_state<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        match self.storage.get(key).await? {
+            Some(value) => Ok(Some(serde_json::from_value(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set_state<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        self.storage.set(key, serde_json::to_value(value)?).await
     }
 }
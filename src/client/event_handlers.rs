@@ -1,5 +1,7 @@
 use super::{Context, FutureOutcome};
-use crate::model::{raw::RawUpdate, Update};
+use crate::model::{
+    raw::RawUpdate, ChatJoinRequest, MessageReactionCountUpdated, MessageReactionUpdated, Update,
+};
 
 /// A function that handles a new update, it receives a [`Context`] and
 /// [`Update`] and returns a pinned future. Wrap an async function with
@@ -10,3 +12,20 @@ pub type EventHandlerFunc = fn(Context, Update) -> FutureOutcome;
 /// [`RawUpdate`] and returns a pinned future. Wrap an async function with
 /// `#[prepare_listener]` for easier development.
 pub type RawEventHandlerFunc = fn(Context, RawUpdate) -> FutureOutcome;
+
+/// A function that handles a change of the reactions of a user on a message,
+/// it receives a [`Context`] and [`MessageReactionUpdated`] and returns a
+/// pinned future. Wrap an async function with `#[prepare_listener]` for easier
+/// development.
+pub type ReactionHandlerFunc = fn(Context, MessageReactionUpdated) -> FutureOutcome;
+
+/// A function that handles a change of the anonymous reactions on a message,
+/// it receives a [`Context`] and [`MessageReactionCountUpdated`] and returns a
+/// pinned future. Wrap an async function with `#[prepare_listener]` for easier
+/// development.
+pub type ReactionCountHandlerFunc = fn(Context, MessageReactionCountUpdated) -> FutureOutcome;
+
+/// A function that handles a request to join a chat, it receives a
+/// [`Context`] and [`ChatJoinRequest`] and returns a pinned future. Wrap an
+/// async function with `#[prepare_listener]` for easier development.
+pub type ChatJoinRequestHandlerFunc = fn(Context, ChatJoinRequest) -> FutureOutcome;
@@ -0,0 +1,117 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+/// A typed container of the values shared amongst event handlers, commands
+/// and dialogues, holding at most one value of every type.
+///
+/// Values are added once when creating the [`Client`], using
+/// [`ClientBuilder::add_extension`], after which they can be retrieved from
+/// any [`Context`] using [`Context::get`]. The container itself can't be
+/// changed anymore at that point, so getting a value doesn't need any lock.
+/// Values that should be changed while the bot is running have to take care
+/// of that themselves, for example by wrapping their fields in a
+/// `tokio::sync::RwLock` or an atomic, which can be held across `.await`
+/// without blocking other handlers.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use telexide_fork::prelude::*;
+///
+/// #[derive(Default)]
+/// struct Counter(AtomicUsize);
+///
+/// #[command(description = "counts how often it is used")]
+/// async fn count(ctx: Context, _message: Message) -> CommandResult {
+///     ctx.get::<Counter>().0.fetch_add(1, Ordering::Relaxed);
+///     Ok(())
+/// }
+///
+/// # fn main() {
+/// let client = ClientBuilder::new()
+///     .set_token("token")
+///     .set_framework(create_framework!("bot", count))
+///     .add_extension(Counter::default())
+///     .build();
+/// # }
+/// ```
+///
+/// [`Client`]: struct.Client.html
+/// [`ClientBuilder::add_extension`]: struct.ClientBuilder.html#method.add_extension
+/// [`Context`]: struct.Context.html
+/// [`Context::get`]: struct.Context.html#method.get
+#[derive(Clone, Default)]
+pub struct Extensions {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the value, replacing the value of the same type added before
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.insert_arc(Arc::new(value));
+    }
+
+    /// Adds the value that is already wrapped in an [`Arc`], replacing the
+    /// value of the same type added before
+    pub fn insert_arc<T: Send + Sync + 'static>(&mut self, value: Arc<T>) {
+        self.values.insert(TypeId::of::<T>(), value);
+    }
+
+    /// get the value of the given type, if it has been added
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|value| value.downcast().ok())
+    }
+
+    /// returns true if a value of the given type has been added
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes the value of the given type, returning it if it had been added
+    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<Arc<T>> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+    }
+
+    /// get the amount of values that have been added
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// returns true if no values have been added
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // gets the value of the given type, panicking with a message naming the
+    // type if it hasn't been added
+    pub(crate) fn expect<T: Send + Sync + 'static>(&self) -> Arc<T> {
+        self.get().unwrap_or_else(|| {
+            panic!(
+                "no extension of type {} has been added to the client",
+                type_name::<T>()
+            )
+        })
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
@@ -6,7 +6,7 @@
 //! modules are also automatically handled by the [`Client`] for you.
 //!
 //! A [`Context`] is provided for every handler, providing them with access to
-//! the api connection and any shared data you have set.
+//! the api connection and the [`Extensions`] you have added.
 //!
 //! See [here][examples] for some examples on how to use the [`Client`].
 //!
@@ -15,11 +15,13 @@
 //! [`framework`]: ../framework/index.html
 //! [`Context`]: struct.Context.html
 //! [`Client`]: struct.Client.html
+//! [`Extensions`]: struct.Extensions.html
 
 mod builder;
 mod client;
 mod context;
 mod event_handlers;
+mod extensions;
 mod stream;
 mod webhook_handling;
 
@@ -30,7 +32,11 @@ use std::pin::Pin;
 pub use builder::ClientBuilder;
 pub use client::Client;
 pub use context::Context;
-pub use event_handlers::{EventHandlerFunc, RawEventHandlerFunc};
+pub use event_handlers::{
+    ChatJoinRequestHandlerFunc, EventHandlerFunc, RawEventHandlerFunc, ReactionCountHandlerFunc,
+    ReactionHandlerFunc,
+};
+pub use extensions::Extensions;
 pub use stream::UpdatesStream;
 pub use webhook_handling::{Webhook, WebhookOptions};
 
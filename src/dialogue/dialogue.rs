@@ -0,0 +1,639 @@
+use super::{DialogueError, DialogueFlow, DialogueInput, DialogueKey, Transition};
+use crate::{
+    api::types::SendMessage,
+    client::Context,
+    model::{CallbackQuery, Message, Update},
+    storage::{dialogue_key, StateStorage},
+    Result,
+};
+use chrono::Utc;
+use log::{debug, warn};
+use parking_lot::{Mutex, RwLock};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::oneshot;
+
+type DialogueData = Arc<Mutex<HashMap<String, Value>>>;
+
+struct ActiveDialogue {
+    // distinguishes a restarted dialogue from the one it replaced
+    id: u64,
+    flow: Arc<DialogueFlow>,
+    state: String,
+    data: DialogueData,
+    last_activity: Instant,
+    running: bool,
+    waiting: Option<oneshot::Sender<DialogueInput>>,
+    // input received while the handler of a state was running without
+    // waiting for it
+    queued: VecDeque<DialogueInput>,
+}
+
+impl ActiveDialogue {
+    fn is_expired(&self) -> bool {
+        self.flow
+            .get_timeout()
+            .is_some_and(|timeout| self.last_activity.elapsed() >= timeout)
+    }
+}
+
+// what the timer of a dialogue finds when it goes off
+enum TimeoutCheck {
+    // the dialogue has ended or has been replaced
+    Ended,
+    // the dialogue has been active since the timer was set, so it should wait
+    // for the given time
+    Wait(Duration),
+    Expired(Arc<DialogueFlow>),
+}
+
+// the part of a dialogue that is kept in the storage
+#[derive(Serialize, Deserialize)]
+struct StoredDialogue {
+    flow: String,
+    state: String,
+    data: HashMap<String, Value>,
+    updated_at: i64,
+}
+
+impl From<&ActiveDialogue> for StoredDialogue {
+    fn from(dialogue: &ActiveDialogue) -> Self {
+        Self {
+            flow: dialogue.flow.get_name().to_owned(),
+            state: dialogue.state.clone(),
+            data: dialogue.data.lock().clone(),
+            updated_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+// the dialogues going on, shared between the manager and the handles given
+// to the state handlers
+#[derive(Default)]
+struct DialogueRegistry {
+    active: Mutex<HashMap<DialogueKey, ActiveDialogue>>,
+    storage: Option<Arc<dyn StateStorage>>,
+}
+
+impl DialogueRegistry {
+    // ends the dialogue with the key, if it is the one with the given id,
+    // returns false if there was none
+    #[allow(clippy::unnecessary_map_or)] // `is_none_or` needs Rust 1.82
+    fn end(&self, key: DialogueKey, id: Option<u64>) -> bool {
+        let mut active = self.active.lock();
+        match active.get(&key) {
+            Some(dialogue) if id.map_or(true, |id| dialogue.id == id) => {
+                active.remove(&key);
+            },
+            _ => return false,
+        }
+        drop(active);
+
+        if let Some(storage) = self.storage.clone() {
+            tokio::spawn(async move {
+                if let Err(err) = storage.remove(&dialogue_key(key)).await {
+                    warn!("failed to remove dialogue from the storage: {err}");
+                }
+            });
+        }
+        true
+    }
+
+    fn check_timeout(&self, key: DialogueKey, id: u64, timeout: Duration) -> TimeoutCheck {
+        let active = self.active.lock();
+        match active.get(&key) {
+            Some(dialogue) if dialogue.id == id => {
+                // a state that is running or waiting for input ends the
+                // dialogue itself when it times out
+                if dialogue.running {
+                    TimeoutCheck::Wait(timeout)
+                } else if dialogue.is_expired() {
+                    TimeoutCheck::Expired(dialogue.flow.clone())
+                } else {
+                    TimeoutCheck::Wait(timeout.saturating_sub(dialogue.last_activity.elapsed()))
+                }
+            },
+            _ => TimeoutCheck::Ended,
+        }
+    }
+
+    async fn save(&self, key: DialogueKey, stored: &StoredDialogue) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+
+        let result = match serde_json::to_value(stored) {
+            Ok(value) => storage.set(&dialogue_key(key), value).await,
+            Err(err) => Err(err.into()),
+        };
+        if let Err(err) = result {
+            warn!("failed to save dialogue in the storage: {err}");
+        }
+    }
+
+    async fn load(&self, key: DialogueKey) -> Option<StoredDialogue> {
+        let storage = self.storage.as_ref()?;
+        match storage.get(&dialogue_key(key)).await {
+            Ok(value) => serde_json::from_value(value?).ok(),
+            Err(err) => {
+                warn!("failed to load dialogue from the storage: {err}");
+                None
+            },
+        }
+    }
+}
+
+/// Keeps track of the flows that have been added and of the dialogues that
+/// are going on, dispatching the input of users to them.
+///
+/// It is managed by the [`Client`], add flows to it using
+/// [`ClientBuilder::add_dialogue_flow`] and start dialogues using
+/// [`Context::start_dialogue`].
+///
+/// When it has a [`StateStorage`], every dialogue is saved in it after each
+/// state handler, so it can continue in that state after a restart.
+///
+/// [`Client`]: ../client/struct.Client.html
+/// [`ClientBuilder::add_dialogue_flow`]: ../client/struct.ClientBuilder.html#method.add_dialogue_flow
+/// [`Context::start_dialogue`]: ../client/struct.Context.html#method.start_dialogue
+#[derive(Default)]
+pub struct Dialogues {
+    flows: RwLock<HashMap<String, Arc<DialogueFlow>>>,
+    registry: Arc<DialogueRegistry>,
+    next_id: AtomicU64,
+}
+
+impl Dialogues {
+    /// Creates a new instance saving the dialogues in the given storage
+    pub fn with_storage(storage: Arc<dyn StateStorage>) -> Self {
+        Self {
+            registry: Arc::new(DialogueRegistry {
+                active: Mutex::default(),
+                storage: Some(storage),
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// add a flow dialogues can be started with
+    pub fn add_flow(&self, flow: DialogueFlow) {
+        self.flows
+            .write()
+            .insert(flow.get_name().to_owned(), Arc::new(flow));
+    }
+
+    /// returns true if any flow has been added
+    pub fn has_flows(&self) -> bool {
+        !self.flows.read().is_empty()
+    }
+
+    /// Starts a dialogue following the flow with the given name, calling the
+    /// handler of its initial state with the input. A dialogue that was
+    /// already going on for the same chat and user is replaced.
+    pub fn start(
+        &self,
+        context: Context,
+        input: impl Into<DialogueInput>,
+        flow: &str,
+    ) -> Result<()> {
+        let input = input.into();
+        let flow = self
+            .flows
+            .read()
+            .get(flow)
+            .cloned()
+            .ok_or_else(|| DialogueError::UnknownFlow(flow.to_owned()))?;
+        let key = input.get_key().ok_or(DialogueError::NoChat)?;
+
+        debug!("starting dialogue {} for {:?}", flow.get_name(), key);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let timeout = flow.get_timeout();
+        self.registry.active.lock().insert(
+            key,
+            ActiveDialogue {
+                id,
+                state: flow.get_initial_state().to_owned(),
+                flow,
+                data: DialogueData::default(),
+                last_activity: Instant::now(),
+                running: false,
+                waiting: None,
+                queued: VecDeque::new(),
+            },
+        );
+
+        if let Some(timeout) = timeout {
+            watch_timeout(self.registry.clone(), context.clone(), key, id, timeout);
+        }
+        self.run_state(context, key, input);
+        Ok(())
+    }
+
+    /// Ends the dialogue with the given key, returns false if there was none
+    pub fn cancel(&self, key: DialogueKey) -> bool {
+        self.registry.end(key, None)
+    }
+
+    /// returns true if a dialogue is going on with the given key
+    pub fn is_active(&self, key: DialogueKey) -> bool {
+        self.registry.active.lock().contains_key(&key)
+    }
+
+    /// get the name of the flow and the state of the dialogue with the given
+    /// key
+    pub fn get_state(&self, key: DialogueKey) -> Option<(String, String)> {
+        self.registry
+            .active
+            .lock()
+            .get(&key)
+            .map(|d| (d.flow.get_name().to_owned(), d.state.clone()))
+    }
+
+    /// Passes the input in the update on to the dialogue it belongs to, if
+    /// one is going on or saved in the storage. Returns true if the update
+    /// was used by the dialogue, in which case it shouldn't trigger any
+    /// commands.
+    pub async fn handle_update(&self, context: Context, update: Update) -> bool {
+        if !self.has_flows() {
+            return false;
+        }
+        let Some(input) = DialogueInput::from_update(update) else {
+            return false;
+        };
+        let Some(key) = input.get_key() else {
+            return false;
+        };
+
+        if !self.is_active(key) && !self.restore(&context, key).await {
+            return false;
+        }
+        self.dispatch(context, key, input)
+    }
+
+    // loads the dialogue with the given key from the storage, returns false if
+    // it wasn't saved in it
+    async fn restore(&self, context: &Context, key: DialogueKey) -> bool {
+        let Some(stored) = self.registry.load(key).await else {
+            return false;
+        };
+        let Some(flow) = self.flows.read().get(&stored.flow).cloned() else {
+            return false;
+        };
+
+        let elapsed = Utc::now().timestamp().saturating_sub(stored.updated_at);
+        let elapsed = Duration::from_secs(u64::try_from(elapsed).unwrap_or_default());
+        let last_activity = Instant::now()
+            .checked_sub(elapsed)
+            .unwrap_or_else(Instant::now);
+
+        debug!("restoring dialogue {} for {:?}", flow.get_name(), key);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let timeout = flow.get_timeout();
+        let restored = self
+            .registry
+            .active
+            .lock()
+            .entry(key)
+            .or_insert_with(|| ActiveDialogue {
+                id,
+                flow,
+                state: stored.state,
+                data: Arc::new(Mutex::new(stored.data)),
+                last_activity,
+                running: false,
+                waiting: None,
+                queued: VecDeque::new(),
+            })
+            .id
+            == id;
+
+        if let Some(timeout) = timeout.filter(|_| restored) {
+            watch_timeout(self.registry.clone(), context.clone(), key, id, timeout);
+        }
+        true
+    }
+
+    // passes the input on to the dialogue with the given key, which is going
+    // on
+    fn dispatch(&self, context: Context, key: DialogueKey, input: DialogueInput) -> bool {
+        let mut active = self.registry.active.lock();
+        let Some(dialogue) = active.get_mut(&key) else {
+            return false;
+        };
+        let flow = dialogue.flow.clone();
+        let id = dialogue.id;
+
+        if !dialogue.running && dialogue.is_expired() {
+            drop(active);
+            debug!("dialogue {} for {:?} timed out", flow.get_name(), key);
+            self.registry.end(key, Some(id));
+            send_reply(context, key, flow.get_timeout_reply());
+            return false;
+        }
+
+        if flow.is_cancelled_by(&input, context.framework.as_deref()) {
+            drop(active);
+            debug!("dialogue {} for {:?} cancelled", flow.get_name(), key);
+            self.registry.end(key, Some(id));
+            send_reply(context, key, flow.get_cancel_reply());
+            return true;
+        }
+
+        dialogue.last_activity = Instant::now();
+        let input = match dialogue.waiting.take() {
+            Some(waiting) => match waiting.send(input) {
+                Ok(()) => return true,
+                Err(input) => input,
+            },
+            None => input,
+        };
+        if dialogue.running {
+            debug!("queueing input for busy dialogue {key:?}");
+            dialogue.queued.push_back(input);
+            return true;
+        }
+
+        drop(active);
+        self.run_state(context, key, input);
+        true
+    }
+
+    fn run_state(&self, context: Context, key: DialogueKey, input: DialogueInput) {
+        run_state(self.registry.clone(), context, key, input);
+    }
+}
+
+// calls the handler of the state the dialogue is in with the input and
+// applies the transition it returns, after which the input that was queued in
+// the meantime is handled
+fn run_state(
+    registry: Arc<DialogueRegistry>,
+    context: Context,
+    key: DialogueKey,
+    input: DialogueInput,
+) {
+    let mut active = registry.active.lock();
+    let Some(dialogue) = active.get_mut(&key) else {
+        return;
+    };
+    let id = dialogue.id;
+    let Some(handler) = dialogue.flow.get_state(&dialogue.state) else {
+        warn!(
+            "dialogue {} has no state {}, ending it",
+            dialogue.flow.get_name(),
+            &dialogue.state
+        );
+        drop(active);
+        registry.end(key, Some(id));
+        return;
+    };
+
+    dialogue.running = true;
+    let handle = Dialogue {
+        key,
+        id,
+        flow: dialogue.flow.clone(),
+        state: dialogue.state.clone(),
+        data: dialogue.data.clone(),
+        context: context.clone(),
+        registry: registry.clone(),
+    };
+    drop(active);
+
+    tokio::spawn(async move {
+        let flow = handle.flow.clone();
+        let state = handle.state.clone();
+        let result = handler(context.clone(), handle, input).await;
+
+        let stored = {
+            let mut active = registry.active.lock();
+            let Some(dialogue) = active.get_mut(&key).filter(|d| d.id == id) else {
+                return;
+            };
+            dialogue.last_activity = Instant::now();
+
+            match result {
+                Ok(Transition::Next(next)) => dialogue.state = next,
+                Ok(Transition::Stay) => (),
+                Ok(Transition::Finish) => {
+                    drop(active);
+                    debug!("dialogue {} for {:?} finished", flow.get_name(), key);
+                    registry.end(key, Some(id));
+                    return;
+                },
+                Err(e) => warn!(
+                    "state {} of dialogue {} returned error: {}",
+                    state,
+                    flow.get_name(),
+                    e
+                ),
+            }
+            StoredDialogue::from(&*dialogue)
+        };
+
+        // the dialogue only handles new input once it has been saved
+        registry.save(key, &stored).await;
+        let queued = match registry.active.lock().get_mut(&key) {
+            Some(dialogue) if dialogue.id == id => {
+                dialogue.running = false;
+                dialogue.queued.pop_front()
+            },
+            _ => None,
+        };
+        if let Some(input) = queued {
+            run_state(registry, context, key, input);
+        }
+    });
+}
+
+// ends the dialogue with the key and the given id once it has been inactive
+// for the timeout of its flow, so it doesn't wait for the user forever when
+// they never send anything anymore
+fn watch_timeout(
+    registry: Arc<DialogueRegistry>,
+    context: Context,
+    key: DialogueKey,
+    id: u64,
+    timeout: Duration,
+) {
+    tokio::spawn(async move {
+        let mut wait = timeout;
+        loop {
+            tokio::time::sleep(wait).await;
+            match registry.check_timeout(key, id, timeout) {
+                TimeoutCheck::Ended => return,
+                TimeoutCheck::Wait(remaining) => wait = remaining,
+                TimeoutCheck::Expired(flow) => {
+                    if registry.end(key, Some(id)) {
+                        debug!("dialogue {} for {:?} timed out", flow.get_name(), key);
+                        send_reply(context, key, flow.get_timeout_reply());
+                    }
+                    return;
+                },
+            }
+        }
+    });
+}
+
+// sends the reply of a flow to the chat of the dialogue, if it has one
+fn send_reply(context: Context, key: DialogueKey, reply: Option<&str>) {
+    let Some(reply) = reply else {
+        return;
+    };
+
+    let data = SendMessage::new(key.chat_id, reply);
+    tokio::spawn(async move {
+        if let Err(err) = context.api.send_message(data).await {
+            warn!("failed to send dialogue reply: {err}");
+        }
+    });
+}
+
+/// A handle to a dialogue going on with a user, given to the handlers of its
+/// states.
+///
+/// It holds the data of the dialogue, which is kept between states, and
+/// provides `ask`-style helpers waiting for the next input of the user
+/// within a single state.
+#[derive(Clone)]
+pub struct Dialogue {
+    key: DialogueKey,
+    id: u64,
+    flow: Arc<DialogueFlow>,
+    state: String,
+    data: DialogueData,
+    context: Context,
+    registry: Arc<DialogueRegistry>,
+}
+
+impl Dialogue {
+    /// get the key of the dialogue
+    pub fn get_key(&self) -> DialogueKey {
+        self.key
+    }
+
+    /// get the flow the dialogue follows
+    pub fn get_flow(&self) -> &DialogueFlow {
+        &self.flow
+    }
+
+    /// get the state the dialogue is handling input for
+    pub fn get_state(&self) -> &str {
+        &self.state
+    }
+
+    /// Gets the value stored under the given key, if there is one and it can
+    /// be deserialized into the wanted type
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.data.lock().get(key).cloned()?;
+        serde_json::from_value(value).ok()
+    }
+
+    /// Stores the value under the given key, it is kept until the dialogue
+    /// ends
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let value = serde_json::to_value(value)?;
+        self.data.lock().insert(key.to_owned(), value);
+        Ok(())
+    }
+
+    /// Removes the value stored under the given key
+    pub fn remove(&self, key: &str) {
+        self.data.lock().remove(key);
+    }
+
+    /// Waits for the next input of the user, which is then not handled by the
+    /// state the dialogue is in. Input the user sent while the state was
+    /// busy is returned first.
+    ///
+    /// Returns an error when the user did not respond within the timeout of
+    /// the flow, in which case the dialogue is ended, or when the dialogue
+    /// was cancelled in the meantime.
+    pub async fn next_input(&self) -> Result<DialogueInput> {
+        let (sender, receiver) = oneshot::channel();
+        match self.registry.active.lock().get_mut(&self.key) {
+            Some(dialogue) if dialogue.id == self.id => {
+                if let Some(input) = dialogue.queued.pop_front() {
+                    return Ok(input);
+                }
+                dialogue.waiting = Some(sender);
+                dialogue.last_activity = Instant::now();
+            },
+            _ => return Err(DialogueError::Cancelled.into()),
+        }
+
+        let input = match self.flow.get_timeout() {
+            Some(timeout) => {
+                if let Ok(input) = tokio::time::timeout(timeout, receiver).await {
+                    input
+                } else {
+                    self.end_timed_out();
+                    return Err(DialogueError::TimedOut.into());
+                }
+            },
+            None => receiver.await,
+        };
+
+        input.map_err(|_| DialogueError::Cancelled.into())
+    }
+
+    /// Waits for the next message of the user, ignoring callback queries
+    pub async fn next_message(&self) -> Result<Message> {
+        loop {
+            if let DialogueInput::Message(message) = self.next_input().await? {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// Waits for the user to press a button of an inline keyboard, ignoring
+    /// messages
+    pub async fn next_callback_query(&self) -> Result<CallbackQuery> {
+        loop {
+            if let DialogueInput::CallbackQuery(query) = self.next_input().await? {
+                return Ok(query);
+            }
+        }
+    }
+
+    /// Sends the question to the chat of the dialogue and waits for the user
+    /// to answer it with a message
+    pub async fn ask(&self, question: &str) -> Result<Message> {
+        self.context
+            .api
+            .send_message(SendMessage::new(self.key.chat_id, question))
+            .await?;
+        self.next_message().await
+    }
+
+    /// Sends the message, which should have an inline keyboard, and waits for
+    /// the user to press one of its buttons
+    pub async fn ask_choice(&self, data: SendMessage) -> Result<CallbackQuery> {
+        self.context.api.send_message(data).await?;
+        self.next_callback_query().await
+    }
+
+    fn end_timed_out(&self) {
+        if self.registry.end(self.key, Some(self.id)) {
+            debug!(
+                "dialogue {} for {:?} timed out",
+                self.flow.get_name(),
+                self.key
+            );
+            send_reply(
+                self.context.clone(),
+                self.key,
+                self.flow.get_timeout_reply(),
+            );
+        }
+    }
+}
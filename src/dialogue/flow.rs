@@ -0,0 +1,174 @@
+use super::{Dialogue, DialogueInput};
+use crate::{client::Context, framework::Framework, Result};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+/// The future returned by a [`StateHandlerFunc`]
+pub type StateOutcome = Pin<Box<dyn Future<Output = Result<Transition>> + Send>>;
+
+/// A function handling the input a dialogue receives in a state, it receives
+/// a [`Context`], the [`Dialogue`] and the [`DialogueInput`] and returns a
+/// pinned future resolving to the [`Transition`] to make. Wrap an async
+/// function with `#[prepare_listener]` for easier development.
+pub type StateHandlerFunc = fn(Context, Dialogue, DialogueInput) -> StateOutcome;
+
+/// What a dialogue does after a state handler has finished
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transition {
+    /// Moves to the state with the given name, the next input is handled by it
+    Next(String),
+    /// Stays in the same state, the next input is handled by it again
+    Stay,
+    /// Ends the dialogue
+    Finish,
+}
+
+impl Transition {
+    /// Moves to the state with the given name
+    pub fn next(state: &str) -> Self {
+        Self::Next(state.to_owned())
+    }
+}
+
+/// A multi-step conversation with a user, declared as named states with a
+/// handler for each.
+///
+/// A dialogue following the flow is started using [`Context::start_dialogue`],
+/// which calls the handler of the initial state with the input that started
+/// it. Every next input of the same user in the same chat is then handled by
+/// the state the dialogue moved to, instead of triggering commands.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use telexide_fork::{
+///     dialogue::{Dialogue, DialogueFlow, DialogueInput, Transition},
+///     prelude::*,
+/// };
+///
+/// #[prepare_listener]
+/// async fn ask_name(ctx: Context, dialogue: Dialogue, _input: DialogueInput) -> telexide_fork::Result<Transition> {
+///     let name = dialogue.ask("What is your name?").await?;
+///     dialogue.set("name", &name.get_text())?;
+///     Ok(Transition::Finish)
+/// }
+///
+/// let mut flow = DialogueFlow::new("register", "name", ask_name);
+/// flow.set_timeout(std::time::Duration::from_secs(300))
+///     .set_cancel_reply("Registration cancelled");
+/// ```
+///
+/// [`Context::start_dialogue`]: ../client/struct.Context.html#method.start_dialogue
+#[derive(Clone)]
+pub struct DialogueFlow {
+    name: String,
+    initial_state: String,
+    states: HashMap<String, StateHandlerFunc>,
+    timeout: Option<Duration>,
+    timeout_reply: Option<String>,
+    cancel_commands: Vec<String>,
+    cancel_reply: Option<String>,
+}
+
+impl DialogueFlow {
+    /// Creates a new flow with the given name, starting in the given state.
+    ///
+    /// By default a dialogue never times out and can be cancelled using the
+    /// `/cancel` command.
+    pub fn new(name: &str, initial_state: &str, handler: StateHandlerFunc) -> Self {
+        let mut states = HashMap::new();
+        states.insert(initial_state.to_owned(), handler);
+
+        Self {
+            name: name.to_owned(),
+            initial_state: initial_state.to_owned(),
+            states,
+            timeout: None,
+            timeout_reply: None,
+            cancel_commands: vec!["cancel".to_owned()],
+            cancel_reply: None,
+        }
+    }
+
+    /// Adds a state with the handler of the input received in it
+    pub fn add_state(&mut self, name: &str, handler: StateHandlerFunc) -> &mut Self {
+        self.states.insert(name.to_owned(), handler);
+        self
+    }
+
+    /// Sets how long the dialogue waits for the user to respond before it is
+    /// ended
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the message sent to the user when the dialogue timed out
+    pub fn set_timeout_reply(&mut self, reply: &str) -> &mut Self {
+        self.timeout_reply = Some(reply.to_owned());
+        self
+    }
+
+    /// Adds a command that cancels the dialogue, like `stop` for `/stop`
+    pub fn add_cancel_command(&mut self, command: &str) -> &mut Self {
+        self.cancel_commands.push(command.to_owned());
+        self
+    }
+
+    /// Removes all commands cancelling the dialogue, including `/cancel`
+    pub fn clear_cancel_commands(&mut self) -> &mut Self {
+        self.cancel_commands.clear();
+        self
+    }
+
+    /// Sets the message sent to the user when the dialogue was cancelled
+    pub fn set_cancel_reply(&mut self, reply: &str) -> &mut Self {
+        self.cancel_reply = Some(reply.to_owned());
+        self
+    }
+
+    /// get the name of the flow
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// get the state dialogues following the flow start in
+    pub fn get_initial_state(&self) -> &str {
+        &self.initial_state
+    }
+
+    /// get the handler of the state with the given name
+    pub fn get_state(&self, name: &str) -> Option<StateHandlerFunc> {
+        self.states.get(name).copied()
+    }
+
+    /// get how long the dialogue waits for the user to respond
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// get the message sent to the user when the dialogue timed out
+    pub fn get_timeout_reply(&self) -> Option<&str> {
+        self.timeout_reply.as_deref()
+    }
+
+    /// get the commands that cancel the dialogue
+    pub fn get_cancel_commands(&self) -> &[String] {
+        &self.cancel_commands
+    }
+
+    /// get the message sent to the user when the dialogue was cancelled
+    pub fn get_cancel_reply(&self) -> Option<&str> {
+        self.cancel_reply.as_deref()
+    }
+
+    // returns true if the input is one of the commands cancelling the dialogue
+    pub(crate) fn is_cancelled_by(
+        &self,
+        input: &DialogueInput,
+        framework: Option<&Framework>,
+    ) -> bool {
+        input
+            .get_command_name(framework)
+            .is_some_and(|name| self.cancel_commands.iter().any(|c| c == name))
+    }
+}
@@ -0,0 +1,29 @@
+//! Dialogues are multi-step conversations with a user, like registration
+//! forms or order wizards.
+//!
+//! A [`DialogueFlow`] declares the states of a conversation with a handler for
+//! each of them, which decides what state to move to after handling the input
+//! of the user. Dialogues are kept per chat and user, so every user can be in
+//! their own conversation with the bot in every chat.
+//!
+//! Flows are added to the [`Client`] using
+//! [`ClientBuilder::add_dialogue_flow`], after which a dialogue can be started
+//! from any command or handler using [`Context::start_dialogue`]. While a
+//! dialogue is going on, the messages and callback queries of its user are
+//! handled by it instead of triggering commands.
+//!
+//! Within a single state, the [`Dialogue`] given to its handler can also be
+//! used to wait for the next input of the user, like
+//! `dialogue.ask("What is your name?").await`.
+//!
+//! [`Client`]: ../client/struct.Client.html
+//! [`ClientBuilder::add_dialogue_flow`]: ../client/struct.ClientBuilder.html#method.add_dialogue_flow
+//! [`Context::start_dialogue`]: ../client/struct.Context.html#method.start_dialogue
+
+mod dialogue;
+mod flow;
+mod types;
+
+pub use dialogue::{Dialogue, Dialogues};
+pub use flow::{DialogueFlow, StateHandlerFunc, StateOutcome, Transition};
+pub use types::{DialogueError, DialogueInput, DialogueKey};
@@ -0,0 +1,148 @@
+use crate::{
+    framework::Framework,
+    model::{CallbackQuery, Message, MessageContent, Update, UpdateContent},
+};
+use serde::{Deserialize, Serialize};
+
+/// Identifies a dialogue: every user has their own dialogue in every chat
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DialogueKey {
+    /// The id of the chat the dialogue takes place in
+    pub chat_id: i64,
+    /// The id of the user the dialogue is with, or of the chat the messages are
+    /// sent on behalf of when they have no user
+    pub user_id: i64,
+}
+
+impl DialogueKey {
+    pub fn new(chat_id: i64, user_id: i64) -> Self {
+        Self { chat_id, user_id }
+    }
+
+    /// Gets the key of the dialogue the message belongs to
+    pub fn from_message(message: &Message) -> Self {
+        let user_id = message.from.as_ref().map_or_else(
+            || {
+                message
+                    .sender_chat
+                    .as_ref()
+                    .unwrap_or(&message.chat)
+                    .get_id()
+            },
+            |u| u.id,
+        );
+        Self::new(message.chat.get_id(), user_id)
+    }
+
+    /// Gets the key of the dialogue the callback query belongs to, if it
+    /// originates from a message in a chat
+    pub fn from_callback_query(query: &CallbackQuery) -> Option<Self> {
+        query
+            .message
+            .as_ref()
+            .map(|m| Self::new(m.chat.get_id(), query.from.id))
+    }
+}
+
+/// An update a dialogue can receive from its user
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogueInput {
+    /// A new message
+    Message(Message),
+    /// A press on a button of an inline keyboard
+    CallbackQuery(CallbackQuery),
+}
+
+impl DialogueInput {
+    /// Gets the input in the update, if it has any
+    pub fn from_update(update: Update) -> Option<Self> {
+        match update.content {
+            UpdateContent::Message(m) => Some(Self::Message(m)),
+            UpdateContent::CallbackQuery(c) => Some(Self::CallbackQuery(c)),
+            _ => None,
+        }
+    }
+
+    /// Gets the key of the dialogue the input belongs to
+    pub fn get_key(&self) -> Option<DialogueKey> {
+        match self {
+            Self::Message(m) => Some(DialogueKey::from_message(m)),
+            Self::CallbackQuery(c) => DialogueKey::from_callback_query(c),
+        }
+    }
+
+    /// Gets the text or caption of the message, if the input is a message
+    pub fn get_text(&self) -> Option<String> {
+        match self {
+            Self::Message(m) => m.get_text(),
+            Self::CallbackQuery(_) => None,
+        }
+    }
+
+    /// Gets the data of the callback query, if the input is a callback query
+    pub fn get_data(&self) -> Option<&str> {
+        match self {
+            Self::Message(_) => None,
+            Self::CallbackQuery(c) => c.data.as_deref(),
+        }
+    }
+
+    // gets the name of the command the input starts with, if any, without the
+    // `/` and the bot name. Commands addressed to another bot than the one of
+    // the framework are ignored.
+    pub(crate) fn get_command_name(&self, framework: Option<&Framework>) -> Option<&str> {
+        let Self::Message(Message {
+            content: MessageContent::Text { content: text, .. },
+            ..
+        }) = self
+        else {
+            return None;
+        };
+
+        let command = text.split_whitespace().next()?.strip_prefix('/')?;
+        match framework {
+            Some(framework) => framework.strip_bot_name(command),
+            None => Some(command.split_once('@').map_or(command, |(name, _)| name)),
+        }
+    }
+}
+
+impl From<Message> for DialogueInput {
+    fn from(message: Message) -> Self {
+        Self::Message(message)
+    }
+}
+
+impl From<CallbackQuery> for DialogueInput {
+    fn from(query: CallbackQuery) -> Self {
+        Self::CallbackQuery(query)
+    }
+}
+
+/// The error returned when a dialogue could not be started or continued
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogueError {
+    /// No flow with the given name has been added
+    UnknownFlow(String),
+    /// The input does not belong to a chat, so it cannot start a dialogue
+    NoChat,
+    /// The user did not respond within the timeout of the flow
+    TimedOut,
+    /// The dialogue was cancelled, finished or restarted while waiting for
+    /// the user
+    Cancelled,
+}
+
+impl std::fmt::Display for DialogueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DialogueError::UnknownFlow(name) => write!(f, "unknown dialogue flow: {name}"),
+            DialogueError::NoChat => f.write_str("the input does not belong to a chat"),
+            DialogueError::TimedOut => f.write_str("the dialogue timed out"),
+            DialogueError::Cancelled => f.write_str("the dialogue was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for DialogueError {}
@@ -0,0 +1,444 @@
+use super::{triggers::get_pattern, types::CommandOptions};
+use crate::{
+    api::types::SendMessage,
+    client::Context,
+    model::{Message, MessageEntity},
+    utils::result::Result,
+};
+use std::ops::Deref;
+
+/// The error returned when the arguments of a command could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgError {
+    /// A required argument was not provided
+    Missing,
+    /// An argument was provided, but could not be parsed into the wanted type
+    Invalid(String),
+    /// More arguments were provided than the command accepts
+    TooMany,
+    /// A quoted argument was opened, but never closed
+    UnclosedQuote,
+}
+
+impl std::fmt::Display for ArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgError::Missing => f.write_str("missing argument"),
+            ArgError::Invalid(e) => write!(f, "invalid argument: {e}"),
+            ArgError::TooMany => f.write_str("too many arguments"),
+            ArgError::UnclosedQuote => f.write_str("unclosed quote in arguments"),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+/// The arguments provided to a command, i.e. the text following the command
+/// itself.
+///
+/// Arguments are separated by whitespace, unless they are wrapped in quotes
+/// (`"like this"`), in which case the quotes are removed and the content is
+/// used as a single argument.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    text: String,
+    position: usize,
+}
+
+impl Args {
+    /// Creates a new `Args` from the text following a command
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            position: 0,
+        }
+    }
+
+    /// Creates a new `Args` from the text of a message, skipping the command
+    /// it was invoked with, be it a `/command`, a command starting with one
+    /// of the given prefixes like `!command` or a command following a mention
+    /// of the bot.
+    pub fn from_message(message: &Message, prefixes: &[&str]) -> Self {
+        match find_command(message, prefixes) {
+            Some((text, end)) => Self::new(&text[end..]),
+            None => Self::new(""),
+        }
+    }
+
+    /// Creates a new `Args` for the given command from the text of a message,
+    /// skipping the command it was invoked with and, when it was invoked as a
+    /// subcommand like `/config set`, the name of the subcommand.
+    ///
+    /// The prefixes of the framework the context belongs to and those of the
+    /// command are the ones a prefixed command is recognised by. For commands
+    /// triggered by a pattern, the arguments are the text following the first
+    /// match of the pattern.
+    pub fn for_command(context: &Context, message: &Message, options: &CommandOptions) -> Self {
+        Self::with_prefixes(message, options, framework_prefixes(context))
+    }
+
+    // creates the `Args` for the given command, with the given prefixes of the
+    // framework
+    pub(crate) fn with_prefixes(
+        message: &Message,
+        options: &CommandOptions,
+        prefixes: &[String],
+    ) -> Self {
+        if let Some(pattern) = options.pattern {
+            let text = message
+                .get_text_with_entities()
+                .map_or("", |(text, _)| text);
+            return match get_pattern(pattern).find(text) {
+                Some(found) => Self::new(&text[found.end()..]),
+                None => Self::new(""),
+            };
+        }
+
+        let prefixes = command_prefixes(prefixes, options);
+        let mut args = Self::from_message(message, &prefixes);
+        if invoked_prefix(message, options, &prefixes).is_some() {
+            let remaining = args.remaining();
+            let end = remaining
+                .find(char::is_whitespace)
+                .unwrap_or(remaining.len());
+            if options.matches_name(&remaining[..end].to_lowercase()) {
+                args.position = args.text.len() - remaining.len() + end;
+            }
+        }
+        args
+    }
+
+    /// The arguments that have not been consumed yet
+    pub fn remaining(&self) -> &str {
+        self.text[self.position..].trim_start()
+    }
+
+    /// Returns true if all arguments have been consumed
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// Consumes the next argument, taking quotes into account
+    pub fn next_arg(&mut self) -> std::result::Result<Option<String>, ArgError> {
+        let remaining = self.remaining().to_owned();
+        let start = self.text.len() - remaining.len();
+
+        let mut chars = remaining.char_indices();
+        let closing = match chars.next() {
+            None => return Ok(None),
+            Some((_, '"')) => '"',
+            Some((_, '“')) => '”',
+            Some(_) => {
+                let end = remaining
+                    .find(char::is_whitespace)
+                    .unwrap_or(remaining.len());
+                self.position = start + end;
+                return Ok(Some(remaining[..end].to_owned()));
+            },
+        };
+
+        let mut arg = String::new();
+        let mut escaped = false;
+        for (i, c) in chars {
+            match c {
+                _ if escaped => {
+                    arg.push(c);
+                    escaped = false;
+                },
+                '\\' => escaped = true,
+                c if c == closing => {
+                    self.position = start + i + c.len_utf8();
+                    return Ok(Some(arg));
+                },
+                c => arg.push(c),
+            }
+        }
+
+        Err(ArgError::UnclosedQuote)
+    }
+
+    /// Consumes all remaining arguments as they were written
+    pub fn rest(&mut self) -> Option<String> {
+        let remaining = self.remaining().trim_end().to_owned();
+        self.position = self.text.len();
+
+        if remaining.is_empty() {
+            None
+        } else {
+            Some(remaining)
+        }
+    }
+
+    /// Parses the next argument(s) into the wanted type
+    pub fn parse<T: FromCommandArg>(&mut self) -> std::result::Result<T, ArgError> {
+        T::extract(self)
+    }
+
+    /// Returns an error if there are still arguments left to consume
+    pub fn finish(&self) -> std::result::Result<(), ArgError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(ArgError::TooMany)
+        }
+    }
+}
+
+// finds the text or caption of the message and the byte index at which the
+// command it invokes ends, which is either a bot command at the start of it, a
+// word following a mention at the start of it, a word starting with one of the
+// given prefixes or a bot command anywhere in it
+fn find_command<'a>(message: &'a Message, prefixes: &[&str]) -> Option<(&'a str, usize)> {
+    let (text, entities) = message.get_text_with_entities()?;
+    let word_end = |start: usize| {
+        let start = start + (text[start..].len() - text[start..].trim_start().len());
+        text[start..]
+            .find(char::is_whitespace)
+            .map_or(text.len(), |end| start + end)
+    };
+
+    let at_start = entities.iter().find_map(|entity| match entity {
+        MessageEntity::BotCommand(block) if block.offset == 0 => {
+            Some(utf16_to_byte_index(text, block.length))
+        },
+        MessageEntity::Mention(block) if block.offset == 0 => {
+            Some(word_end(utf16_to_byte_index(text, block.length)))
+        },
+        _ => None,
+    });
+    if let Some(end) = at_start {
+        return Some((text, end));
+    }
+
+    let prefix = prefixes
+        .iter()
+        .filter(|prefix| !prefix.is_empty() && text.starts_with(*prefix))
+        .max_by_key(|prefix| prefix.len());
+    if let Some(prefix) = prefix {
+        return Some((text, word_end(prefix.len())));
+    }
+
+    let anywhere = entities.iter().find_map(|entity| match entity {
+        MessageEntity::BotCommand(block) => {
+            Some(utf16_to_byte_index(text, block.offset + block.length))
+        },
+        _ => None,
+    });
+    Some((text, anywhere.unwrap_or_else(|| word_end(0))))
+}
+
+// gets the name of the group a command was invoked through as a subcommand,
+// i.e. `config` for `/config set`
+fn invoked_prefix<'a>(
+    message: &'a Message,
+    options: &CommandOptions,
+    prefixes: &[&str],
+) -> Option<&'a str> {
+    let (text, end) = find_command(message, prefixes)?;
+    let command = text[..end].rsplit(char::is_whitespace).next()?;
+    let name = command.trim_start_matches(|c: char| !c.is_alphanumeric() && c != '_');
+    let name = name.split_once('@').map_or(name, |(name, _)| name);
+
+    if options.matches_name(&name.to_lowercase()) {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+// gets the prefixes of the framework the context belongs to
+fn framework_prefixes(context: &Context) -> &[String] {
+    context
+        .framework
+        .as_ref()
+        .map_or(&[], |framework| framework.get_prefixes())
+}
+
+// gets the prefixes the given command can be invoked with
+fn command_prefixes<'a>(prefixes: &'a [String], options: &CommandOptions) -> Vec<&'a str> {
+    let mut all: Vec<&str> = prefixes.iter().map(String::as_str).collect();
+    all.extend(options.prefixes);
+    all
+}
+
+fn utf16_to_byte_index(text: &str, units: usize) -> usize {
+    let mut count = 0;
+    for (i, c) in text.char_indices() {
+        if count >= units {
+            return i;
+        }
+        count += c.len_utf16();
+    }
+    text.len()
+}
+
+/// A trait for types that can be used as a typed argument of a command created
+/// with the `#[command]` macro.
+///
+/// It is implemented for the primitive number types, `bool`, `char`,
+/// `String`, [`Rest`] and `Option<T>` for every `T` implementing it, the
+/// latter making an argument optional.
+///
+/// # Examples
+///
+/// ```rust
+/// use telexide_fork::framework::{ArgError, FromCommandArg};
+///
+/// struct Dice(u8);
+///
+/// impl FromCommandArg for Dice {
+///     fn from_arg(arg: &str) -> Result<Self, ArgError> {
+///         match arg.strip_prefix('d').map(str::parse) {
+///             Some(Ok(sides)) => Ok(Self(sides)),
+///             _ => Err(ArgError::Invalid(format!("{} is not a dice", arg))),
+///         }
+///     }
+/// }
+/// ```
+pub trait FromCommandArg: Sized {
+    /// Parses a single argument
+    fn from_arg(arg: &str) -> std::result::Result<Self, ArgError>;
+
+    /// Takes the value out of the arguments, by default a single argument is
+    /// consumed and parsed using [`FromCommandArg::from_arg`]
+    fn extract(args: &mut Args) -> std::result::Result<Self, ArgError> {
+        match args.next_arg()? {
+            Some(arg) => Self::from_arg(&arg),
+            None => Err(ArgError::Missing),
+        }
+    }
+
+    /// How the argument is displayed in the usage of a command
+    fn usage(name: &str) -> String {
+        format!("<{name}>")
+    }
+}
+
+macro_rules! from_str_args {
+    ($($t:ty),*) => {
+        $(
+            impl FromCommandArg for $t {
+                fn from_arg(arg: &str) -> std::result::Result<Self, ArgError> {
+                    arg.parse::<$t>().map_err(|e| ArgError::Invalid(format!("{arg}: {e}")))
+                }
+            }
+        )*
+    };
+}
+
+from_str_args!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char, String
+);
+
+impl FromCommandArg for bool {
+    fn from_arg(arg: &str) -> std::result::Result<Self, ArgError> {
+        match arg.to_lowercase().as_str() {
+            "true" | "yes" | "y" | "on" | "1" => Ok(true),
+            "false" | "no" | "n" | "off" | "0" => Ok(false),
+            _ => Err(ArgError::Invalid(format!("{arg}: expected yes or no"))),
+        }
+    }
+}
+
+impl<T: FromCommandArg> FromCommandArg for Option<T> {
+    fn from_arg(arg: &str) -> std::result::Result<Self, ArgError> {
+        T::from_arg(arg).map(Some)
+    }
+
+    fn extract(args: &mut Args) -> std::result::Result<Self, ArgError> {
+        if args.is_empty() {
+            Ok(None)
+        } else {
+            T::extract(args).map(Some)
+        }
+    }
+
+    fn usage(name: &str) -> String {
+        let inner = T::usage(name);
+        format!("[{}]", inner.trim_start_matches('<').trim_end_matches('>'))
+    }
+}
+
+/// A command argument consuming all remaining text of the message, as it was
+/// written by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rest(pub String);
+
+impl Deref for Rest {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Rest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Rest> for String {
+    fn from(rest: Rest) -> Self {
+        rest.0
+    }
+}
+
+impl FromCommandArg for Rest {
+    fn from_arg(arg: &str) -> std::result::Result<Self, ArgError> {
+        Ok(Self(arg.to_owned()))
+    }
+
+    fn extract(args: &mut Args) -> std::result::Result<Self, ArgError> {
+        args.rest().map(Self).ok_or(ArgError::Missing)
+    }
+
+    fn usage(name: &str) -> String {
+        format!("<{name}...>")
+    }
+}
+
+/// Describes a typed argument of a command, used for generating its usage
+#[derive(Clone)]
+pub struct CommandArgument {
+    /// The name of the argument
+    pub name: &'static str,
+    /// Formats the argument for the usage of the command, is
+    /// [`FromCommandArg::usage`] of the type of the argument
+    pub usage: fn(&str) -> String,
+}
+
+impl CommandArgument {
+    /// How the argument is displayed in the usage of a command
+    pub fn get_usage(&self) -> String {
+        (self.usage)(self.name)
+    }
+}
+
+// used by the `#[command]` macro to tell the user how to use a command when
+// parsing its arguments failed
+#[doc(hidden)]
+pub async fn reply_usage(
+    context: &Context,
+    message: &Message,
+    options: &CommandOptions,
+    error: &ArgError,
+) -> Result<()> {
+    // the command wasn't used, so it shouldn't be on cooldown
+    if let Some(framework) = &context.framework {
+        framework.cooldowns.release(options, message);
+    }
+
+    let prefixes = command_prefixes(framework_prefixes(context), options);
+    let usage = match invoked_prefix(message, options, &prefixes) {
+        Some(prefix) => format!("/{} {}", prefix, &options.usage()[1..]),
+        None => options.usage(),
+    };
+    let text = format!("{error}\nUsage: {usage}");
+
+    let mut data = SendMessage::new(message.chat.get_id(), &text);
+    data.reply_to_message(message);
+    context.api.send_message(data).await?;
+
+    Ok(())
+}
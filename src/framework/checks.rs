@@ -0,0 +1,307 @@
+use super::types::CommandOptions;
+use crate::{
+    api::types::{GetChat, GetChatAdministrators, GetChatMember, SendMessage},
+    client::Context,
+    model::{Chat, ChatMember, ChatPermissions, Message},
+};
+use async_trait::async_trait;
+use parking_lot::{const_mutex, Mutex};
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+/// The reason a [`Check`] did not allow a command to be called, it will be
+/// sent to the user unless the command has a `denial_reply` set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckError(pub String);
+
+impl<T: std::fmt::Display> From<T> for CheckError {
+    #[inline]
+    fn from(d: T) -> Self {
+        CheckError(d.to_string())
+    }
+}
+
+/// A type alias for an std Result with the [`CheckError`] as the Err and ()
+/// as the Ok
+pub type CheckResult = ::std::result::Result<(), CheckError>;
+
+/// A check that has to pass before a command is called, added to a command
+/// using the `checks = [...]` option of the `#[command]` macro.
+///
+/// # Examples
+///
+/// ```rust
+/// use telexide_fork::{
+///     framework::{Check, CheckResult},
+///     prelude::*,
+/// };
+///
+/// struct NoForwards;
+///
+/// #[telexide_fork::framework::async_trait]
+/// impl Check for NoForwards {
+///     async fn check(&self, _context: &Context, message: &Message) -> CheckResult {
+///         if message.forward_data.is_some() {
+///             return Err("commands cannot be forwarded".into());
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// #[command(description = "say hi", checks = [NoForwards])]
+/// async fn hi(_context: Context, _message: Message) -> CommandResult {
+///     Ok(())
+/// }
+/// ```
+#[async_trait]
+pub trait Check: Send + Sync {
+    /// Checks whether the command is allowed to be called for the message
+    async fn check(&self, context: &Context, message: &Message) -> CheckResult;
+}
+
+// runs the given checks for a command, replying to the message if one of
+// them fails
+pub(crate) async fn run_checks(
+    context: &Context,
+    message: &Message,
+    options: &CommandOptions,
+    checks: &[&dyn Check],
+) -> bool {
+    for check in checks {
+        if let Err(e) = check.check(context, message).await {
+            let reply = options.denial_reply.unwrap_or(&e.0);
+            if !reply.is_empty() {
+                let mut data = SendMessage::new(message.chat.get_id(), reply);
+                data.reply_to_message(message);
+                if let Err(err) = context.api.send_message(data).await {
+                    log::warn!(
+                        "failed to reply to denied command {}: {}",
+                        options.name,
+                        err
+                    );
+                }
+            }
+
+            log::debug!("command {} denied: {}", options.name, e.0);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Only allows a command to be used in private chats
+pub struct PrivateOnly;
+
+#[async_trait]
+impl Check for PrivateOnly {
+    async fn check(&self, _context: &Context, message: &Message) -> CheckResult {
+        match message.chat {
+            Chat::Private(_) => Ok(()),
+            _ => Err("this command can only be used in private chats".into()),
+        }
+    }
+}
+
+/// Only allows a command to be used in groups and supergroups
+pub struct GroupOnly;
+
+#[async_trait]
+impl Check for GroupOnly {
+    async fn check(&self, _context: &Context, message: &Message) -> CheckResult {
+        match message.chat {
+            Chat::Group(_) | Chat::SuperGroup(_) => Ok(()),
+            _ => Err("this command can only be used in groups".into()),
+        }
+    }
+}
+
+/// Only allows a command to be used by the owner of the bot, given their user
+/// id
+pub struct OwnerOnly(pub i64);
+
+#[async_trait]
+impl Check for OwnerOnly {
+    async fn check(&self, _context: &Context, message: &Message) -> CheckResult {
+        match &message.from {
+            Some(user) if user.id == self.0 => Ok(()),
+            _ => Err("this command can only be used by the owner of the bot".into()),
+        }
+    }
+}
+
+/// Only allows a command to be used by the users with the given ids
+pub struct Whitelist(pub &'static [i64]);
+
+#[async_trait]
+impl Check for Whitelist {
+    async fn check(&self, _context: &Context, message: &Message) -> CheckResult {
+        match &message.from {
+            Some(user) if self.0.contains(&user.id) => Ok(()),
+            _ => Err("you are not allowed to use this command".into()),
+        }
+    }
+}
+
+static ADMIN_CACHE: Mutex<BTreeMap<i64, (Instant, Vec<i64>)>> = const_mutex(BTreeMap::new());
+
+/// Only allows a command to be used by administrators of the group it is used
+/// in, including anonymous administrators.
+///
+/// The administrators of a chat are cached for
+/// [`AdminOnly::CACHE_DURATION`], use [`AdminOnly::invalidate`] to clear the
+/// cache for a chat when its administrators change.
+pub struct AdminOnly;
+
+impl AdminOnly {
+    /// How long the administrators of a chat are cached
+    #[allow(clippy::duration_suboptimal_units)] // `from_mins` needs Rust 1.91
+    pub const CACHE_DURATION: Duration = Duration::from_secs(5 * 60);
+
+    /// Clears the cached administrators of the given chat
+    pub fn invalidate(chat_id: i64) {
+        ADMIN_CACHE.lock().remove(&chat_id);
+    }
+
+    async fn get_admins(context: &Context, chat_id: i64) -> crate::Result<Vec<i64>> {
+        if let Some((at, admins)) = ADMIN_CACHE.lock().get(&chat_id) {
+            if at.elapsed() < Self::CACHE_DURATION {
+                return Ok(admins.clone());
+            }
+        }
+
+        let admins: Vec<i64> = context
+            .api
+            .get_chat_administrators(GetChatAdministrators { chat_id })
+            .await?
+            .iter()
+            .map(|m| m.get_user().id)
+            .collect();
+
+        ADMIN_CACHE
+            .lock()
+            .insert(chat_id, (Instant::now(), admins.clone()));
+        Ok(admins)
+    }
+}
+
+#[async_trait]
+impl Check for AdminOnly {
+    async fn check(&self, context: &Context, message: &Message) -> CheckResult {
+        let chat_id = message.chat.get_id();
+        match message.chat {
+            Chat::Group(_) | Chat::SuperGroup(_) => (),
+            _ => return Err("this command can only be used in groups".into()),
+        }
+
+        if matches!(&message.sender_chat, Some(c) if c.get_id() == chat_id) {
+            return Ok(());
+        }
+
+        let user_id = match &message.from {
+            Some(user) => user.id,
+            None => return Err("this command can only be used by administrators".into()),
+        };
+
+        let admins = Self::get_admins(context, chat_id).await?;
+        if admins.contains(&user_id) {
+            Ok(())
+        } else {
+            Err("this command can only be used by administrators".into())
+        }
+    }
+}
+
+/// A single permission of the [`ChatPermissions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatPermission {
+    CanSendMessages,
+    CanSendMediaMessages,
+    CanSendPolls,
+    CanSendOtherMessages,
+    CanAddWebPagePreviews,
+    CanChangeInfo,
+    CanInviteUsers,
+    CanPinMessages,
+}
+
+impl ChatPermission {
+    /// Gets the value of this permission in the given [`ChatPermissions`]
+    pub fn get(self, permissions: &ChatPermissions) -> bool {
+        match self {
+            Self::CanSendMessages => permissions.can_send_messages,
+            Self::CanSendMediaMessages => permissions.can_send_media_messages,
+            Self::CanSendPolls => permissions.can_send_polls,
+            Self::CanSendOtherMessages => permissions.can_send_other_messages,
+            Self::CanAddWebPagePreviews => permissions.can_add_web_page_previews,
+            Self::CanChangeInfo => permissions.can_change_info,
+            Self::CanInviteUsers => permissions.can_invite_users,
+            Self::CanPinMessages => permissions.can_pin_messages,
+        }
+    }
+
+    fn get_for_member(self, member: &ChatMember) -> Option<bool> {
+        Some(match member {
+            ChatMember::Creator(_) => true,
+            ChatMember::Administrator(m) => match self {
+                Self::CanChangeInfo => m.can_change_info,
+                Self::CanInviteUsers => m.can_invite_users,
+                Self::CanPinMessages => m.can_pin_messages.unwrap_or(true),
+                _ => true,
+            },
+            ChatMember::Restricted(m) => self.get(&ChatPermissions {
+                can_send_messages: m.can_send_messages,
+                can_send_media_messages: m.can_send_media_messages,
+                can_send_polls: m.can_send_polls,
+                can_send_other_messages: m.can_send_other_messages,
+                can_add_web_page_previews: m.can_add_web_page_previews,
+                can_change_info: m.can_change_info,
+                can_invite_users: m.can_invite_users,
+                can_pin_messages: m.can_pin_messages,
+            }),
+            ChatMember::Left(_) | ChatMember::Kicked(_) => false,
+            ChatMember::Member(_) => return None,
+        })
+    }
+}
+
+/// Only allows a command to be used by users having the given permission in
+/// the group it is used in. For normal members the default permissions of the
+/// chat are used.
+pub struct HasPermission(pub ChatPermission);
+
+#[async_trait]
+impl Check for HasPermission {
+    async fn check(&self, context: &Context, message: &Message) -> CheckResult {
+        let chat_id = message.chat.get_id();
+        let user_id = match (&message.chat, &message.from) {
+            (Chat::Private(_), _) => return Ok(()),
+            (_, Some(user)) => user.id,
+            (_, None) => return Err("you are not allowed to use this command".into()),
+        };
+
+        let member = context
+            .api
+            .get_chat_member(GetChatMember { chat_id, user_id })
+            .await?;
+
+        let allowed = if let Some(allowed) = self.0.get_for_member(&member) {
+            allowed
+        } else {
+            let permissions = match context.api.get_chat(GetChat { chat_id }).await? {
+                Chat::Group(c) => c.permissions,
+                Chat::SuperGroup(c) => c.permissions,
+                _ => None,
+            };
+            permissions.is_some_and(|p| self.0.get(&p))
+        };
+
+        if allowed {
+            Ok(())
+        } else {
+            Err("you are not allowed to use this command".into())
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use super::types::CommandOptions;
+use crate::{api::types::SendMessage, client::Context, model::Message};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Who shares a [`Cooldown`] of a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CooldownBucket {
+    /// Every user has their own cooldown
+    User,
+    /// Every chat has its own cooldown, shared by all users in it
+    Chat,
+    /// The cooldown is shared by everyone using the command
+    Global,
+}
+
+/// The minimum time between two uses of a command, added to a command using
+/// the `cooldown = "10s per user"` option of the `#[command]` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cooldown {
+    /// The time that has to pass before the command can be used again
+    pub duration: Duration,
+    /// Who shares the cooldown
+    pub bucket: CooldownBucket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CooldownKey {
+    User(i64),
+    Chat(i64),
+    Global,
+}
+
+impl CooldownKey {
+    fn new(bucket: CooldownBucket, message: &Message) -> Self {
+        match bucket {
+            CooldownBucket::User => message.from.as_ref().map_or_else(
+                || {
+                    Self::User(
+                        message
+                            .sender_chat
+                            .as_ref()
+                            .unwrap_or(&message.chat)
+                            .get_id(),
+                    )
+                },
+                |u| Self::User(u.id),
+            ),
+            CooldownBucket::Chat => Self::Chat(message.chat.get_id()),
+            CooldownBucket::Global => Self::Global,
+        }
+    }
+}
+
+/// Keeps track of when commands with a [`Cooldown`] can be used again
+#[derive(Debug, Default)]
+pub(crate) struct Cooldowns {
+    // commands are keyed on the address of their static options, as commands
+    // in different groups can share a name
+    until: Mutex<HashMap<(usize, CooldownKey), Instant>>,
+}
+
+impl Cooldowns {
+    /// Registers a use of the command, returning the time left before it can
+    /// be used again if it is still on cooldown
+    pub(crate) fn try_use(
+        &self,
+        options: &CommandOptions,
+        message: &Message,
+    ) -> Result<(), Duration> {
+        let Some(cooldown) = options.cooldown else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let key = Self::key(options, cooldown, message);
+
+        let mut until = self.until.lock();
+        if let Some(u) = until.get(&key) {
+            if *u > now {
+                return Err(*u - now);
+            }
+        }
+
+        until.retain(|_, u| *u > now);
+        until.insert(key, now + cooldown.duration);
+        Ok(())
+    }
+
+    /// Undoes the use of the command registered by [`try_use`], for when the
+    /// command could not be used after all
+    ///
+    /// [`try_use`]: #method.try_use
+    pub(crate) fn release(&self, options: &CommandOptions, message: &Message) {
+        if let Some(cooldown) = options.cooldown {
+            self.until
+                .lock()
+                .remove(&Self::key(options, cooldown, message));
+        }
+    }
+
+    fn key(
+        options: &CommandOptions,
+        cooldown: Cooldown,
+        message: &Message,
+    ) -> (usize, CooldownKey) {
+        (
+            std::ptr::addr_of!(*options) as usize,
+            CooldownKey::new(cooldown.bucket, message),
+        )
+    }
+}
+
+// registers the use of a command, replying to the message with the
+// `cooldown_reply` of the command if it is still on cooldown
+pub(crate) async fn use_cooldown(
+    cooldowns: &Cooldowns,
+    context: &Context,
+    message: &Message,
+    options: &CommandOptions,
+) -> bool {
+    let remaining = match cooldowns.try_use(options, message) {
+        Ok(()) => return true,
+        Err(remaining) => remaining,
+    };
+
+    log::debug!("command {} is on cooldown", options.name);
+    if let Some(reply) = options.cooldown_reply {
+        let text = reply.replace("{remaining}", &format_remaining(remaining));
+        let mut data = SendMessage::new(message.chat.get_id(), &text);
+        data.reply_to_message(message);
+        if let Err(err) = context.api.send_message(data).await {
+            log::warn!(
+                "failed to reply to command {} on cooldown: {}",
+                options.name,
+                err
+            );
+        }
+    }
+
+    false
+}
+
+/// Formats the time left on a cooldown for telling it to a user, for example
+/// `1m 5s`
+pub fn format_remaining(remaining: Duration) -> String {
+    let mut secs = remaining.as_secs();
+    if remaining.subsec_nanos() > 0 {
+        secs += 1;
+    }
+
+    let (hours, minutes, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes > 0 {
+        parts.push(format!("{minutes}m"));
+    }
+    if secs > 0 || parts.is_empty() {
+        parts.push(format!("{secs}s"));
+    }
+
+    parts.join(" ")
+}
@@ -1,80 +1,387 @@
-use super::types::{CommandTypes, TelegramCommand};
+use super::{
+    args::Args,
+    checks::run_checks,
+    cooldown::{use_cooldown, Cooldowns},
+    help::{render_group, render_help, reply_help, HELP_COMMAND_OPTIONS},
+    triggers::{get_pattern, CommandTriggers, Invocation},
+    types::{CommandGroup, CommandOptions, CommandTypes, TelegramCommand},
+};
 use crate::{
     client::Context,
-    model::{Message, MessageContent, MessageEntity, Update, UpdateContent},
+    model::{Message, MessageEntity, Update},
 };
 use log::{debug, warn};
+use parking_lot::RwLock;
+use std::sync::Arc;
 
 /// A utility for easily managing commands.
 ///
 /// Refer to the [module-level documentation](index.html) for more detail
 pub struct Framework {
     commands: Vec<TelegramCommand>,
-    bot_name: String,
+    groups: Vec<&'static CommandGroup>,
+    bot_name: RwLock<String>,
+    triggers: CommandTriggers,
+    prefixes: Vec<String>,
+    mention_prefix: bool,
+    help: bool,
+    pub(super) cooldowns: Arc<Cooldowns>,
 }
 
 impl Framework {
-    /// Creates a new framework instance given the bot name
+    /// Creates a new framework instance given the bot name.
+    ///
+    /// When the framework is used by a [`Client`], the bot name is replaced by
+    /// the username of the bot as returned by `getMe` once the client starts.
+    ///
+    /// [`Client`]: ../client/struct.Client.html
     pub fn new(bot_name: &str) -> Self {
         Self {
             commands: Vec::new(),
-            bot_name: bot_name.to_owned(),
+            groups: Vec::new(),
+            bot_name: RwLock::new(bot_name.to_owned()),
+            triggers: CommandTriggers::default(),
+            prefixes: Vec::new(),
+            mention_prefix: false,
+            help: true,
+            cooldowns: Arc::new(Cooldowns::default()),
+        }
+    }
+
+    // gets the name of the command triggered by the message and how it was
+    // invoked, if it is meant for this bot
+    fn get_command_name(&self, message: &Message) -> Option<(String, Invocation)> {
+        let (command, invocation) = match self.triggers.get_command(message) {
+            Some(command) => (
+                command.trim_start_matches('/').to_owned(),
+                Invocation::Slash,
+            ),
+            None => self.get_prefixed_command(message)?,
+        };
+
+        let name = self.strip_bot_name(&command)?;
+        if self.triggers.case_insensitive {
+            Some((name.to_lowercase(), invocation))
+        } else {
+            Some((name.to_owned(), invocation))
+        }
+    }
+
+    // strips the name of the bot the command is addressed to, like
+    // `/command@bot_name`, returns None if it is addressed to another bot
+    pub(crate) fn strip_bot_name<'a>(&self, command: &'a str) -> Option<&'a str> {
+        match command.split_once('@') {
+            Some((name, bot_name)) if bot_name.eq_ignore_ascii_case(&self.bot_name.read()) => {
+                Some(name)
+            },
+            Some(_) => None,
+            None => Some(command),
+        }
+    }
+
+    // gets the command at the start of the message that is invoked by
+    // mentioning the bot or using one of the prefixes
+    fn get_prefixed_command(&self, message: &Message) -> Option<(String, Invocation)> {
+        let (text, entities) = self.triggers.get_text(message)?;
+
+        let mention = entities.iter().find_map(|entity| match entity {
+            MessageEntity::Mention(t) if t.offset == 0 => Some(t.get_text(text)),
+            _ => None,
+        });
+        if let Some(mention) = mention {
+            if mention[1..].eq_ignore_ascii_case(&self.bot_name.read()) {
+                let command = text[mention.len()..].split_whitespace().next()?;
+                return Some((
+                    command.trim_start_matches('/').to_owned(),
+                    Invocation::Mention,
+                ));
+            }
+        }
+
+        let prefix = self
+            .get_all_prefixes()
+            .into_iter()
+            .filter(|prefix| text.starts_with(prefix))
+            .max_by_key(|prefix| prefix.len())?;
+        let command = text[prefix.len()..]
+            .split(char::is_whitespace)
+            .next()
+            .filter(|command| !command.is_empty())?;
+
+        Some((command.to_owned(), Invocation::Prefix(prefix.to_owned())))
+    }
+
+    // gets the prefixes of the framework and those of all registered commands
+    fn get_all_prefixes(&self) -> Vec<&str> {
+        let grouped = self.groups.iter().flat_map(|g| g.commands.iter().copied());
+        let mut prefixes: Vec<&str> = self.prefixes.iter().map(String::as_str).collect();
+        for command in self.commands.iter().chain(grouped) {
+            prefixes.extend(command.options.prefixes);
+        }
+        prefixes
+    }
+
+    // returns true if any command can be invoked in the given way
+    fn accepts_invocation(&self, invocation: &Invocation) -> bool {
+        match invocation {
+            Invocation::Slash => true,
+            Invocation::Prefix(prefix) => self.prefixes.contains(prefix),
+            Invocation::Mention => self.mention_prefix,
+        }
+    }
+
+    // returns true if the given command can be invoked in the given way,
+    // which is never the case for commands triggered by a pattern
+    fn accepts_command(&self, invocation: &Invocation, options: &CommandOptions) -> bool {
+        if options.pattern.is_some() {
+            return false;
+        }
+
+        self.accepts_invocation(invocation)
+            || match invocation {
+                Invocation::Slash => true,
+                Invocation::Prefix(prefix) => options.prefixes.contains(&prefix.as_str()),
+                Invocation::Mention => options.mention,
+            }
+    }
+
+    // gets the first word following the command, used as the name of a
+    // subcommand
+    fn get_subcommand_name(&self, message: &Message) -> Option<String> {
+        let args = Args::from_message(message, &self.get_all_prefixes());
+        let name = args.remaining().split_whitespace().next()?;
+        if self.triggers.case_insensitive {
+            Some(name.to_lowercase())
+        } else {
+            Some(name.to_owned())
+        }
+    }
+
+    // whether the command name is the prefix of a group, ignoring its case
+    // when the command names are matched case-insensitively
+    fn matches_group_prefix(&self, prefix: &str, name: &str) -> bool {
+        if self.triggers.case_insensitive {
+            prefix.eq_ignore_ascii_case(name)
+        } else {
+            prefix == name
+        }
+    }
+
+    fn fire_command(
+        &self,
+        context: Context,
+        message: Message,
+        command: &TelegramCommand,
+        group: Option<&'static CommandGroup>,
+    ) {
+        match command.command.clone() {
+            CommandTypes::Default(c) => {
+                let options = command.options;
+                let command_name = options.name;
+                let cooldowns = self.cooldowns.clone();
+
+                tokio::spawn(async move {
+                    let group_checks = group.map_or(&[][..], |g| g.checks);
+                    if !run_checks(&context, &message, options, group_checks).await
+                        || !run_checks(&context, &message, options, options.checks).await
+                        || !use_cooldown(&cooldowns, &context, &message, options).await
+                    {
+                        return;
+                    }
+
+                    debug!("calling command {}", &command_name);
+                    if let Err(e) = c(context, message).await {
+                        warn!("command {} returned error: {}", &command_name, e.0);
+                    }
+                });
+            },
         }
     }
 
-    fn match_command(&self, message: &Message, name: &str) -> bool {
-        if let MessageContent::Text { entities, content } = &message.content {
-            for entity in entities {
-                if let MessageEntity::BotCommand(ref t) = entity {
-                    let t = t.get_text(content);
-                    return t == format!("/{name}")
-                        || t == format!("/{}@{}", name, &self.bot_name);
+    fn fire_help(context: Context, message: Message, text: String) {
+        tokio::spawn(async move {
+            debug!("calling command {}", HELP_COMMAND_OPTIONS.name);
+            reply_help(&context, &message, text).await;
+        });
+    }
+
+    // fires off the commands with a pattern matching the text of the message
+    fn fire_pattern_commands(&self, context: &Context, message: &Message) {
+        let Some((text, _)) = self.triggers.get_text(message) else {
+            return;
+        };
+        let grouped = self
+            .groups
+            .iter()
+            .flat_map(|g| g.commands.iter().map(move |c| (*c, Some(*g))));
+
+        for (command, group) in self.commands.iter().map(|c| (c, None)).chain(grouped) {
+            if let Some(pattern) = command.options.pattern {
+                if get_pattern(pattern).is_match(text) {
+                    self.fire_command(context.clone(), message.clone(), command, group);
                 }
             }
         }
-        false
     }
 
     #[allow(clippy::needless_pass_by_value)]
     fn fire_message_commands(&self, context: Context, message: Message) {
+        self.fire_pattern_commands(&context, &message);
+
+        let Some((name, invocation)) = self.get_command_name(&message) else {
+            return;
+        };
+        let name = name.as_str();
+
         for command in &self.commands {
-            match command.command.clone() {
-                CommandTypes::Default(c) if self.match_command(&message, command.options.name) => {
-                    let ctx = context.clone();
-                    let msg = message.clone();
-                    let command_name = command.options.name;
-                    debug!("calling command {}", &command_name);
+            if command.options.matches_name(name)
+                && self.accepts_command(&invocation, command.options)
+            {
+                self.fire_command(context.clone(), message.clone(), command, None);
+            }
+        }
 
-                    tokio::spawn(async move {
-                        let res = c(ctx, msg).await;
-                        if res.is_err() {
-                            warn!(
-                                "command {} returned error: {}",
-                                &command_name,
-                                res.unwrap_err().0
+        for group in &self.groups {
+            match group.prefix {
+                Some(prefix) if self.matches_group_prefix(prefix, name) => {
+                    let sub = self.get_subcommand_name(&message);
+                    match sub.and_then(|sub| group.get_command(&sub)) {
+                        Some(command) if self.accepts_command(&invocation, command.options) => {
+                            self.fire_command(
+                                context.clone(),
+                                message.clone(),
+                                command,
+                                Some(group),
                             );
-                        }
-                    });
-                }
-                CommandTypes::Default(_) => (),
+                        },
+                        None if self.help && self.accepts_invocation(&invocation) => {
+                            Self::fire_help(context.clone(), message.clone(), render_group(group));
+                        },
+                        Some(_) | None => (),
+                    }
+                },
+                Some(_) => (),
+                None => {
+                    let command = group
+                        .get_command(name)
+                        .filter(|c| self.accepts_command(&invocation, c.options));
+                    if let Some(command) = command {
+                        self.fire_command(context.clone(), message.clone(), command, Some(group));
+                    }
+                },
             }
         }
+
+        if self.has_help()
+            && HELP_COMMAND_OPTIONS.matches_name(name)
+            && self.accepts_command(&invocation, &HELP_COMMAND_OPTIONS)
+        {
+            let text = render_help(self, &message);
+            Self::fire_help(context, message, text);
+        }
     }
 
     /// add a command to the registered commands
+    ///
+    /// # Panics
+    ///
+    /// Panics when the command has a pattern that is not a valid regex
     pub fn add_command(&mut self, command: &TelegramCommand) {
+        if let Some(pattern) = command.options.pattern {
+            get_pattern(pattern);
+        }
         self.commands.push(command.clone());
     }
 
-    /// get all registered commands
+    /// add a group of commands to the registered commands
+    ///
+    /// # Panics
+    ///
+    /// Panics when one of the commands has a pattern that is not a valid regex
+    pub fn add_group(&mut self, group: &'static CommandGroup) {
+        for pattern in group.commands.iter().filter_map(|c| c.options.pattern) {
+            get_pattern(pattern);
+        }
+        self.groups.push(group);
+    }
+
+    /// get all registered commands that are not part of a group
     pub fn get_commands(&self) -> &Vec<TelegramCommand> {
         &self.commands
     }
 
+    /// get all registered groups of commands
+    pub fn get_groups(&self) -> &Vec<&'static CommandGroup> {
+        &self.groups
+    }
+
+    /// get the name of the bot commands have to be addressed to when they are
+    /// given one, like `/command@bot_name`
+    pub fn get_bot_name(&self) -> String {
+        self.bot_name.read().clone()
+    }
+
+    /// set the name of the bot commands have to be addressed to, this is done
+    /// automatically by the [`Client`] when it starts
+    ///
+    /// [`Client`]: ../client/struct.Client.html
+    pub fn set_bot_name(&self, bot_name: &str) {
+        bot_name.clone_into(&mut self.bot_name.write());
+    }
+
+    /// get which updates and messages trigger commands
+    pub fn get_triggers(&self) -> CommandTriggers {
+        self.triggers
+    }
+
+    /// set which updates and messages trigger commands, see
+    /// [`CommandTriggers`] for more detail
+    pub fn set_triggers(&mut self, triggers: CommandTriggers) {
+        self.triggers = triggers;
+    }
+
+    /// add a prefix all commands can be invoked with besides `/`, like `!` for
+    /// `!command`
+    pub fn add_prefix(&mut self, prefix: &str) {
+        self.prefixes.push(prefix.to_owned());
+    }
+
+    /// get the prefixes all commands can be invoked with besides `/`
+    pub fn get_prefixes(&self) -> &Vec<String> {
+        &self.prefixes
+    }
+
+    /// set whether all commands can be invoked by mentioning the bot at the
+    /// start of the message, like `@bot_name command`
+    pub fn set_mention_prefix(&mut self, enabled: bool) {
+        self.mention_prefix = enabled;
+    }
+
+    /// get whether all commands can be invoked by mentioning the bot at the
+    /// start of the message
+    pub fn get_mention_prefix(&self) -> bool {
+        self.mention_prefix
+    }
+
+    /// disables the built-in `/help` command
+    pub fn disable_help(&mut self) {
+        self.help = false;
+    }
+
+    /// returns true if the built-in `/help` command is used, which is the case
+    /// unless it is disabled or a command named `help` has been registered
+    pub fn has_help(&self) -> bool {
+        self.help
+            && !self.commands.iter().any(|c| c.options.matches_name("help"))
+            && !self.groups.iter().any(|g| {
+                g.prefix == Some("help") || g.prefix.is_none() && g.get_command("help").is_some()
+            })
+    }
+
     /// fires off all commands matching the content in the update
     pub fn fire_commands(&self, context: Context, update: Update) {
-        if let UpdateContent::Message(c) = update.content {
-            self.fire_message_commands(context, c);
+        if let Some(message) = self.triggers.get_message(update) {
+            self.fire_message_commands(context, message);
         }
     }
 }
@@ -0,0 +1,174 @@
+use super::{
+    args::{Args, CommandArgument, FromCommandArg},
+    cooldown::{format_remaining, CooldownBucket},
+    framework::Framework,
+    types::{CommandGroup, CommandOptions},
+};
+use crate::{api::types::SendMessage, client::Context, model::Message};
+use std::fmt::Write;
+
+/// The options of the built-in `/help` command of the [`Framework`], which
+/// lists the commands and shows the details of a single one when given its
+/// name
+pub static HELP_COMMAND_OPTIONS: CommandOptions = CommandOptions {
+    name: "help",
+    description: "Shows the available commands and how to use them",
+    arguments: &[CommandArgument {
+        name: "command",
+        usage: <Option<String> as FromCommandArg>::usage,
+    }],
+    aliases: &[],
+    prefixes: &[],
+    mention: false,
+    pattern: None,
+    hidden: false,
+    scopes: &[],
+    language_code: None,
+    checks: &[],
+    denial_reply: None,
+    cooldown: None,
+    cooldown_reply: None,
+};
+
+// replies to the message with the generated help
+pub(crate) async fn reply_help(context: &Context, message: &Message, text: String) {
+    let mut data = SendMessage::new(message.chat.get_id(), &text);
+    data.reply_to_message(message);
+    if let Err(err) = context.api.send_message(data).await {
+        log::warn!("failed to reply to help command: {err}");
+    }
+}
+
+/// Generates the help for the text following the `/help` command in the
+/// message: an overview of all commands when it is empty, or the details of
+/// the command or group with the given name.
+pub fn render_help(framework: &Framework, message: &Message) -> String {
+    let query = Args::with_prefixes(message, &HELP_COMMAND_OPTIONS, framework.get_prefixes())
+        .rest()
+        .unwrap_or_default();
+    let mut words = query.split_whitespace();
+
+    let name = match words.next() {
+        Some(name) => name.trim_start_matches('/'),
+        None => return render_overview(framework),
+    };
+
+    if let Some(group) = framework
+        .get_groups()
+        .iter()
+        .find(|g| g.prefix == Some(name))
+    {
+        return match words.next().and_then(|sub| group.get_command(sub)) {
+            Some(command) if !command.options.hidden => {
+                render_command(Some(group), command.options)
+            },
+            _ => render_group(group),
+        };
+    }
+
+    if framework.has_help() && HELP_COMMAND_OPTIONS.matches_name(name) {
+        return render_command(None, &HELP_COMMAND_OPTIONS);
+    }
+
+    if let Some(command) = framework
+        .get_commands()
+        .iter()
+        .find(|c| !c.options.hidden && c.options.matches_name(name))
+    {
+        return render_command(None, command.options);
+    }
+
+    for group in framework.get_groups().iter().filter(|g| g.prefix.is_none()) {
+        if let Some(command) = group.get_command(name).filter(|c| !c.options.hidden) {
+            return render_command(Some(group), command.options);
+        }
+    }
+
+    format!("Unknown command: /{name}\nUse /help to see the available commands")
+}
+
+fn render_overview(framework: &Framework) -> String {
+    let mut text = String::from("Available commands:\n");
+    if framework.has_help() {
+        push_line(
+            &mut text,
+            &HELP_COMMAND_OPTIONS.usage(),
+            HELP_COMMAND_OPTIONS.description,
+        );
+    }
+    for command in framework
+        .get_commands()
+        .iter()
+        .filter(|c| !c.options.hidden)
+    {
+        push_line(
+            &mut text,
+            &command.options.usage(),
+            command.options.description,
+        );
+    }
+
+    for group in framework.get_groups() {
+        text.push('\n');
+        text.push_str(&render_group(group));
+    }
+
+    text.push_str("\nUse /help <command> to see how to use a command");
+    text
+}
+
+pub(crate) fn render_group(group: &CommandGroup) -> String {
+    let mut text = if group.description.is_empty() {
+        format!("{}:\n", group.name)
+    } else {
+        format!("{}: {}\n", group.name, group.description)
+    };
+
+    for command in group.commands.iter().filter(|c| !c.options.hidden) {
+        push_line(
+            &mut text,
+            &group.usage(command.options),
+            command.options.description,
+        );
+    }
+    text
+}
+
+fn render_command(group: Option<&CommandGroup>, options: &CommandOptions) -> String {
+    let mut text = group.map_or_else(|| options.usage(), |g| g.usage(options));
+    if !options.description.is_empty() {
+        text.push('\n');
+        text.push_str(options.description);
+    }
+
+    if !options.aliases.is_empty() {
+        let aliases: Vec<String> = options.aliases.iter().map(|a| format!("/{a}")).collect();
+        let _ = write!(text, "\nAliases: {}", aliases.join(", "));
+    }
+    if let Some(group) = group {
+        let _ = write!(text, "\nCategory: {}", group.name);
+    }
+    if let Some(cooldown) = options.cooldown {
+        let bucket = match cooldown.bucket {
+            CooldownBucket::User => "per user",
+            CooldownBucket::Chat => "per chat",
+            CooldownBucket::Global => "global",
+        };
+        let _ = write!(
+            text,
+            "\nCooldown: {} {}",
+            format_remaining(cooldown.duration),
+            bucket
+        );
+    }
+    text
+}
+
+fn push_line(text: &mut String, usage: &str, description: &str) {
+    text.push_str(usage);
+    if !description.is_empty() {
+        text.push_str(" - ");
+        text.push_str(description);
+    }
+    text.push('\n');
+}
@@ -1,12 +1,30 @@
 //! The framework provides a customizable way to manage your bots commands
 
+mod checks;
+mod cooldown;
 pub(crate) mod framework;
+mod help;
+mod triggers;
 
 // made public for the procedural macros to use
 #[doc(hidden)]
+pub mod args;
+#[doc(hidden)]
 pub mod handlers;
 #[doc(hidden)]
 pub mod types;
 
+pub use args::{ArgError, Args, FromCommandArg, Rest};
+pub use checks::{
+    AdminOnly, ChatPermission, Check, CheckError, CheckResult, GroupOnly, HasPermission, OwnerOnly,
+    PrivateOnly, Whitelist,
+};
+pub use cooldown::{format_remaining, Cooldown, CooldownBucket};
 pub use framework::Framework;
-pub use types::{CommandError, CommandResult};
+pub use help::{render_help, HELP_COMMAND_OPTIONS};
+pub use triggers::CommandTriggers;
+pub use types::{CommandError, CommandGroup, CommandResult};
+
+/// Re-exported for implementing [`Check`] and other async traits of the
+/// library
+pub use async_trait::async_trait;
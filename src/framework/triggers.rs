@@ -0,0 +1,131 @@
+use crate::{
+    api::types::UpdateType,
+    model::{Message, MessageContent, MessageEntity, Update, UpdateContent},
+};
+use parking_lot::{const_mutex, Mutex};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// How a message invoked a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Invocation {
+    /// Using a telegram bot command, like `/command`
+    Slash,
+    /// Using one of the configured prefixes, like `!command`
+    Prefix(String),
+    /// By mentioning the bot, like `@bot command`
+    Mention,
+}
+
+static PATTERNS: Mutex<BTreeMap<&'static str, Regex>> = const_mutex(BTreeMap::new());
+
+/// Gets the compiled regex of the pattern of a command, compiling it only the
+/// first time it is used.
+///
+/// # Panics
+///
+/// Panics when the pattern is not a valid regex
+pub(crate) fn get_pattern(pattern: &'static str) -> Regex {
+    PATTERNS
+        .lock()
+        .entry(pattern)
+        .or_insert_with(|| {
+            Regex::new(pattern)
+                .unwrap_or_else(|e| panic!("invalid command pattern {}: {}", pattern, e))
+        })
+        .clone()
+}
+
+/// Configures which updates and which parts of their messages can trigger the
+/// commands of the [`Framework`], set using [`Framework::set_triggers`].
+///
+/// By default only new messages starting with a command trigger it, and
+/// command names are matched case-sensitively.
+///
+/// [`Framework`]: struct.Framework.html
+/// [`Framework::set_triggers`]: struct.Framework.html#method.set_triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandTriggers {
+    /// Whether new messages trigger commands
+    pub messages: bool,
+    /// Whether edited messages trigger commands
+    pub edited_messages: bool,
+    /// Whether new channel posts trigger commands
+    pub channel_posts: bool,
+    /// Whether edited channel posts trigger commands
+    pub edited_channel_posts: bool,
+    /// Whether commands in the captions of media trigger commands
+    pub captions: bool,
+    /// Whether a command anywhere in the text triggers, instead of only one at
+    /// the start of it
+    pub anywhere: bool,
+    /// Whether command names are matched case-insensitively, so `/Help`
+    /// triggers the `help` command
+    pub case_insensitive: bool,
+}
+
+impl Default for CommandTriggers {
+    fn default() -> Self {
+        Self {
+            messages: true,
+            edited_messages: false,
+            channel_posts: false,
+            edited_channel_posts: false,
+            captions: false,
+            anywhere: false,
+            case_insensitive: false,
+        }
+    }
+}
+
+impl CommandTriggers {
+    /// The update types that have to be received for the triggers to work
+    pub fn update_types(&self) -> Vec<UpdateType> {
+        let mut types = Vec::new();
+        if self.messages {
+            types.push(UpdateType::Message);
+        }
+        if self.edited_messages {
+            types.push(UpdateType::EditedMessage);
+        }
+        if self.channel_posts {
+            types.push(UpdateType::ChannelPost);
+        }
+        if self.edited_channel_posts {
+            types.push(UpdateType::EditedChannelPost);
+        }
+        types
+    }
+
+    /// Gets the message in the update that can trigger commands, if any
+    pub fn get_message(&self, update: Update) -> Option<Message> {
+        match update.content {
+            UpdateContent::Message(m) if self.messages => Some(m),
+            UpdateContent::EditedMessage(m) if self.edited_messages => Some(m),
+            UpdateContent::ChannelPost(m) if self.channel_posts => Some(m),
+            UpdateContent::EditedChannelPost(m) if self.edited_channel_posts => Some(m),
+            _ => None,
+        }
+    }
+
+    /// Gets the text or caption of the message that can trigger commands,
+    /// along with its entities
+    pub fn get_text<'a>(&self, message: &'a Message) -> Option<(&'a str, &'a [MessageEntity])> {
+        if !self.captions && !matches!(message.content, MessageContent::Text { .. }) {
+            return None;
+        }
+        message.get_text_with_entities()
+    }
+
+    /// Gets the text of the command triggered by the message, including the
+    /// `/` and the bot name if they were given
+    pub fn get_command(&self, message: &Message) -> Option<String> {
+        let (text, entities) = self.get_text(message)?;
+        entities.iter().find_map(|entity| match entity {
+            MessageEntity::BotCommand(t) if self.anywhere || t.offset == 0 => {
+                Some(t.get_text(text))
+            },
+            _ => None,
+        })
+    }
+}
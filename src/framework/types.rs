@@ -1,5 +1,7 @@
-use super::handlers::CommandHandlerFunc;
-use crate::{model::BotCommand, utils::result::Error};
+use super::{
+    args::CommandArgument, checks::Check, cooldown::Cooldown, handlers::CommandHandlerFunc,
+};
+use crate::{api::types::BotCommandScope, model::BotCommand, utils::result::Error};
 
 #[derive(Clone)]
 pub enum CommandTypes {
@@ -10,6 +12,36 @@ pub enum CommandTypes {
 pub struct CommandOptions {
     pub name: &'static str,
     pub description: &'static str,
+    pub arguments: &'static [CommandArgument],
+    pub aliases: &'static [&'static str],
+    pub prefixes: &'static [&'static str],
+    pub mention: bool,
+    pub pattern: Option<&'static str>,
+    pub hidden: bool,
+    pub scopes: &'static [BotCommandScope],
+    pub language_code: Option<&'static str>,
+    pub checks: &'static [&'static dyn Check],
+    pub denial_reply: Option<&'static str>,
+    pub cooldown: Option<Cooldown>,
+    pub cooldown_reply: Option<&'static str>,
+}
+
+impl CommandOptions {
+    /// Gets how the command is used, including its typed arguments
+    pub fn usage(&self) -> String {
+        let mut usage = format!("/{}", self.name);
+        for arg in self.arguments {
+            usage.push(' ');
+            usage.push_str(&arg.get_usage());
+        }
+        usage
+    }
+
+    /// Returns true if the given name is the name or one of the aliases of the
+    /// command
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
 }
 
 #[derive(Clone)]
@@ -27,6 +59,47 @@ impl TelegramCommand {
     }
 }
 
+/// A group of commands sharing checks and a category in the help, created
+/// using the `#[group]` macro.
+///
+/// When the group has a prefix, its commands are used as subcommands of it,
+/// like `/config set key value`, and only the prefix is registered in
+/// telegram.
+#[derive(Clone)]
+pub struct CommandGroup {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub prefix: Option<&'static str>,
+    pub checks: &'static [&'static dyn Check],
+    pub commands: &'static [&'static TelegramCommand],
+}
+
+impl CommandGroup {
+    /// Gets the command of the group with the given name or alias
+    pub fn get_command(&self, name: &str) -> Option<&'static TelegramCommand> {
+        self.commands
+            .iter()
+            .copied()
+            .find(|c| c.options.matches_name(name))
+    }
+
+    /// Gets how the given command of the group is used, including the prefix
+    /// of the group and the typed arguments of the command
+    pub fn usage(&self, options: &CommandOptions) -> String {
+        match self.prefix {
+            Some(prefix) => format!("/{} {}", prefix, &options.usage()[1..]),
+            None => options.usage(),
+        }
+    }
+
+    pub fn get_bot_command(&self) -> Option<BotCommand> {
+        self.prefix.map(|prefix| BotCommand {
+            command: prefix.to_owned(),
+            description: self.description.to_owned(),
+        })
+    }
+}
+
 /// The error to be returned from a command.
 ///
 /// It can be formed from anything implementing [`std::fmt::Display`], but won't
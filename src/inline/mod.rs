@@ -0,0 +1,18 @@
+//! Routing of inline queries, sent when a user types `@yourbot` followed by a
+//! query in any chat, to handlers returning the results to show.
+//!
+//! Handlers are added to an [`InlineRouter`] for queries starting with a
+//! prefix or matching a regex, and return an iterator of results. The router
+//! answers the query with a page of these results at a time, Telegram asks for
+//! the next page when the user scrolls down using the `next_offset` of the
+//! answer. The router is set on the client using
+//! [`ClientBuilder::set_inline_router`].
+//!
+//! [`ClientBuilder::set_inline_router`]: ../client/struct.ClientBuilder.html#method.set_inline_router
+
+mod router;
+
+pub use router::{
+    inline_results, InlineHandlerFunc, InlineOutcome, InlineRequest, InlineResults, InlineRoute,
+    InlineRouter, MAX_INLINE_RESULTS,
+};
@@ -0,0 +1,467 @@
+use crate::{
+    api::types::{AnswerInlineQuery, InlineQueryResult},
+    client::Context,
+    model::InlineQuery,
+    utils::result::TelegramError,
+    Result,
+};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The maximum amount of results Telegram accepts in a single answer to an
+/// inline query
+pub const MAX_INLINE_RESULTS: usize = 50;
+
+/// The results returned by an [`InlineHandlerFunc`], see [`inline_results`]
+/// for creating them from a collection
+pub type InlineResults = Box<dyn Iterator<Item = InlineQueryResult> + Send>;
+
+/// The future returned by an [`InlineHandlerFunc`]
+pub type InlineOutcome = Pin<Box<dyn Future<Output = Result<InlineResults>> + Send>>;
+
+/// A function handling inline queries, it receives a [`Context`] and the
+/// [`InlineRequest`] and returns a pinned future resolving to the results.
+/// Wrap an async function with `#[prepare_listener]` for easier development.
+pub type InlineHandlerFunc = fn(Context, InlineRequest) -> InlineOutcome;
+
+/// Creates [`InlineResults`] from a collection of results, like a `Vec`
+pub fn inline_results<I>(results: I) -> InlineResults
+where
+    I: IntoIterator<Item = InlineQueryResult>,
+    I::IntoIter: Send + 'static,
+{
+    Box::new(results.into_iter())
+}
+
+/// An inline query received by a handler of the [`InlineRouter`]
+///
+/// [`InlineRouter`]: struct.InlineRouter.html
+#[derive(Debug, Clone)]
+pub struct InlineRequest {
+    /// The inline query that was received
+    pub query: InlineQuery,
+    /// The text of the query after the prefix of the route, or the whole text
+    /// of the query for a route with a pattern
+    pub text: String,
+    /// The capture groups of the pattern of the route, a group that did not
+    /// participate in the match is an empty string
+    pub captures: Vec<String>,
+}
+
+enum Matcher {
+    Prefix(String),
+    Pattern(Regex),
+}
+
+/// A handler of the [`InlineRouter`] together with the queries it handles and
+/// the way its answers are cached.
+///
+/// [`InlineRouter`]: struct.InlineRouter.html
+pub struct InlineRoute {
+    matcher: Matcher,
+    handler: InlineHandlerFunc,
+    cache_time: Option<i64>,
+    is_personal: Option<bool>,
+}
+
+impl InlineRoute {
+    /// Creates a route for the queries starting with the prefix as a separate
+    /// word, an empty prefix matches every query
+    pub fn prefix(prefix: &str, handler: InlineHandlerFunc) -> Self {
+        Self::new(Matcher::Prefix(prefix.to_owned()), handler)
+    }
+
+    /// Creates a route for the queries matching the regex
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is not a valid regex
+    pub fn pattern(pattern: &str, handler: InlineHandlerFunc) -> Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("invalid inline query pattern {:?}: {}", pattern, e));
+        Self::new(Matcher::Pattern(regex), handler)
+    }
+
+    fn new(matcher: Matcher, handler: InlineHandlerFunc) -> Self {
+        Self {
+            matcher,
+            handler,
+            cache_time: None,
+            is_personal: None,
+        }
+    }
+
+    /// Sets the amount of seconds the answers of the route may be cached,
+    /// overriding the default of the router
+    pub fn set_cache_time(&mut self, cache_time: i64) -> &mut Self {
+        self.cache_time = Some(cache_time);
+        self
+    }
+
+    /// Sets whether the answers of the route may only be cached for the user
+    /// that sent the query, overriding the default of the router
+    pub fn set_personal(&mut self, is_personal: bool) -> &mut Self {
+        self.is_personal = Some(is_personal);
+        self
+    }
+
+    fn matches(&self, query: &InlineQuery) -> Option<InlineRequest> {
+        let (text, captures) = match &self.matcher {
+            Matcher::Prefix(prefix) => {
+                let rest = query.query.strip_prefix(prefix.as_str())?;
+                if !prefix.is_empty() && !rest.is_empty() && !rest.starts_with(char::is_whitespace)
+                {
+                    return None;
+                }
+                (rest.trim_start().to_owned(), Vec::new())
+            },
+            Matcher::Pattern(regex) => {
+                let captures = regex.captures(&query.query)?;
+                (
+                    query.query.clone(),
+                    captures
+                        .iter()
+                        .skip(1)
+                        .map(|c| c.map_or_else(String::new, |c| c.as_str().to_owned()))
+                        .collect(),
+                )
+            },
+        };
+
+        Some(InlineRequest {
+            query: query.clone(),
+            text,
+            captures,
+        })
+    }
+}
+
+// identifies the results of a query: the index of the route, the text of the
+// query and the user that sent it, if the answers of the route are personal
+type CacheKey = (usize, String, Option<i64>);
+
+struct CachedResults {
+    expires: Instant,
+    results: Arc<Vec<InlineQueryResult>>,
+}
+
+/// Dispatches inline queries to handlers based on their text, and answers them
+/// with the results of the handler a page at a time.
+///
+/// The first route added that matches the text of a query handles it. The
+/// results of its handler are kept for the cache time of the route, so the
+/// handler is not called again when Telegram asks for the next page of them.
+///
+/// While a user is typing, Telegram sends a query for almost every key they
+/// press. When a debounce delay is set using [`set_debounce`], the router
+/// waits that long before calling a handler, and drops the query if the user
+/// sent a new one in the meantime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use telexide_fork::{
+///     api::types::{
+///         InlineQueryResult, InlineQueryResultArticle, InputMessageContent,
+///         InputTextMessageContent,
+///     },
+///     inline::{inline_results, InlineRequest, InlineResults, InlineRouter},
+///     prelude::*,
+/// };
+///
+/// #[prepare_listener]
+/// async fn shout(_ctx: Context, request: InlineRequest) -> telexide_fork::Result<InlineResults> {
+///     let text = request.text.to_uppercase();
+///     Ok(inline_results(vec![InlineQueryResult::Article(
+///         InlineQueryResultArticle {
+///             id: "shout".to_owned(),
+///             title: text.clone(),
+///             input_message_content: InputMessageContent::Text(InputTextMessageContent {
+///                 message_text: text,
+///                 parse_mode: None,
+///                 disable_web_page_preview: false,
+///             }),
+///             reply_markup: None,
+///             url: None,
+///             hide_url: false,
+///             description: None,
+///             thumb_url: None,
+///             thumb_width: None,
+///             thumb_height: None,
+///         },
+///     )]))
+/// }
+///
+/// let mut router = InlineRouter::new();
+/// router
+///     .add_prefix_handler("shout", shout)
+///     .set_debounce(std::time::Duration::from_millis(300));
+///
+/// let client = ClientBuilder::new()
+///     .set_token("token")
+///     .set_inline_router(std::sync::Arc::new(router))
+///     .build();
+/// ```
+///
+/// [`set_debounce`]: #method.set_debounce
+pub struct InlineRouter {
+    routes: Vec<InlineRoute>,
+    cache_time: i64,
+    is_personal: bool,
+    page_size: usize,
+    max_results: usize,
+    debounce: Duration,
+    latest: Mutex<HashMap<i64, String>>,
+    cache: Mutex<HashMap<CacheKey, CachedResults>>,
+}
+
+impl InlineRouter {
+    /// Creates a router without any routes, which answers with pages of 50
+    /// results that may be cached for 300 seconds for all users, without
+    /// debouncing queries
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            cache_time: 300,
+            is_personal: false,
+            page_size: MAX_INLINE_RESULTS,
+            max_results: 1000,
+            debounce: Duration::ZERO,
+            latest: Mutex::new(HashMap::new()),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Adds a route, routes are tried in the order they were added
+    pub fn add_route(&mut self, route: InlineRoute) -> &mut Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Adds a handler for the queries starting with the prefix as a separate
+    /// word, it receives the text after the prefix
+    pub fn add_prefix_handler(&mut self, prefix: &str, handler: InlineHandlerFunc) -> &mut Self {
+        self.add_route(InlineRoute::prefix(prefix, handler))
+    }
+
+    /// Adds a handler for the queries matching the regex, it receives the
+    /// capture groups of the pattern
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is not a valid regex
+    pub fn add_pattern_handler(&mut self, pattern: &str, handler: InlineHandlerFunc) -> &mut Self {
+        self.add_route(InlineRoute::pattern(pattern, handler))
+    }
+
+    /// Sets the amount of seconds answers may be cached by Telegram and the
+    /// router, 300 by default
+    pub fn set_cache_time(&mut self, cache_time: i64) -> &mut Self {
+        self.cache_time = cache_time;
+        self
+    }
+
+    /// Sets whether answers may only be cached for the user that sent the
+    /// query, false by default
+    pub fn set_personal(&mut self, is_personal: bool) -> &mut Self {
+        self.is_personal = is_personal;
+        self
+    }
+
+    /// Sets the amount of results in every answer, at most
+    /// [`MAX_INLINE_RESULTS`]
+    ///
+    /// [`MAX_INLINE_RESULTS`]: constant.MAX_INLINE_RESULTS.html
+    pub fn set_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.page_size = page_size.clamp(1, MAX_INLINE_RESULTS);
+        self
+    }
+
+    /// Sets the amount of results taken from a handler, results after it are
+    /// never shown. 1000 by default.
+    pub fn set_max_results(&mut self, max_results: usize) -> &mut Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Sets how long to wait for a newer query of the same user before
+    /// calling the handler, the default of zero disables debouncing
+    pub fn set_debounce(&mut self, debounce: Duration) -> &mut Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// get the amount of seconds answers may be cached
+    pub fn get_cache_time(&self) -> i64 {
+        self.cache_time
+    }
+
+    /// get whether answers may only be cached for the user that sent the
+    /// query
+    pub fn is_personal(&self) -> bool {
+        self.is_personal
+    }
+
+    /// get the amount of results in every answer
+    pub fn get_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// get how long to wait for a newer query of the same user
+    pub fn get_debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Answers the query with the page of results at its offset from the
+    /// first route matching it, returns false if there is none
+    pub async fn handle_query(&self, context: Context, query: InlineQuery) -> bool {
+        let Some((index, route, request)) = self.find_route(&query) else {
+            return false;
+        };
+
+        match self
+            .answer_route(context.clone(), index, route, request)
+            .await
+        {
+            Ok(Some(answer)) => {
+                if let Err(e) = context.api.answer_inline_query(answer).await {
+                    debug!("failed to send the answer to an inline query: {e}");
+                }
+            },
+            Ok(None) => debug!("dropping superseded inline query: {}", query.query),
+            Err(e) => warn!("failed to answer inline query: {e}"),
+        }
+        true
+    }
+
+    /// get the answer to the query with the page of results at its offset
+    /// from the first route matching it, without sending it. Returns `None`
+    /// if no route matches the query or the user sent a newer query while it
+    /// was being debounced.
+    pub async fn get_answer(
+        &self,
+        context: Context,
+        query: &InlineQuery,
+    ) -> Result<Option<AnswerInlineQuery>> {
+        match self.find_route(query) {
+            Some((index, route, request)) => {
+                self.answer_route(context, index, route, request).await
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn find_route(&self, query: &InlineQuery) -> Option<(usize, &InlineRoute, InlineRequest)> {
+        self.routes
+            .iter()
+            .enumerate()
+            .find_map(|(i, r)| r.matches(query).map(|request| (i, r, request)))
+    }
+
+    async fn answer_route(
+        &self,
+        context: Context,
+        index: usize,
+        route: &InlineRoute,
+        request: InlineRequest,
+    ) -> Result<Option<AnswerInlineQuery>> {
+        let query = &request.query;
+        let offset = if query.offset.is_empty() {
+            0
+        } else {
+            query.offset.parse().map_err(|_| {
+                TelegramError::InvalidArgument(format!(
+                    "invalid inline query offset: {}",
+                    query.offset
+                ))
+            })?
+        };
+
+        // only the first page is requested while typing
+        if offset == 0 && !self.debounce.is_zero() && !self.debounce_query(query).await {
+            return Ok(None);
+        }
+
+        let cache_time = route.cache_time.unwrap_or(self.cache_time);
+        let is_personal = route.is_personal.unwrap_or(self.is_personal);
+        let key = (
+            index,
+            query.query.clone(),
+            is_personal.then_some(query.from.id),
+        );
+        let inline_query_id = query.id.clone();
+
+        let results = if let Some(results) = self.get_cached(&key) {
+            results
+        } else {
+            let results = (route.handler)(context, request).await?;
+            let results = Arc::new(results.take(self.max_results).collect::<Vec<_>>());
+            self.set_cached(key, results.clone(), cache_time);
+            results
+        };
+
+        let start = offset.min(results.len());
+        let end = (start + self.page_size).min(results.len());
+        Ok(Some(AnswerInlineQuery {
+            inline_query_id,
+            results: results[start..end].to_vec(),
+            cache_time: Some(cache_time),
+            is_personal,
+            next_offset: (end < results.len()).then(|| end.to_string()),
+            switch_pm_text: None,
+            switch_pm_parameter: None,
+        }))
+    }
+
+    // waits for the debounce delay, returns false if the user sent a newer
+    // query in the meantime
+    async fn debounce_query(&self, query: &InlineQuery) -> bool {
+        self.latest.lock().insert(query.from.id, query.id.clone());
+        tokio::time::sleep(self.debounce).await;
+
+        let mut latest = self.latest.lock();
+        if latest.get(&query.from.id) != Some(&query.id) {
+            return false;
+        }
+        latest.remove(&query.from.id);
+        true
+    }
+
+    fn get_cached(&self, key: &CacheKey) -> Option<Arc<Vec<InlineQueryResult>>> {
+        self.cache
+            .lock()
+            .get(key)
+            .filter(|c| c.expires > Instant::now())
+            .map(|c| c.results.clone())
+    }
+
+    fn set_cached(&self, key: CacheKey, results: Arc<Vec<InlineQueryResult>>, cache_time: i64) {
+        let Ok(max_age) = u64::try_from(cache_time).map(Duration::from_secs) else {
+            return;
+        };
+        if max_age.is_zero() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut cache = self.cache.lock();
+        cache.retain(|_, c| c.expires > now);
+        cache.insert(
+            key,
+            CachedResults {
+                expires: now + max_age,
+                results,
+            },
+        );
+    }
+}
@@ -19,6 +19,22 @@
 //! sent by users of your bot. For further information about the framework,
 //! please see the [framework's module-level documentation][framework].
 //!
+//! Multi-step conversations with users, like registration forms, can be
+//! declared as a [`DialogueFlow`] of states. For further information about
+//! dialogues, please see the [dialogue module-level documentation][dialogue].
+//!
+//! Presses on the buttons of inline keyboards can be handled by typed
+//! handlers using a [`CallbackRouter`]. For further information, please see
+//! the [callback module-level documentation][callback].
+//!
+//! Inline queries can be answered with pages of results by handlers added to
+//! an [`InlineRouter`]. For further information, please see the
+//! [inline module-level documentation][inline].
+//!
+//! The init data sent by Web Apps to their server can be validated using an
+//! [`InitDataValidator`]. For further information, please see the
+//! [web app module-level documentation][web_app].
+//!
 //! Telegram also has their own [API docs for bots][tg docs]. Although this
 //! documentation will try to be as accurate as possible, if you need to be
 //! sure, refer to their docs.
@@ -51,6 +67,14 @@
 //! [tg_commands]: https://core.telegram.org/bots#commands
 //! [`Framework`]: framework/struct.Framework.html
 //! [framework]: framework/index.html
+//! [`DialogueFlow`]: dialogue/struct.DialogueFlow.html
+//! [dialogue]: dialogue/index.html
+//! [`CallbackRouter`]: callback/struct.CallbackRouter.html
+//! [callback]: callback/index.html
+//! [`InlineRouter`]: inline/struct.InlineRouter.html
+//! [inline]: inline/index.html
+//! [`InitDataValidator`]: web_app/struct.InitDataValidator.html
+//! [web_app]: web_app/index.html
 
 #![warn(clippy::pedantic)]
 #![allow(
@@ -64,15 +88,20 @@
 )]
 
 pub mod api;
+pub mod callback;
 pub mod client;
+pub mod dialogue;
 pub mod framework;
+pub mod inline;
 pub mod model;
+pub mod storage;
 mod utils;
+pub mod web_app;
 
 /// Macros for using the framework and helping with adding listeners
 pub mod macros {
     pub use super::create_framework;
-    pub use telexide_fork_proc_macros::{command, prepare_listener};
+    pub use telexide_fork_proc_macros::{command, group, prepare_listener};
 }
 
 pub use client::Client;
@@ -103,7 +132,7 @@ pub mod prelude {
         model::{Message, Update},
         Error as TelexideError,
     };
-    pub use telexide_fork_proc_macros::{command, prepare_listener};
+    pub use telexide_fork_proc_macros::{command, group, prepare_listener};
 }
 
 #[doc(hidden)]
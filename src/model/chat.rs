@@ -103,6 +103,9 @@ pub struct SuperGroupChat {
     ///
     /// [`get_chat`]: ../../api/trait.API.html#method.get_chat
     pub location: Option<ChatLocation>,
+    /// True, if the supergroup chat is a forum (has topics enabled)
+    #[serde(default)]
+    pub is_forum: bool,
 }
 
 /// A Channel object
@@ -150,6 +153,19 @@ pub enum Chat {
     Channel(ChannelChat),
 }
 
+/// This object represents a forum topic.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForumTopic {
+    /// Unique identifier of the forum topic
+    pub message_thread_id: i64,
+    /// Name of the topic
+    pub name: String,
+    /// Color of the topic icon in RGB format
+    pub icon_color: i64,
+    /// Unique identifier of the custom emoji shown as the topic icon
+    pub icon_custom_emoji_id: Option<String>,
+}
+
 /// Represents a location to which a chat is connected.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ChatLocation {
@@ -275,6 +291,7 @@ impl From<RawChat> for Chat {
                 slow_mode_delay: raw.slow_mode_delay,
                 linked_chat_id: raw.linked_chat_id,
                 location: raw.location,
+                is_forum: raw.is_forum,
             }),
             ChatType::Sender => unreachable!(),
         }
@@ -302,6 +319,7 @@ impl From<Chat> for RawChat {
                 slow_mode_delay: None,
                 linked_chat_id: None,
                 location: None,
+                is_forum: false,
             },
             Chat::Group(c) => RawChat {
                 chat_type: ChatType::Group,
@@ -321,6 +339,7 @@ impl From<Chat> for RawChat {
                 bio: None,
                 linked_chat_id: None,
                 location: None,
+                is_forum: false,
             },
             Chat::SuperGroup(c) => RawChat {
                 chat_type: ChatType::SuperGroup,
@@ -337,6 +356,7 @@ impl From<Chat> for RawChat {
                 slow_mode_delay: c.slow_mode_delay,
                 linked_chat_id: c.linked_chat_id,
                 location: c.location,
+                is_forum: c.is_forum,
                 bio: None,
                 first_name: None,
                 last_name: None,
@@ -359,6 +379,7 @@ impl From<Chat> for RawChat {
                 last_name: None,
                 bio: None,
                 location: None,
+                is_forum: false,
             },
         }
     }
@@ -451,6 +472,67 @@ pub struct AdministratorMemberStatus {
     pub custom_title: Option<String>,
 }
 
+/// Represents the rights of an administrator in a chat.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ChatAdministratorRights {
+    /// True, if the user's presence in the chat is hidden
+    #[serde(default)]
+    pub is_anonymous: bool,
+    /// True, if the administrator can access the chat event log, get boost
+    /// list, see hidden supergroup and channel members, report spam messages
+    /// and ignore slow mode. Implied by any other administrator privilege.
+    #[serde(default)]
+    pub can_manage_chat: bool,
+    /// True, if the administrator can delete messages of other users
+    #[serde(default)]
+    pub can_delete_messages: bool,
+    /// True, if the administrator can manage video chats
+    #[serde(default)]
+    pub can_manage_video_chats: bool,
+    /// True, if the administrator can restrict, ban or unban chat members, or
+    /// access supergroup statistics
+    #[serde(default)]
+    pub can_restrict_members: bool,
+    /// True, if the administrator can add new administrators with a subset of
+    /// their own privileges or demote administrators that they have promoted,
+    /// directly or indirectly (promoted by administrators that were appointed
+    /// by the user)
+    #[serde(default)]
+    pub can_promote_members: bool,
+    /// True, if the user is allowed to change the chat title, photo and other
+    /// settings
+    #[serde(default)]
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat
+    #[serde(default)]
+    pub can_invite_users: bool,
+    /// True, if the administrator can post stories to the chat
+    #[serde(default)]
+    pub can_post_stories: bool,
+    /// True, if the administrator can edit stories posted by other users
+    #[serde(default)]
+    pub can_edit_stories: bool,
+    /// True, if the administrator can delete stories posted by other users
+    #[serde(default)]
+    pub can_delete_stories: bool,
+    /// True, if the administrator can post messages in the channel, or access
+    /// channel statistics; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// True, if the administrator can edit messages of other users and can pin
+    /// messages; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// True, if the user is allowed to pin messages; groups and supergroups
+    /// only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// True, if the user is allowed to create, rename, close, and reopen forum
+    /// topics; supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
 /// Represents a [`ChatMember`] who is a normal member of the [`Chat`] without
 /// any special powers.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -545,12 +627,42 @@ pub struct ChatInviteLink {
     /// If the link is revoked
     pub is_revoked: bool,
     /// When the link will expire or has been expired
+    #[serde(default)]
     #[serde(with = "unix_date_formatting::optional")]
     pub expire_date: Option<DateTime<Utc>>,
     /// Maximum number of users that can be members of the chat simultaneously
     /// after joining the chat via this invite link; 1-99999
     #[serde(default)]
     pub member_limit: Option<i32>,
+    /// Invite link name
+    pub name: Option<String>,
+    /// True, if users joining the chat via the link need to be approved by
+    /// chat administrators
+    #[serde(default)]
+    pub creates_join_request: bool,
+    /// Number of pending join requests created using this link
+    pub pending_join_request_count: Option<i64>,
+}
+
+/// Represents a join request sent to a chat.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+    /// User that sent the join request
+    pub from: User,
+    /// Identifier of a private chat with the user who sent the join request.
+    /// The bot can use this identifier for 5 minutes to send messages until
+    /// the join request is processed, assuming no other administrator
+    /// contacted the user.
+    pub user_chat_id: i64,
+    /// Date the request was sent
+    #[serde(with = "unix_date_formatting")]
+    pub date: DateTime<Utc>,
+    /// Bio of the user
+    pub bio: Option<String>,
+    /// Chat invite link that was used by the user to send the join request
+    pub invite_link: Option<ChatInviteLink>,
 }
 
 /// Represents changes in the status of a chat member.
@@ -0,0 +1,424 @@
+use super::{InlineCustomEmoji, MessageEntity, Pre, TextBlock, TextLink, TextMention, User};
+
+/// A formatted text, built up from plain and styled parts, which can be turned
+/// into a `MarkdownV2` or HTML string with everything escaped, or into the plain
+/// text along with its [`MessageEntity`]s.
+///
+/// Styled parts can be nested by passing another `Text` instead of a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use telexide_fork::model::Text;
+///
+/// let text = Text::new()
+///     .bold("Note:")
+///     .plain(" read the ")
+///     .link(Text::new().italic("docs"), "https://core.telegram.org/bots/api")
+///     .plain(" before using ")
+///     .code("answer_inline_query");
+///
+/// assert_eq!(
+///     text.to_markdown_v2(),
+///     "*Note:* read the [_docs_](https://core.telegram.org/bots/api) before using `answer_inline_query`"
+/// );
+/// assert_eq!(text.to_plain(), "Note: read the docs before using answer_inline_query");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Text {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Plain(String),
+    Styled(Style, Text),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Style {
+    Bold,
+    Italic,
+    Underline,
+    StrikeThrough,
+    Spoiler,
+    Blockquote,
+    Code,
+    Pre(Option<String>),
+    Link(String),
+    Mention(Box<User>),
+    CustomEmoji(String),
+}
+
+impl Text {
+    /// Creates an empty text
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends text without any formatting
+    #[must_use]
+    pub fn plain(mut self, text: &str) -> Self {
+        self.parts.push(Part::Plain(text.to_owned()));
+        self
+    }
+
+    /// Appends the parts of another text
+    #[must_use]
+    pub fn append(mut self, text: Text) -> Self {
+        self.parts.extend(text.parts);
+        self
+    }
+
+    /// Appends bold text
+    #[must_use]
+    pub fn bold<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::Bold, text.into())
+    }
+
+    /// Appends italic text
+    #[must_use]
+    pub fn italic<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::Italic, text.into())
+    }
+
+    /// Appends underlined text
+    #[must_use]
+    pub fn underline<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::Underline, text.into())
+    }
+
+    /// Appends strikethrough text
+    #[must_use]
+    pub fn strikethrough<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::StrikeThrough, text.into())
+    }
+
+    /// Appends text that is hidden until it is tapped
+    #[must_use]
+    pub fn spoiler<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::Spoiler, text.into())
+    }
+
+    /// Appends a quote. In `MarkdownV2` a quote lasts until the end of its
+    /// last line, so text after it has to start on a new line.
+    #[must_use]
+    pub fn blockquote<T: Into<Text>>(self, text: T) -> Self {
+        self.styled(Style::Blockquote, text.into())
+    }
+
+    /// Appends a monowidth code string, which can't contain other formatting
+    #[must_use]
+    pub fn code(self, code: &str) -> Self {
+        self.styled(Style::Code, Text::from(code))
+    }
+
+    /// Appends a monowidth code block, optionally highlighted as the given
+    /// programming language
+    #[must_use]
+    pub fn pre(self, code: &str, language: Option<&str>) -> Self {
+        self.styled(
+            Style::Pre(language.map(ToOwned::to_owned)),
+            Text::from(code),
+        )
+    }
+
+    /// Appends text that opens the url when it is tapped
+    #[must_use]
+    pub fn link<T: Into<Text>>(self, text: T, url: &str) -> Self {
+        self.styled(Style::Link(url.to_owned()), text.into())
+    }
+
+    /// Appends a mention of the user, which also works for users without a
+    /// username
+    #[must_use]
+    pub fn mention<T: Into<Text>>(self, text: T, user: &User) -> Self {
+        self.styled(Style::Mention(Box::new(user.clone())), text.into())
+    }
+
+    /// Appends a custom emoji, the text has to be a regular emoji which is
+    /// shown where custom emoji aren't supported
+    #[must_use]
+    pub fn custom_emoji(self, emoji: &str, custom_emoji_id: &str) -> Self {
+        self.styled(
+            Style::CustomEmoji(custom_emoji_id.to_owned()),
+            Text::from(emoji),
+        )
+    }
+
+    fn styled(mut self, style: Style, text: Text) -> Self {
+        self.parts.push(Part::Styled(style, text));
+        self
+    }
+
+    /// returns true if the text contains no characters
+    pub fn is_empty(&self) -> bool {
+        self.parts.iter().all(|part| match part {
+            Part::Plain(text) => text.is_empty(),
+            Part::Styled(_, text) => text.is_empty(),
+        })
+    }
+
+    /// Gets the text without any formatting
+    pub fn to_plain(&self) -> String {
+        let mut out = String::new();
+        self.write_plain(&mut out);
+        out
+    }
+
+    /// Gets the text without any formatting together with the entities
+    /// describing its formatting, with their offsets in UTF-16 code units
+    pub fn to_entities(&self) -> (String, Vec<MessageEntity>) {
+        let mut out = String::new();
+        let mut entities = Vec::new();
+        self.write_entities(&mut out, &mut 0, &mut entities);
+        (out, entities)
+    }
+
+    /// Gets the text formatted using `MarkdownV2`, to be sent with
+    /// [`ParseMode::MarkdownV2`]
+    ///
+    /// [`ParseMode::MarkdownV2`]: enum.ParseMode.html#variant.MarkdownV2
+    pub fn to_markdown_v2(&self) -> String {
+        let mut writer = MarkdownWriter::default();
+        self.write_markdown(&mut writer);
+        writer.out
+    }
+
+    /// Gets the text formatted using HTML, to be sent with
+    /// [`ParseMode::HTML`]
+    ///
+    /// [`ParseMode::HTML`]: enum.ParseMode.html#variant.HTML
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        self.write_html(&mut out);
+        out
+    }
+
+    fn write_plain(&self, out: &mut String) {
+        for part in &self.parts {
+            match part {
+                Part::Plain(text) => out.push_str(text),
+                Part::Styled(_, text) => text.write_plain(out),
+            }
+        }
+    }
+
+    fn write_entities(
+        &self,
+        out: &mut String,
+        offset: &mut usize,
+        entities: &mut Vec<MessageEntity>,
+    ) {
+        for part in &self.parts {
+            match part {
+                Part::Plain(text) => {
+                    out.push_str(text);
+                    *offset += text.encode_utf16().count();
+                },
+                Part::Styled(style, text) => {
+                    let start = *offset;
+                    let index = entities.len();
+                    text.write_entities(out, offset, entities);
+                    if *offset > start {
+                        let text_block = TextBlock {
+                            offset: start,
+                            length: *offset - start,
+                        };
+                        entities.insert(index, style.to_entity(text_block));
+                    }
+                },
+            }
+        }
+    }
+
+    fn write_markdown(&self, writer: &mut MarkdownWriter) {
+        for part in &self.parts {
+            match part {
+                Part::Plain(text) => writer.text(text, MARKDOWN_SPECIAL),
+                Part::Styled(style, text) => match style {
+                    Style::Bold => writer.wrap("*", "*", |w| text.write_markdown(w)),
+                    Style::Italic => writer.wrap("_", "_", |w| text.write_markdown(w)),
+                    Style::Underline => writer.wrap("__", "__", |w| text.write_markdown(w)),
+                    Style::StrikeThrough => writer.wrap("~", "~", |w| text.write_markdown(w)),
+                    Style::Spoiler => writer.wrap("||", "||", |w| text.write_markdown(w)),
+                    Style::Code => writer.wrap("`", "`", |w| w.text(&text.to_plain(), "`\\")),
+                    Style::Pre(language) => writer.wrap(
+                        &format!("```{}\n", language.as_deref().unwrap_or_default()),
+                        "\n```",
+                        |w| w.text(&text.to_plain(), "`\\"),
+                    ),
+                    Style::Link(url) => writer.link("[", text, url),
+                    Style::Mention(user) => {
+                        writer.link("[", text, &format!("tg://user?id={}", user.id));
+                    },
+                    Style::CustomEmoji(id) => {
+                        writer.link("![", text, &format!("tg://emoji?id={id}"));
+                    },
+                    Style::Blockquote => {
+                        let mut quote = MarkdownWriter::default();
+                        text.write_markdown(&mut quote);
+                        // a quote only starts at the start of a line
+                        writer.quote_end = false;
+                        if !writer.out.is_empty() && !writer.out.ends_with('\n') {
+                            writer.out.push('\n');
+                        }
+                        writer.marker(">");
+                        writer.out.push_str(&quote.out.replace('\n', "\n>"));
+                        writer.marker_end = quote.marker_end;
+                        writer.quote_end = !writer.out.ends_with('\n');
+                    },
+                },
+            }
+        }
+    }
+
+    fn write_html(&self, out: &mut String) {
+        for part in &self.parts {
+            match part {
+                Part::Plain(text) => out.push_str(&escape_html(text)),
+                Part::Styled(style, text) => {
+                    let (open, close) = match style {
+                        Style::Bold => ("<b>".to_owned(), "</b>"),
+                        Style::Italic => ("<i>".to_owned(), "</i>"),
+                        Style::Underline => ("<u>".to_owned(), "</u>"),
+                        Style::StrikeThrough => ("<s>".to_owned(), "</s>"),
+                        Style::Spoiler => ("<tg-spoiler>".to_owned(), "</tg-spoiler>"),
+                        Style::Blockquote => ("<blockquote>".to_owned(), "</blockquote>"),
+                        Style::Code => ("<code>".to_owned(), "</code>"),
+                        Style::Pre(None) => ("<pre>".to_owned(), "</pre>"),
+                        Style::Pre(Some(language)) => (
+                            format!("<pre><code class=\"language-{}\">", escape_html(language)),
+                            "</code></pre>",
+                        ),
+                        Style::Link(url) => (format!("<a href=\"{}\">", escape_html(url)), "</a>"),
+                        Style::Mention(user) => {
+                            (format!("<a href=\"tg://user?id={}\">", user.id), "</a>")
+                        },
+                        Style::CustomEmoji(id) => (
+                            format!("<tg-emoji emoji-id=\"{}\">", escape_html(id)),
+                            "</tg-emoji>",
+                        ),
+                    };
+                    out.push_str(&open);
+                    text.write_html(out);
+                    out.push_str(close);
+                },
+            }
+        }
+    }
+}
+
+impl Style {
+    fn to_entity(&self, text_block: TextBlock) -> MessageEntity {
+        match self {
+            Self::Bold => MessageEntity::Bold(text_block),
+            Self::Italic => MessageEntity::Italic(text_block),
+            Self::Underline => MessageEntity::Underline(text_block),
+            Self::StrikeThrough => MessageEntity::StrikeThrough(text_block),
+            Self::Spoiler => MessageEntity::Spoiler(text_block),
+            Self::Blockquote => MessageEntity::Blockquote(text_block),
+            Self::Code => MessageEntity::Code(text_block),
+            Self::Pre(language) => MessageEntity::Pre(Pre {
+                text_block,
+                language: language.clone(),
+            }),
+            Self::Link(url) => MessageEntity::TextLink(TextLink {
+                text_block,
+                url: url.clone(),
+            }),
+            Self::Mention(user) => MessageEntity::TextMention(TextMention {
+                text_block,
+                user: user.as_ref().clone(),
+            }),
+            Self::CustomEmoji(custom_emoji_id) => MessageEntity::CustomEmoji(InlineCustomEmoji {
+                text_block,
+                custom_emoji_id: custom_emoji_id.clone(),
+            }),
+        }
+    }
+}
+
+impl From<&str> for Text {
+    fn from(text: &str) -> Self {
+        Self::new().plain(text)
+    }
+}
+
+impl From<String> for Text {
+    fn from(text: String) -> Self {
+        Self {
+            parts: vec![Part::Plain(text)],
+        }
+    }
+}
+
+impl From<Text> for (String, Vec<MessageEntity>) {
+    fn from(text: Text) -> Self {
+        text.to_entities()
+    }
+}
+
+// the characters that have to be escaped in MarkdownV2 text
+const MARKDOWN_SPECIAL: &str = "_*[]()~`>#+-=|{}.!\\";
+
+#[derive(Default)]
+struct MarkdownWriter {
+    out: String,
+    // whether the output ends with a formatting marker, to separate an italic
+    // marker from an underline one following it
+    marker_end: bool,
+    // whether the output ends with a quote, which the output following it
+    // would continue on the same line
+    quote_end: bool,
+}
+
+impl MarkdownWriter {
+    fn text(&mut self, text: &str, special: &str) {
+        self.leave_quote(text);
+        for c in text.chars() {
+            if special.contains(c) {
+                self.out.push('\\');
+            }
+            self.out.push(c);
+        }
+        self.marker_end &= text.is_empty();
+    }
+
+    fn marker(&mut self, marker: &str) {
+        self.leave_quote(marker);
+        if self.marker_end && marker.starts_with('_') && self.out.ends_with('_') {
+            self.out.push('\r');
+        }
+        self.out.push_str(marker);
+        self.marker_end = true;
+    }
+
+    // starts a new line for the output following a quote
+    fn leave_quote(&mut self, next: &str) {
+        if !next.is_empty() && std::mem::take(&mut self.quote_end) && !next.starts_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn wrap<F: FnOnce(&mut Self)>(&mut self, open: &str, close: &str, inner: F) {
+        self.marker(open);
+        inner(self);
+        self.marker(close);
+    }
+
+    fn link(&mut self, open: &str, text: &Text, url: &str) {
+        self.marker(open);
+        text.write_markdown(self);
+        self.marker("](");
+        self.text(url, ")\\");
+        self.marker(")");
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,4 +1,4 @@
-use super::{CallbackGame, LoginUrl};
+use super::{CallbackGame, ChatAdministratorRights, LoginUrl, WebAppInfo};
 use serde::{Deserialize, Serialize};
 
 /// This object represents an [inline keyboard] that appears right next to the
@@ -71,6 +71,54 @@ pub struct InlineKeyboardButton {
     /// [Pay button]: https://core.telegram.org/bots/api#payments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pay: Option<bool>,
+    /// Description of the [Web App] that will be launched when the user
+    /// presses the button. The Web App will be able to send an arbitrary
+    /// message on behalf of the user using the method
+    /// [`answer_web_app_query`]. Available only in private chats between a
+    /// user and the bot.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    /// [`answer_web_app_query`]: ../api/trait.API.html#method.answer_web_app_query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+}
+
+impl InlineKeyboardButton {
+    /// Creates a button with the given text, sending a [callback query] with
+    /// the data to the bot when it is pressed
+    ///
+    /// [callback query]: ../model/struct.CallbackQuery.html
+    pub fn callback(text: &str, data: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            url: None,
+            login_url: None,
+            callback_data: Some(data.to_owned()),
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            callback_game: None,
+            pay: None,
+            web_app: None,
+        }
+    }
+
+    /// Creates a button with the given text, launching the [Web App] at the
+    /// given url when it is pressed
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    pub fn web_app(text: &str, url: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            url: None,
+            login_url: None,
+            callback_data: None,
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            callback_game: None,
+            pay: None,
+            web_app: Some(WebAppInfo::new(url)),
+        }
+    }
 }
 
 /// This object represents a custom keyboard with reply options
@@ -171,13 +219,24 @@ pub struct ForceReply {
 /// For simple text buttons String can be used instead of this object to specify
 /// text of the button.
 ///
-/// **Note:** Optional fields `request_contact`, `request_location`, and
-/// `request_poll` are mutually exclusive.
+/// **Note:** Optional fields `web_app`, `request_users`, `request_chat`,
+/// `request_contact`, `request_location`, and `request_poll` are mutually
+/// exclusive.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct KeyboardButton {
     /// Text of the button. If none of the optional fields are used,
     /// it will be sent as a message when the button is pressed
     pub text: String,
+    /// If specified, pressing the button will open a list of suitable users.
+    /// Identifiers of selected users will be sent to the bot in a
+    /// `users_shared` service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_users: Option<KeyboardButtonRequestUsers>,
+    /// If specified, pressing the button will open a list of suitable chats.
+    /// Tapping on a chat will send its identifier to the bot in a
+    /// `chat_shared` service message. Available in private chats only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_chat: Option<KeyboardButtonRequestChat>,
     /// If true, the user's phone number will be sent as a contact when the
     /// button is pressed. Available in private chats only
     pub request_contact: bool,
@@ -188,6 +247,179 @@ pub struct KeyboardButton {
     /// bot when the button is pressed. Available in private chats only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_poll: Option<KeyboardButtonPollType>,
+    /// If specified, the described [Web App] will be launched when the button
+    /// is pressed. The Web App will be able to send a `web_app_data` service
+    /// message. Available in private chats only.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+}
+
+impl KeyboardButton {
+    /// Creates a button with the given text, launching the [Web App] at the
+    /// given url when it is pressed
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    pub fn web_app(text: &str, url: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            request_users: None,
+            request_chat: None,
+            request_contact: false,
+            request_location: false,
+            request_poll: None,
+            web_app: Some(WebAppInfo::new(url)),
+        }
+    }
+
+    /// Creates a button with the given text, asking the user to select users
+    /// matching the given criteria when it is pressed
+    pub fn request_users(text: &str, request: KeyboardButtonRequestUsers) -> Self {
+        Self {
+            text: text.to_owned(),
+            request_users: Some(request),
+            request_chat: None,
+            request_contact: false,
+            request_location: false,
+            request_poll: None,
+            web_app: None,
+        }
+    }
+
+    /// Creates a button with the given text, asking the user to select a chat
+    /// matching the given criteria when it is pressed
+    pub fn request_chat(text: &str, request: KeyboardButtonRequestChat) -> Self {
+        Self {
+            text: text.to_owned(),
+            request_users: None,
+            request_chat: Some(request),
+            request_contact: false,
+            request_location: false,
+            request_poll: None,
+            web_app: None,
+        }
+    }
+}
+
+/// This object defines the criteria used to request suitable users. The
+/// identifiers of the selected users will be shared with the bot when the
+/// corresponding button is pressed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KeyboardButtonRequestUsers {
+    /// Signed 32-bit identifier of the request that will be received back in
+    /// the [`UsersShared`] object. Must be unique within the message
+    ///
+    /// [`UsersShared`]: ../model/struct.UsersShared.html
+    pub request_id: i64,
+    /// Pass True to request bots, pass False to request regular users. If not
+    /// specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_bot: Option<bool>,
+    /// Pass True to request premium users, pass False to request non-premium
+    /// users. If not specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_premium: Option<bool>,
+    /// The maximum number of users to be selected; 1-10. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<i64>,
+    /// Pass True to request the users' first and last names
+    #[serde(default)]
+    pub request_name: bool,
+    /// Pass True to request the users' usernames
+    #[serde(default)]
+    pub request_username: bool,
+    /// Pass True to request the users' photos
+    #[serde(default)]
+    pub request_photo: bool,
+}
+
+impl KeyboardButtonRequestUsers {
+    /// function to create a new `KeyboardButtonRequestUsers` object, setting
+    /// all optional fields to None
+    pub fn new(request_id: i64) -> Self {
+        Self {
+            request_id,
+            user_is_bot: None,
+            user_is_premium: None,
+            max_quantity: None,
+            request_name: false,
+            request_username: false,
+            request_photo: false,
+        }
+    }
+}
+
+/// This object defines the criteria used to request a suitable chat. The
+/// identifier of the selected chat will be shared with the bot when the
+/// corresponding button is pressed. The bot will be granted the requested
+/// rights in the chat if appropriate.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KeyboardButtonRequestChat {
+    /// Signed 32-bit identifier of the request, which will be received back in
+    /// the [`ChatShared`] object. Must be unique within the message
+    ///
+    /// [`ChatShared`]: ../model/struct.ChatShared.html
+    pub request_id: i64,
+    /// Pass True to request a channel chat, pass False to request a group or a
+    /// supergroup chat.
+    pub chat_is_channel: bool,
+    /// Pass True to request a forum supergroup, pass False to request a
+    /// non-forum chat. If not specified, no additional restrictions are
+    /// applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_forum: Option<bool>,
+    /// Pass True to request a supergroup or a channel with a username, pass
+    /// False to request a chat without a username. If not specified, no
+    /// additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_has_username: Option<bool>,
+    /// Pass True to request a chat owned by the user. Otherwise, no additional
+    /// restrictions are applied.
+    #[serde(default)]
+    pub chat_is_created: bool,
+    /// The required administrator rights of the user in the chat. If not
+    /// specified, no additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_administrator_rights: Option<ChatAdministratorRights>,
+    /// The required administrator rights of the bot in the chat. The rights
+    /// must be a subset of `user_administrator_rights`. If not specified, no
+    /// additional restrictions are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_administrator_rights: Option<ChatAdministratorRights>,
+    /// Pass True to request a chat with the bot as a member. Otherwise, no
+    /// additional restrictions are applied.
+    #[serde(default)]
+    pub bot_is_member: bool,
+    /// Pass True to request the chat's title
+    #[serde(default)]
+    pub request_title: bool,
+    /// Pass True to request the chat's username
+    #[serde(default)]
+    pub request_username: bool,
+    /// Pass True to request the chat's photo
+    #[serde(default)]
+    pub request_photo: bool,
+}
+
+impl KeyboardButtonRequestChat {
+    /// function to create a new `KeyboardButtonRequestChat` object, setting
+    /// all optional fields to None
+    pub fn new(request_id: i64, chat_is_channel: bool) -> Self {
+        Self {
+            request_id,
+            chat_is_channel,
+            chat_is_forum: None,
+            chat_has_username: None,
+            chat_is_created: false,
+            user_administrator_rights: None,
+            bot_administrator_rights: None,
+            bot_is_member: false,
+            request_title: false,
+            request_username: false,
+            request_photo: false,
+        }
+    }
 }
 
 /// This object represents type of a poll, which is allowed to be created and
@@ -2,8 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{
-    message_contents::*, message_entity::*, raw::*, Game, InlineKeyboardMarkup, Invoice,
-    PassportData, Sticker, SuccessfulPayment, User,
+    message_contents::*, message_entity::*, raw::*, ExternalReplyInfo, Game, InlineKeyboardMarkup,
+    Invoice, PaidMediaInfo, PassportData, Sticker, SuccessfulPayment, TextQuote, User, WebAppData,
 };
 
 /// This object represents a message.
@@ -18,24 +18,49 @@ pub struct Message {
     /// group administrators. The linked channel for messages automatically
     /// forwarded to the discussion group
     pub sender_chat: Option<super::Chat>,
+    /// The bot that actually sent the message on behalf of the business
+    /// account. Available only for outgoing messages sent on behalf of the
+    /// connected business account.
+    pub sender_business_bot: Option<User>,
     /// Date the message was sent
     pub date: DateTime<Utc>,
+    /// Unique identifier of the business connection from which the message
+    /// was received. If non-empty, the message belongs to a chat of the
+    /// corresponding business account that is independent from any potential
+    /// bot chat which might share the same identifier.
+    pub business_connection_id: Option<String>,
     /// Conversation the message belongs to
     pub chat: super::Chat,
+    /// Unique identifier of the forum topic the message belongs to, for
+    /// supergroups only
+    pub message_thread_id: Option<i64>,
+    /// True, if the message is sent to a forum topic
+    pub is_topic_message: bool,
 
     /// Data about what message it was forwarded from
     pub forward_data: Option<ForwardData>,
 
     pub reply_to_message: Option<Box<Message>>,
+    /// Information about the message that is being replied to, which may come
+    /// from another chat or forum topic
+    pub external_reply: Option<Box<ExternalReplyInfo>>,
+    /// For replies that quote part of the original message, the quoted part of
+    /// the message
+    pub quote: Option<TextQuote>,
     /// Bot through which the message was sent
     pub via_bot: Option<User>,
     /// Date the message was last edited in Unix time
     pub edit_date: Option<DateTime<Utc>>,
     /// Signature of the post author for messages in channels
     pub author_signature: Option<String>,
+    /// True, if the message can't be forwarded
+    pub has_protected_content: bool,
 
     /// The content of the message
     pub content: MessageContent,
+    /// Options used for link preview generation for the message, if it is a
+    /// text message and link preview options were changed
+    pub link_preview_options: Option<LinkPreviewOptions>,
 
     /// The domain name of the website on which the user has logged in.
     pub connected_website: Option<String>,
@@ -196,6 +221,15 @@ pub enum MessageContent {
         /// [payment]: https://core.telegram.org/bots/api#payments
         content: Invoice,
     },
+    PaidMedia {
+        /// Message contains paid media; information about the paid media
+        content: PaidMediaInfo,
+        /// The caption, 0-1024 characters
+        caption: Option<String>,
+        /// Special entities like usernames, URLs, bot commands, etc. that
+        /// appear in the caption
+        caption_entities: Option<Vec<MessageEntity>>,
+    },
     SuccessfulPayment {
         /// Message is a service message about a successful payment, information
         /// about the payment.
@@ -222,6 +256,42 @@ pub enum MessageContent {
         /// Service message: new participants invited to a voice chat
         content: VoiceChatParticipantsInvited,
     },
+    ForumTopicCreated {
+        /// Service message: forum topic created
+        content: ForumTopicCreated,
+    },
+    ForumTopicEdited {
+        /// Service message: forum topic edited
+        content: ForumTopicEdited,
+    },
+    ForumTopicClosed {
+        /// Service message: forum topic closed
+        content: ForumTopicClosed,
+    },
+    ForumTopicReopened {
+        /// Service message: forum topic reopened
+        content: ForumTopicReopened,
+    },
+    GeneralForumTopicHidden {
+        /// Service message: the General forum topic hidden
+        content: GeneralForumTopicHidden,
+    },
+    GeneralForumTopicUnhidden {
+        /// Service message: the General forum topic unhidden
+        content: GeneralForumTopicUnhidden,
+    },
+    WebAppData {
+        /// Service message: data sent by a Web App
+        content: WebAppData,
+    },
+    UsersShared {
+        /// Service message: users were shared with the bot
+        content: UsersShared,
+    },
+    ChatShared {
+        /// Service message: a chat was shared with the bot
+        content: ChatShared,
+    },
 
     /// Service message: the chat photo was deleted
     DeleteChatPhoto,
@@ -273,7 +343,57 @@ impl Message {
             | MessageContent::Animation { ref caption, .. }
             | MessageContent::Video { ref caption, .. }
             | MessageContent::Voice { ref caption, .. }
-            | MessageContent::Photo { ref caption, .. } => caption.clone(),
+            | MessageContent::Photo { ref caption, .. }
+            | MessageContent::PaidMedia { ref caption, .. } => caption.clone(),
+            _ => None,
+        }
+    }
+
+    /// Gets the text or caption of the message together with its entities
+    pub fn get_text_with_entities(&self) -> Option<(&str, &[MessageEntity])> {
+        match self.content {
+            MessageContent::Text {
+                ref content,
+                ref entities,
+            } => Some((content, entities)),
+            MessageContent::Audio {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::Document {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::Animation {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::Video {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::Voice {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::Photo {
+                ref caption,
+                ref caption_entities,
+                ..
+            }
+            | MessageContent::PaidMedia {
+                ref caption,
+                ref caption_entities,
+                ..
+            } => Some((
+                caption.as_deref()?,
+                caption_entities.as_deref().unwrap_or_default(),
+            )),
             _ => None,
         }
     }
@@ -285,12 +405,20 @@ impl From<RawMessage> for Message {
         let message_id = raw.message_id;
         let from = raw.from;
         let sender_chat = raw.sender_chat.map(std::convert::Into::into);
+        let sender_business_bot = raw.sender_business_bot;
         let date = raw.date;
+        let business_connection_id = raw.business_connection_id;
         let chat = raw.chat.into();
+        let message_thread_id = raw.message_thread_id;
+        let is_topic_message = raw.is_topic_message;
         let reply_to_message = raw.reply_to_message.map(|r| Box::new((*r).into()));
+        let external_reply = raw.external_reply;
+        let quote = raw.quote;
         let via_bot = raw.via_bot;
         let edit_date = raw.edit_date;
         let author_signature = raw.author_signature;
+        let has_protected_content = raw.has_protected_content;
+        let link_preview_options = raw.link_preview_options;
         let connected_website = raw.connected_website;
         let passport_data = raw.passport_data;
         let reply_markup = raw.reply_markup;
@@ -312,14 +440,22 @@ impl From<RawMessage> for Message {
             message_id,
             from,
             sender_chat,
+            sender_business_bot,
             date,
+            business_connection_id,
             chat,
+            message_thread_id,
+            is_topic_message,
             forward_data,
             reply_to_message,
+            external_reply,
+            quote,
             via_bot,
             edit_date,
             author_signature,
+            has_protected_content,
             content,
+            link_preview_options,
             connected_website,
             passport_data,
             reply_markup,
@@ -382,6 +518,7 @@ impl From<RawMessage> for Message {
         content_with_captions!(raw.animation, Animation);
         content_with_captions!(raw.document, Document);
         content_with_captions!(raw.voice, Voice);
+        content_with_captions!(raw.paid_media, PaidMedia);
 
         content!(raw.game, Game);
         content!(raw.sticker, Sticker);
@@ -411,6 +548,15 @@ impl From<RawMessage> for Message {
             raw.voice_chat_participants_invited,
             VoiceChatParticipantsInvited
         );
+        content!(raw.forum_topic_created, ForumTopicCreated);
+        content!(raw.forum_topic_edited, ForumTopicEdited);
+        content!(raw.forum_topic_closed, ForumTopicClosed);
+        content!(raw.forum_topic_reopened, ForumTopicReopened);
+        content!(raw.general_forum_topic_hidden, GeneralForumTopicHidden);
+        content!(raw.general_forum_topic_unhidden, GeneralForumTopicUnhidden);
+        content!(raw.web_app_data, WebAppData);
+        content!(raw.users_shared, UsersShared);
+        content!(raw.chat_shared, ChatShared);
 
         bool_content!(raw.delete_chat_photo, DeleteChatPhoto);
         bool_content!(raw.group_chat_created, GroupChatCreated);
@@ -428,11 +574,18 @@ impl From<Message> for RawMessage {
             message_id: message.message_id,
             from: message.from,
             sender_chat: message.sender_chat.map(std::convert::Into::into),
+            sender_business_bot: message.sender_business_bot,
             date: message.date,
+            business_connection_id: message.business_connection_id,
             chat: message.chat.into(),
+            message_thread_id: message.message_thread_id,
+            is_topic_message: message.is_topic_message,
             reply_to_message: message.reply_to_message.map(|r| Box::new((*r).into())),
+            external_reply: message.external_reply,
+            quote: message.quote,
             via_bot: message.via_bot,
             edit_date: message.edit_date,
+            has_protected_content: message.has_protected_content,
             media_group_id: None,
             author_signature: message.author_signature,
 
@@ -445,6 +598,7 @@ impl From<Message> for RawMessage {
 
             text: None,
             entities: None,
+            link_preview_options: message.link_preview_options,
             caption_entities: None,
             audio: None,
             document: None,
@@ -474,12 +628,22 @@ impl From<Message> for RawMessage {
             migrate_from_chat_id: None,
             pinned_message: None,
             invoice: None,
+            paid_media: None,
             successful_payment: None,
             proximity_alert_triggered: None,
             voice_chat_scheduled: None,
             voice_chat_started: None,
             voice_chat_ended: None,
             voice_chat_participants_invited: None,
+            forum_topic_created: None,
+            forum_topic_edited: None,
+            forum_topic_closed: None,
+            forum_topic_reopened: None,
+            general_forum_topic_hidden: None,
+            general_forum_topic_unhidden: None,
+            web_app_data: None,
+            users_shared: None,
+            chat_shared: None,
 
             connected_website: message.connected_website,
             passport_data: message.passport_data,
@@ -500,7 +664,7 @@ impl From<Message> for RawMessage {
                 ret.text = Some(content);
                 ret.entities = Some(entities);
                 ret
-            }
+            },
             MessageContent::Audio {
                 content,
                 caption,
@@ -510,7 +674,7 @@ impl From<Message> for RawMessage {
                 ret.caption = caption;
                 ret.caption_entities = caption_entities;
                 ret
-            }
+            },
             MessageContent::Document {
                 content,
                 caption,
@@ -520,7 +684,7 @@ impl From<Message> for RawMessage {
                 ret.caption = caption;
                 ret.caption_entities = caption_entities;
                 ret
-            }
+            },
             MessageContent::Animation {
                 content,
                 caption,
@@ -530,7 +694,7 @@ impl From<Message> for RawMessage {
                 ret.caption = caption;
                 ret.caption_entities = caption_entities;
                 ret
-            }
+            },
             MessageContent::Voice {
                 content,
                 caption,
@@ -540,7 +704,7 @@ impl From<Message> for RawMessage {
                 ret.caption = caption;
                 ret.caption_entities = caption_entities;
                 ret
-            }
+            },
             MessageContent::Video {
                 content,
                 caption,
@@ -552,7 +716,7 @@ impl From<Message> for RawMessage {
                 ret.caption_entities = caption_entities;
                 ret.media_group_id = media_group_id;
                 ret
-            }
+            },
             MessageContent::Photo {
                 content,
                 caption,
@@ -564,115 +728,161 @@ impl From<Message> for RawMessage {
                 ret.caption_entities = caption_entities;
                 ret.media_group_id = media_group_id;
                 ret
-            }
+            },
             MessageContent::Game { content } => {
                 ret.game = Some(content);
                 ret
-            }
+            },
             MessageContent::Sticker { content } => {
                 ret.sticker = Some(content);
                 ret
-            }
+            },
             MessageContent::VideoNote { content } => {
                 ret.video_note = Some(content);
                 ret
-            }
+            },
             MessageContent::Contact { content } => {
                 ret.contact = Some(content);
                 ret
-            }
+            },
             MessageContent::Location { content } => {
                 ret.location = Some(content);
                 ret
-            }
+            },
             MessageContent::Venue { content } => {
                 ret.venue = Some(content);
                 ret
-            }
+            },
             MessageContent::Poll { content } => {
                 ret.poll = Some(content);
                 ret
-            }
+            },
             MessageContent::Dice { content } => {
                 ret.dice = Some(content);
                 ret
-            }
+            },
             MessageContent::NewChatMembers { content } => {
                 ret.new_chat_members = Some(content);
                 ret
-            }
+            },
             MessageContent::LeftChatMember { content } => {
                 ret.left_chat_member = Some(content);
                 ret
-            }
+            },
             MessageContent::NewChatTitle { content } => {
                 ret.new_chat_title = Some(content);
                 ret
-            }
+            },
             MessageContent::NewChatPhoto { content } => {
                 ret.new_chat_photo = Some(content);
                 ret
-            }
+            },
             MessageContent::MessageAutoDeleteTimerChanged { content } => {
                 ret.message_auto_delete_timer_changed = Some(content);
                 ret
-            }
+            },
             MessageContent::MigrateToChatID { content } => {
                 ret.migrate_to_chat_id = Some(content);
                 ret
-            }
+            },
             MessageContent::MigrateFromChatID { content } => {
                 ret.migrate_from_chat_id = Some(content);
                 ret
-            }
+            },
             MessageContent::Invoice { content } => {
                 ret.invoice = Some(content);
                 ret
-            }
+            },
+            MessageContent::PaidMedia {
+                content,
+                caption,
+                caption_entities,
+            } => {
+                ret.paid_media = Some(content);
+                ret.caption = caption;
+                ret.caption_entities = caption_entities;
+                ret
+            },
             MessageContent::SuccessfulPayment { content } => {
                 ret.successful_payment = Some(content);
                 ret
-            }
+            },
             MessageContent::PinnedMessage { content } => {
                 ret.pinned_message = Some(Box::new((*content).into()));
                 ret
-            }
+            },
             MessageContent::ProximityAlertTriggered { content } => {
                 ret.proximity_alert_triggered = Some(content);
                 ret
-            }
+            },
             MessageContent::VoiceChatScheduled { content } => {
                 ret.voice_chat_scheduled = Some(content);
                 ret
-            }
+            },
             MessageContent::VoiceChatStarted { content } => {
                 ret.voice_chat_started = Some(content);
                 ret
-            }
+            },
             MessageContent::VoiceChatEnded { content } => {
                 ret.voice_chat_ended = Some(content);
                 ret
-            }
+            },
             MessageContent::VoiceChatParticipantsInvited { content } => {
                 ret.voice_chat_participants_invited = Some(content);
                 ret
-            }
+            },
+            MessageContent::ForumTopicCreated { content } => {
+                ret.forum_topic_created = Some(content);
+                ret
+            },
+            MessageContent::ForumTopicEdited { content } => {
+                ret.forum_topic_edited = Some(content);
+                ret
+            },
+            MessageContent::ForumTopicClosed { content } => {
+                ret.forum_topic_closed = Some(content);
+                ret
+            },
+            MessageContent::ForumTopicReopened { content } => {
+                ret.forum_topic_reopened = Some(content);
+                ret
+            },
+            MessageContent::GeneralForumTopicHidden { content } => {
+                ret.general_forum_topic_hidden = Some(content);
+                ret
+            },
+            MessageContent::GeneralForumTopicUnhidden { content } => {
+                ret.general_forum_topic_unhidden = Some(content);
+                ret
+            },
+            MessageContent::WebAppData { content } => {
+                ret.web_app_data = Some(content);
+                ret
+            },
+            MessageContent::UsersShared { content } => {
+                ret.users_shared = Some(content);
+                ret
+            },
+            MessageContent::ChatShared { content } => {
+                ret.chat_shared = Some(content);
+                ret
+            },
             MessageContent::DeleteChatPhoto => {
                 ret.delete_chat_photo = true;
                 ret
-            }
+            },
             MessageContent::GroupChatCreated => {
                 ret.group_chat_created = true;
                 ret
-            }
+            },
             MessageContent::SupergroupChatCreated => {
                 ret.supergroup_chat_created = true;
                 ret
-            }
+            },
             MessageContent::ChannelChatCreated => {
                 ret.channel_chat_created = true;
                 ret
-            }
+            },
             MessageContent::Unknown => ret,
         }
     }
@@ -392,3 +392,146 @@ pub struct MessageAutoDeleteTimerChanged {
     /// New auto-delete time for messages in the chat
     pub message_auto_delete_time: i64,
 }
+
+/// This object represents a service message about a new forum topic created in
+/// the chat.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForumTopicCreated {
+    /// Name of the topic
+    pub name: String,
+    /// Color of the topic icon in RGB format
+    pub icon_color: i64,
+    /// Unique identifier of the custom emoji shown as the topic icon
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// This object represents a service message about an edited forum topic.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForumTopicEdited {
+    /// New name of the topic, if it was edited
+    pub name: Option<String>,
+    /// New identifier of the custom emoji shown as the topic icon, if it was
+    /// edited; an empty string if the icon was removed
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// This object represents a service message about a forum topic closed in the
+/// chat. Currently holds no information.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForumTopicClosed {}
+
+/// This object represents a service message about a forum topic reopened in
+/// the chat. Currently holds no information.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForumTopicReopened {}
+
+/// This object represents a service message about the General forum topic
+/// hidden in the chat. Currently holds no information.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GeneralForumTopicHidden {}
+
+/// This object represents a service message about the General forum topic
+/// unhidden in the chat. Currently holds no information.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GeneralForumTopicUnhidden {}
+
+/// This object contains information about a user that was shared with the bot
+/// using a [`KeyboardButtonRequestUsers`] button.
+///
+/// [`KeyboardButtonRequestUsers`]: ../model/struct.KeyboardButtonRequestUsers.html
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedUser {
+    /// Identifier of the shared user. The bot may not have access to the user
+    /// and could be unable to use this identifier, unless the user is already
+    /// known to the bot by some other means.
+    pub user_id: i64,
+    /// First name of the user, if the name was requested by the bot
+    pub first_name: Option<String>,
+    /// Last name of the user, if the name was requested by the bot
+    pub last_name: Option<String>,
+    /// Username of the user, if the username was requested by the bot
+    pub username: Option<String>,
+    /// Available sizes of the chat photo, if the photo was requested by the
+    /// bot
+    pub photo: Option<Vec<PhotoSize>>,
+}
+
+/// This object represents a service message about the users whose
+/// identifiers were shared with the bot using a [`KeyboardButtonRequestUsers`]
+/// button.
+///
+/// [`KeyboardButtonRequestUsers`]: ../model/struct.KeyboardButtonRequestUsers.html
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsersShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Information about users shared with the bot
+    pub users: Vec<SharedUser>,
+}
+
+/// This object represents a service message about a chat whose identifier was
+/// shared with the bot using a [`KeyboardButtonRequestChat`] button.
+///
+/// [`KeyboardButtonRequestChat`]: ../model/struct.KeyboardButtonRequestChat.html
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChatShared {
+    /// Identifier of the request
+    pub request_id: i64,
+    /// Identifier of the shared chat. The bot may not have access to the chat
+    /// and could be unable to use this identifier, unless the chat is already
+    /// known to the bot by some other means.
+    pub chat_id: i64,
+    /// Title of the chat, if the title was requested by the bot
+    pub title: Option<String>,
+    /// Username of the chat, if the username was requested by the bot and
+    /// available
+    pub username: Option<String>,
+    /// Available sizes of the chat photo, if the photo was requested by the
+    /// bot
+    pub photo: Option<Vec<PhotoSize>>,
+}
+
+/// Describes the options used for link preview generation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct LinkPreviewOptions {
+    /// True, if the link preview is disabled
+    #[serde(default)]
+    pub is_disabled: bool,
+    /// URL to use for the link preview. If empty, then the first URL found in
+    /// the message text will be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// True, if the media in the link preview is supposed to be shrunk; ignored
+    /// if the URL isn't explicitly specified or media size change isn't
+    /// supported for the preview
+    #[serde(default)]
+    pub prefer_small_media: bool,
+    /// True, if the media in the link preview is supposed to be enlarged;
+    /// ignored if the URL isn't explicitly specified or media size change
+    /// isn't supported for the preview
+    #[serde(default)]
+    pub prefer_large_media: bool,
+    /// True, if the link preview must be shown above the message text;
+    /// otherwise, the link preview will be shown below the message text
+    #[serde(default)]
+    pub show_above_text: bool,
+}
+
+impl LinkPreviewOptions {
+    /// Creates `LinkPreviewOptions` that disable the link preview
+    pub fn disabled() -> Self {
+        Self {
+            is_disabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Creates `LinkPreviewOptions` that generate the link preview for the
+    /// given url
+    pub fn for_url(url: &str) -> Self {
+        Self {
+            url: Some(url.to_owned()),
+            ..Self::default()
+        }
+    }
+}
@@ -2,7 +2,9 @@
 
 pub(crate) mod utils;
 
+mod business;
 mod chat;
+mod formatting;
 mod games;
 mod inline;
 mod markup;
@@ -10,14 +12,20 @@ mod message;
 mod message_contents;
 mod message_entity;
 mod other;
+mod paid_media;
 mod payments;
 pub mod raw;
+mod reactions;
+mod reply;
 mod stickers;
 mod telegram_passport;
 mod update;
 mod user;
+mod web_app;
 
+pub use business::*;
 pub use chat::*;
+pub use formatting::*;
 pub use games::*;
 pub use inline::*;
 pub use markup::*;
@@ -25,8 +33,12 @@ pub use message::*;
 pub use message_contents::*;
 pub use message_entity::*;
 pub use other::*;
+pub use paid_media::*;
 pub use payments::*;
+pub use reactions::*;
+pub use reply::*;
 pub use stickers::*;
 pub use telegram_passport::*;
 pub use update::*;
 pub use user::*;
+pub use web_app::*;
@@ -27,7 +27,7 @@ pub struct CallbackQuery {
     pub message: Option<Message>,
     /// Identifier of the message sent via the bot in inline mode, that
     /// originated the query.
-    pub inline_message_id: Option<Message>,
+    pub inline_message_id: Option<String>,
     /// Global identifier, uniquely corresponding to the chat to which the
     /// message with the callback button was sent. Useful for high scores in [games](https://core.telegram.org/bots/api#games).
     pub chat_instance: String,
@@ -50,6 +50,27 @@ pub struct BotCommand {
     pub description: String,
 }
 
+/// This object represents the bot's name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BotName {
+    /// The bot's name
+    pub name: String,
+}
+
+/// This object represents the bot's description.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BotDescription {
+    /// The bot's description
+    pub description: String,
+}
+
+/// This object represents the bot's short description.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BotShortDescription {
+    /// The bot's short description
+    pub short_description: String,
+}
+
 /// The Bot API supports basic formatting for messages.
 /// You can use bold, italic, underlined and strikethrough text, as well as
 /// inline links and pre-formatted code in your bots' messages. Telegram clients
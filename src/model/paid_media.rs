@@ -0,0 +1,40 @@
+use super::{PhotoSize, Video};
+use serde::{Deserialize, Serialize};
+
+/// Describes the paid media added to a message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the
+    /// media
+    pub star_count: i64,
+    /// Information about the paid media
+    pub paid_media: Vec<PaidMedia>,
+}
+
+/// This object describes paid media.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum PaidMedia {
+    /// The paid media isn't available before the payment.
+    #[serde(rename = "preview")]
+    Preview {
+        /// Media width as defined by the sender
+        width: Option<i64>,
+        /// Media height as defined by the sender
+        height: Option<i64>,
+        /// Duration of the media in seconds as defined by the sender
+        duration: Option<i64>,
+    },
+    /// The paid media is a photo.
+    #[serde(rename = "photo")]
+    Photo {
+        /// The photo
+        photo: Vec<PhotoSize>,
+    },
+    /// The paid media is a video.
+    #[serde(rename = "video")]
+    Video {
+        /// The video
+        video: Video,
+    },
+}
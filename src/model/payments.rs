@@ -1,4 +1,5 @@
-use super::User;
+use super::{utils::unix_date_formatting, Chat, PaidMedia, User};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// This object contains basic information about an invoice.
@@ -34,6 +35,17 @@ pub struct SuccessfulPayment {
     pub total_amount: usize,
     /// Bot specified invoice payload
     pub invoice_payload: String,
+    /// Expiration date of the subscription, if this is a payment for a
+    /// subscription
+    #[serde(default)]
+    #[serde(with = "unix_date_formatting::optional")]
+    pub subscription_expiration_date: Option<DateTime<Utc>>,
+    /// True, if the payment is a recurring payment for a subscription
+    #[serde(default)]
+    pub is_recurring: bool,
+    /// True, if the payment is the first payment for a subscription
+    #[serde(default)]
+    pub is_first_recurring: bool,
     /// Identifier of the shipping option chosen by the user
     pub shipping_option_id: Option<String>,
     /// Order info provided by the user
@@ -133,3 +145,102 @@ pub struct LabeledPrice {
     /// (2 for the majority of currencies).
     pub amount: i64,
 }
+
+/// Contains a list of Telegram Star transactions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// Describes a Telegram Star transaction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of
+    /// the original transaction for refund transactions. Coincides with
+    /// `SuccessfulPayment.telegram_payment_charge_id` for successful incoming
+    /// payments from users.
+    pub id: String,
+    /// Integer amount of Telegram Stars transferred by the transaction
+    pub amount: i64,
+    /// The number of 1/1000000000 shares of Telegram Stars transferred by the
+    /// transaction; from 0 to 999999999
+    pub nanostar_amount: Option<i64>,
+    /// Date the transaction was created
+    #[serde(with = "unix_date_formatting")]
+    pub date: DateTime<Utc>,
+    /// Source of an incoming transaction (e.g., a user purchasing goods or
+    /// services, Fragment refunding a failed withdrawal). Only for incoming
+    /// transactions
+    pub source: Option<TransactionPartner>,
+    /// Receiver of an outgoing transaction (e.g., a user for a purchase
+    /// refund, Fragment for a withdrawal). Only for outgoing transactions
+    pub receiver: Option<TransactionPartner>,
+}
+
+/// This object describes the source of a transaction, or its recipient for
+/// outgoing transactions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum TransactionPartner {
+    /// Describes a transaction with a user.
+    #[serde(rename = "user")]
+    User {
+        /// Information about the user
+        user: User,
+        /// Bot-specified invoice payload
+        invoice_payload: Option<String>,
+        /// The duration of the paid subscription, in seconds
+        subscription_period: Option<i64>,
+        /// Information about the paid media bought by the user
+        paid_media: Option<Vec<PaidMedia>>,
+        /// Bot-specified paid media payload
+        paid_media_payload: Option<String>,
+    },
+    /// Describes a transaction with a chat.
+    #[serde(rename = "chat")]
+    Chat {
+        /// Information about the chat
+        chat: Chat,
+    },
+    /// Describes a withdrawal transaction with Fragment.
+    #[serde(rename = "fragment")]
+    Fragment {
+        /// State of the transaction if the transaction is outgoing
+        withdrawal_state: Option<RevenueWithdrawalState>,
+    },
+    /// Describes a withdrawal transaction to the Telegram Ads platform.
+    #[serde(rename = "telegram_ads")]
+    TelegramAds,
+    /// Describes a transaction with payment for paid broadcasting.
+    #[serde(rename = "telegram_api")]
+    TelegramApi {
+        /// The number of successful requests that exceeded regular limits and
+        /// were therefore billed
+        request_count: i64,
+    },
+    /// Describes a transaction with an unknown source or recipient.
+    #[serde(rename = "other")]
+    Other,
+}
+
+/// This object describes the state of a revenue withdrawal operation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum RevenueWithdrawalState {
+    /// The withdrawal is in progress.
+    #[serde(rename = "pending")]
+    Pending,
+    /// The withdrawal succeeded.
+    #[serde(rename = "succeeded")]
+    Succeeded {
+        /// Date the withdrawal was completed
+        #[serde(with = "unix_date_formatting")]
+        date: DateTime<Utc>,
+        /// An HTTPS URL that can be used to see transaction details
+        url: String,
+    },
+    /// The withdrawal failed and the transaction was refunded.
+    #[serde(rename = "failed")]
+    Failed,
+}
@@ -2,10 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    message_contents::*, message_entity::*, utils::unix_date_formatting, CallbackQuery,
-    ChatLocation, ChatMemberUpdated, ChatPhoto, ChatType, ChosenInlineResult, Game,
-    InlineKeyboardMarkup, InlineQuery, Invoice, PassportData, PreCheckoutQuery, ShippingQuery,
-    Sticker, SuccessfulPayment, User,
+    message_contents::*, message_entity::*, utils::unix_date_formatting, BusinessConnection,
+    BusinessMessagesDeleted, CallbackQuery, ChatJoinRequest, ChatLocation, ChatMemberUpdated,
+    ChatPhoto, ChatType, ChosenInlineResult, ExternalReplyInfo, Game, InlineKeyboardMarkup,
+    InlineQuery, Invoice, MessageReactionCountUpdated, MessageReactionUpdated, PaidMediaInfo,
+    PassportData, PreCheckoutQuery, ShippingQuery, Sticker, SuccessfulPayment, TextQuote, User,
+    WebAppData,
 };
 
 /// The raw message, for most usages the [`Message`] object is easier to use
@@ -16,9 +18,14 @@ pub struct RawMessage {
     pub message_id: i64,
     pub from: Option<super::User>,
     pub sender_chat: Option<RawChat>,
+    pub sender_business_bot: Option<super::User>,
     #[serde(with = "unix_date_formatting")]
     pub date: DateTime<Utc>,
+    pub business_connection_id: Option<String>,
     pub chat: RawChat,
+    pub message_thread_id: Option<i64>,
+    #[serde(default)]
+    pub is_topic_message: bool,
 
     pub forward_from: Option<super::User>,
     pub forward_from_chat: Option<RawChat>,
@@ -30,17 +37,22 @@ pub struct RawMessage {
     pub forward_date: Option<DateTime<Utc>>,
 
     pub reply_to_message: Option<Box<RawMessage>>,
+    pub external_reply: Option<Box<ExternalReplyInfo>>,
+    pub quote: Option<TextQuote>,
     pub via_bot: Option<User>,
 
     #[serde(default)]
     #[serde(with = "unix_date_formatting::optional")]
     pub edit_date: Option<DateTime<Utc>>,
 
+    #[serde(default)]
+    pub has_protected_content: bool,
     pub media_group_id: Option<String>,
     pub author_signature: Option<String>,
 
     pub text: Option<String>,
     pub entities: Option<Vec<MessageEntity>>,
+    pub link_preview_options: Option<LinkPreviewOptions>,
     pub caption_entities: Option<Vec<MessageEntity>>,
     pub audio: Option<Audio>,
     pub document: Option<Document>,
@@ -78,6 +90,7 @@ pub struct RawMessage {
 
     pub pinned_message: Option<Box<RawMessage>>,
     pub invoice: Option<Invoice>,
+    pub paid_media: Option<PaidMediaInfo>,
     pub successful_payment: Option<SuccessfulPayment>,
 
     pub connected_website: Option<String>,
@@ -89,6 +102,16 @@ pub struct RawMessage {
     pub voice_chat_started: Option<VoiceChatStarted>,
     pub voice_chat_ended: Option<VoiceChatEnded>,
     pub voice_chat_participants_invited: Option<VoiceChatParticipantsInvited>,
+
+    pub forum_topic_created: Option<ForumTopicCreated>,
+    pub forum_topic_edited: Option<ForumTopicEdited>,
+    pub forum_topic_closed: Option<ForumTopicClosed>,
+    pub forum_topic_reopened: Option<ForumTopicReopened>,
+    pub general_forum_topic_hidden: Option<GeneralForumTopicHidden>,
+    pub general_forum_topic_unhidden: Option<GeneralForumTopicUnhidden>,
+    pub web_app_data: Option<WebAppData>,
+    pub users_shared: Option<UsersShared>,
+    pub chat_shared: Option<ChatShared>,
 }
 
 /// The raw chat, for most usages the [`Chat`] object is easier to use
@@ -161,6 +184,9 @@ pub struct RawChat {
     ///
     /// [`get_chat`]: ../../api/trait.API.html#method.get_chat
     pub location: Option<ChatLocation>,
+    /// True, if the supergroup chat is a forum (has topics enabled)
+    #[serde(default)]
+    pub is_forum: bool,
 }
 
 /// The raw update, for most usages the [`Update`] object is easier to use
@@ -174,13 +200,13 @@ pub struct RawUpdate {
     /// be chosen randomly instead of sequentially.
     pub update_id: i64,
     /// New incoming message of any kind — text, photo, sticker, etc.
-    pub message: Option<RawMessage>,
+    pub message: Option<Box<RawMessage>>,
     /// New version of a message that is known to the bot and was edited.
-    pub edited_message: Option<RawMessage>,
+    pub edited_message: Option<Box<RawMessage>>,
     /// New incoming channel post of any kind — text, photo, sticker, etc.
-    pub channel_post: Option<RawMessage>,
+    pub channel_post: Option<Box<RawMessage>>,
     /// New version of a channel post that is known to the bot and was edited.
-    pub edited_channel_post: Option<RawMessage>,
+    pub edited_channel_post: Option<Box<RawMessage>>,
     /// New incoming inline query.
     pub inline_query: Option<InlineQuery>,
     /// The result of an inline query that was chosen by a user and sent to
@@ -207,4 +233,27 @@ pub struct RawUpdate {
     /// administrator in the chat and must explicitly specify “chat_member”
     /// in the list of allowed_updates to receive these updates.
     pub chat_member: Option<ChatMemberUpdated>,
+    /// A reaction to a message was changed by a user. The bot must be an
+    /// administrator in the chat and must explicitly specify
+    /// `message_reaction` in the list of `allowed_updates` to receive these
+    /// updates.
+    pub message_reaction: Option<MessageReactionUpdated>,
+    /// Reactions to a message with anonymous reactions were changed. The bot
+    /// must be an administrator in the chat and must explicitly specify
+    /// `message_reaction_count` in the list of `allowed_updates` to
+    /// receive these updates.
+    pub message_reaction_count: Option<MessageReactionCountUpdated>,
+    /// A request to join the chat has been sent. The bot must have the
+    /// `can_invite_users` administrator right in the chat to receive these
+    /// updates.
+    pub chat_join_request: Option<ChatJoinRequest>,
+    /// The bot was connected to or disconnected from a business account, or a
+    /// user edited an existing connection with the bot.
+    pub business_connection: Option<BusinessConnection>,
+    /// New message from a connected business account.
+    pub business_message: Option<Box<RawMessage>>,
+    /// New version of a message from a connected business account.
+    pub edited_business_message: Option<Box<RawMessage>>,
+    /// Messages were deleted from a connected business account.
+    pub deleted_business_messages: Option<BusinessMessagesDeleted>,
 }
@@ -0,0 +1,106 @@
+use super::{utils::unix_date_formatting, Chat, User};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// This object describes the type of a reaction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "type")]
+pub enum ReactionType {
+    /// The reaction is based on an emoji.
+    #[serde(rename = "emoji")]
+    Emoji {
+        /// Reaction emoji, for example "👍", "❤" or "🔥"
+        emoji: String,
+    },
+    /// The reaction is based on a custom emoji.
+    #[serde(rename = "custom_emoji")]
+    CustomEmoji {
+        /// Custom emoji identifier
+        custom_emoji_id: String,
+    },
+    /// The reaction is paid.
+    #[serde(rename = "paid")]
+    Paid,
+}
+
+impl ReactionType {
+    /// creates a reaction based on the given emoji
+    pub fn emoji(emoji: &str) -> Self {
+        Self::Emoji {
+            emoji: emoji.to_owned(),
+        }
+    }
+
+    /// creates a reaction based on the custom emoji with the given identifier
+    pub fn custom_emoji(custom_emoji_id: &str) -> Self {
+        Self::CustomEmoji {
+            custom_emoji_id: custom_emoji_id.to_owned(),
+        }
+    }
+}
+
+/// Represents a reaction added to a message along with the number of times it
+/// was added.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReactionCount {
+    /// Type of the reaction
+    #[serde(rename = "type")]
+    pub reaction_type: ReactionType,
+    /// Number of times the reaction was added
+    pub total_count: i64,
+}
+
+/// This object represents a change of a reaction on a message performed by a
+/// user.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageReactionUpdated {
+    /// The chat containing the message the user reacted to
+    pub chat: Chat,
+    /// Unique identifier of the message inside the chat
+    pub message_id: i64,
+    /// The user that changed the reaction, if the user isn't anonymous
+    pub user: Option<User>,
+    /// The chat on behalf of which the reaction was changed, if the user is
+    /// anonymous
+    pub actor_chat: Option<Chat>,
+    /// Date of the change
+    #[serde(with = "unix_date_formatting")]
+    pub date: DateTime<Utc>,
+    /// Previous list of reaction types that were set by the user
+    pub old_reaction: Vec<ReactionType>,
+    /// New list of reaction types that have been set by the user
+    pub new_reaction: Vec<ReactionType>,
+}
+
+impl MessageReactionUpdated {
+    /// Gets the reactions that were added by this change
+    pub fn added_reactions(&self) -> Vec<&ReactionType> {
+        self.new_reaction
+            .iter()
+            .filter(|r| !self.old_reaction.contains(r))
+            .collect()
+    }
+
+    /// Gets the reactions that were removed by this change
+    pub fn removed_reactions(&self) -> Vec<&ReactionType> {
+        self.old_reaction
+            .iter()
+            .filter(|r| !self.new_reaction.contains(r))
+            .collect()
+    }
+}
+
+/// This object represents reaction changes on a message with anonymous
+/// reactions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageReactionCountUpdated {
+    /// The chat containing the message
+    pub chat: Chat,
+    /// Unique message identifier inside the chat
+    pub message_id: i64,
+    /// Date of the change
+    #[serde(with = "unix_date_formatting")]
+    pub date: DateTime<Utc>,
+    /// List of reactions that are present on the message
+    pub reactions: Vec<ReactionCount>,
+}
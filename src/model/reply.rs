@@ -0,0 +1,139 @@
+use super::{
+    message_contents::*, message_entity::MessageEntity, utils::unix_date_formatting, Chat, Game,
+    Invoice, PaidMediaInfo, Sticker, User,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// This object contains information about the quoted part of a message that is
+/// replied to by the given message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TextQuote {
+    /// Text of the quoted part of a message that is replied to by the given
+    /// message
+    pub text: String,
+    /// Special entities that appear in the quote. Currently, only bold,
+    /// italic, underline, strikethrough, spoiler, and `custom_emoji` entities
+    /// are kept in quotes.
+    pub entities: Option<Vec<MessageEntity>>,
+    /// Approximate quote position in the original message in UTF-16 code
+    /// units as specified by the sender
+    pub position: i64,
+    /// True, if the quote was chosen manually by the message sender.
+    /// Otherwise, the quote was added automatically by the server.
+    #[serde(default)]
+    pub is_manual: bool,
+}
+
+/// This object describes the origin of a message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum MessageOrigin {
+    /// The message was originally sent by a known user.
+    #[serde(rename = "user")]
+    User {
+        /// Date the message was sent originally
+        #[serde(with = "unix_date_formatting")]
+        date: DateTime<Utc>,
+        /// User that sent the message originally
+        sender_user: User,
+    },
+    /// The message was originally sent by an unknown user.
+    #[serde(rename = "hidden_user")]
+    HiddenUser {
+        /// Date the message was sent originally
+        #[serde(with = "unix_date_formatting")]
+        date: DateTime<Utc>,
+        /// Name of the user that sent the message originally
+        sender_user_name: String,
+    },
+    /// The message was originally sent on behalf of a chat to a group chat.
+    #[serde(rename = "chat")]
+    Chat {
+        /// Date the message was sent originally
+        #[serde(with = "unix_date_formatting")]
+        date: DateTime<Utc>,
+        /// Chat that sent the message originally
+        sender_chat: Chat,
+        /// For messages originally sent by an anonymous chat administrator,
+        /// original message author signature
+        author_signature: Option<String>,
+    },
+    /// The message was originally sent to a channel chat.
+    #[serde(rename = "channel")]
+    Channel {
+        /// Date the message was sent originally
+        #[serde(with = "unix_date_formatting")]
+        date: DateTime<Utc>,
+        /// Channel chat to which the message was originally sent
+        chat: Chat,
+        /// Unique message identifier inside the chat
+        message_id: i64,
+        /// Signature of the original post author
+        author_signature: Option<String>,
+    },
+}
+
+impl MessageOrigin {
+    /// Gets the date the message was sent originally
+    pub fn get_date(&self) -> DateTime<Utc> {
+        match self {
+            Self::User { date, .. }
+            | Self::HiddenUser { date, .. }
+            | Self::Chat { date, .. }
+            | Self::Channel { date, .. } => *date,
+        }
+    }
+}
+
+/// This object contains information about a message that is being replied
+/// to, which may come from another chat or forum topic.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExternalReplyInfo {
+    /// Origin of the message replied to by the given message
+    pub origin: MessageOrigin,
+    /// Chat the original message belongs to. Available only if the chat is a
+    /// supergroup or a channel.
+    pub chat: Option<Chat>,
+    /// Unique message identifier inside the original chat. Available only if
+    /// the original chat is a supergroup or a channel.
+    pub message_id: Option<i64>,
+    /// Options used for link preview generation for the original message, if
+    /// it is a text message
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Message is an animation, information about the animation
+    pub animation: Option<Animation>,
+    /// Message is an audio file, information about the file
+    pub audio: Option<Audio>,
+    /// Message is a general file, information about the file
+    pub document: Option<Document>,
+    /// Message contains paid media; information about the paid media
+    pub paid_media: Option<PaidMediaInfo>,
+    /// Message is a photo, available sizes of the photo
+    pub photo: Option<Vec<PhotoSize>>,
+    /// Message is a sticker, information about the sticker
+    pub sticker: Option<Sticker>,
+    /// Message is a video, information about the video
+    pub video: Option<Video>,
+    /// Message is a video note, information about the video message
+    pub video_note: Option<VideoNote>,
+    /// Message is a voice message, information about the file
+    pub voice: Option<Voice>,
+    /// True, if the message media is covered by a spoiler animation
+    #[serde(default)]
+    pub has_media_spoiler: bool,
+    /// Message is a shared contact, information about the contact
+    pub contact: Option<Contact>,
+    /// Message is a dice with random value
+    pub dice: Option<Dice>,
+    /// Message is a game, information about the game
+    pub game: Option<Game>,
+    /// Message is an invoice for a payment, information about the invoice
+    pub invoice: Option<Invoice>,
+    /// Message is a shared location, information about the location
+    pub location: Option<Location>,
+    /// Message is a native poll, information about the poll
+    pub poll: Option<Poll>,
+    /// Message is a venue, information about the venue
+    pub venue: Option<Venue>,
+}
@@ -13,12 +13,20 @@ pub struct Sticker {
     pub file_unique_id: String,
     /// Sticker width
     pub width: usize,
+    /// Type of the sticker. The type of the sticker is independent from its
+    /// format, which is determined by the fields `is_animated` and `is_video`.
+    #[serde(rename = "type", default)]
+    pub sticker_type: StickerType,
     /// Sticker height
     pub height: usize,
     /// True, if the sticker is [animated](https://telegram.org/blog/animated-stickers)
     #[serde(default)]
     pub is_animated: bool,
+    /// True, if the sticker is a [video sticker](https://telegram.org/blog/video-stickers-better-reactions)
+    #[serde(default)]
+    pub is_video: bool,
     /// Sticker thumbnail in the .WEBP or .JPG format
+    #[serde(alias = "thumbnail")]
     pub thumb: Option<PhotoSize>,
     /// Emoji associated with the sticker
     pub emoji: Option<String>,
@@ -26,6 +34,13 @@ pub struct Sticker {
     pub set_name: Option<String>,
     /// For mask stickers, the position where the mask should be placed
     pub mask_position: Option<MaskPosition>,
+    /// For custom emoji stickers, unique identifier of the custom emoji
+    pub custom_emoji_id: Option<String>,
+    /// True, if the sticker must be repainted to a text color in messages,
+    /// the color of the Telegram Premium badge in emoji status, white color
+    /// on chat photos, or another appropriate color in other places
+    #[serde(default)]
+    pub needs_repainting: bool,
     /// File size
     pub file_size: Option<usize>,
 }
@@ -37,16 +52,59 @@ pub struct StickerSet {
     pub name: String,
     /// Sticker set title
     pub title: String,
-    /// True, if the sticker set contains [animated stickers](https://telegram.org/blog/animated-stickers)
+    /// Type of stickers in the sticker set
+    #[serde(default)]
+    pub sticker_type: StickerType,
+    /// True, if the sticker set contains [animated stickers](https://telegram.org/blog/animated-stickers).
+    /// No longer sent by telegram, as sticker sets can contain stickers of
+    /// any format
+    #[serde(default)]
     pub is_animated: bool,
-    /// True, if the sticker set contains masks
+    /// True, if the sticker set contains [video stickers](https://telegram.org/blog/video-stickers-better-reactions).
+    /// No longer sent by telegram, as sticker sets can contain stickers of
+    /// any format
+    #[serde(default)]
+    pub is_video: bool,
+    /// True, if the sticker set contains masks. No longer sent by telegram,
+    /// use `sticker_type` instead
+    #[serde(default)]
     pub contains_masks: bool,
     /// List of all set stickers
     pub stickers: Vec<Sticker>,
-    /// Optional. Sticker set thumbnail in the .WEBP or .TGS format
+    /// Optional. Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format
+    #[serde(alias = "thumbnail")]
     pub thumb: Option<PhotoSize>,
 }
 
+/// The type of a sticker, which is independent from its format
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StickerType {
+    /// A regular sticker
+    #[serde(rename = "regular")]
+    #[default]
+    Regular,
+    /// A mask, which is placed on faces in photos
+    #[serde(rename = "mask")]
+    Mask,
+    /// A custom emoji, which can be used in the text of messages
+    #[serde(rename = "custom_emoji")]
+    CustomEmoji,
+}
+
+/// The format of a sticker
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StickerFormat {
+    /// A .WEBP or .PNG image
+    #[serde(rename = "static")]
+    Static,
+    /// A .TGS animation
+    #[serde(rename = "animated")]
+    Animated,
+    /// A WEBM video
+    #[serde(rename = "video")]
+    Video,
+}
+
 /// This object describes the position on faces where a mask should be placed by
 /// default.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
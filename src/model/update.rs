@@ -1,6 +1,7 @@
 use super::{
-    raw::RawUpdate, CallbackQuery, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message,
-    Poll, PollAnswer, PreCheckoutQuery, ShippingQuery,
+    raw::RawUpdate, BusinessConnection, BusinessMessagesDeleted, CallbackQuery, ChatJoinRequest,
+    ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message, MessageReactionCountUpdated,
+    MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery, ShippingQuery,
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -61,6 +62,29 @@ pub enum UpdateContent {
     /// administrator in the chat and must explicitly specify “chat_member”
     /// in the list of allowed_updates to receive these updates.
     ChatMember(ChatMemberUpdated),
+    /// A reaction to a message was changed by a user. The bot must be an
+    /// administrator in the chat and must explicitly specify
+    /// `message_reaction` in the list of `allowed_updates` to receive these
+    /// updates.
+    MessageReaction(MessageReactionUpdated),
+    /// Reactions to a message with anonymous reactions were changed. The bot
+    /// must be an administrator in the chat and must explicitly specify
+    /// `message_reaction_count` in the list of `allowed_updates` to
+    /// receive these updates.
+    MessageReactionCount(MessageReactionCountUpdated),
+    /// A request to join the chat has been sent. The bot must have the
+    /// `can_invite_users` administrator right in the chat to receive these
+    /// updates.
+    ChatJoinRequest(ChatJoinRequest),
+    /// The bot was connected to or disconnected from a business account, or a
+    /// user edited an existing connection with the bot
+    BusinessConnection(BusinessConnection),
+    /// New message from a connected business account
+    BusinessMessage(Message),
+    /// New version of a message from a connected business account
+    EditedBusinessMessage(Message),
+    /// Messages were deleted from a connected business account
+    DeletedBusinessMessages(BusinessMessagesDeleted),
     /// An unknown update content
     Unknown,
 }
@@ -78,10 +102,10 @@ impl From<RawUpdate> for Update {
             };
         }
 
-        set_content!(raw.message, Message);
-        set_content!(raw.edited_message, EditedMessage);
-        set_content!(raw.channel_post, ChannelPost);
-        set_content!(raw.edited_channel_post, EditedChannelPost);
+        set_content!(raw.message.map(|m| *m), Message);
+        set_content!(raw.edited_message.map(|m| *m), EditedMessage);
+        set_content!(raw.channel_post.map(|m| *m), ChannelPost);
+        set_content!(raw.edited_channel_post.map(|m| *m), EditedChannelPost);
         set_content!(raw.inline_query, InlineQuery);
         set_content!(raw.chosen_inline_result, ChosenInlineResult);
         set_content!(raw.callback_query, CallbackQuery);
@@ -91,12 +115,23 @@ impl From<RawUpdate> for Update {
         set_content!(raw.poll_answer, PollAnswer);
         set_content!(raw.my_chat_member, MyChatMember);
         set_content!(raw.chat_member, ChatMember);
+        set_content!(raw.message_reaction, MessageReaction);
+        set_content!(raw.message_reaction_count, MessageReactionCount);
+        set_content!(raw.chat_join_request, ChatJoinRequest);
+        set_content!(raw.business_connection, BusinessConnection);
+        set_content!(raw.business_message.map(|m| *m), BusinessMessage);
+        set_content!(
+            raw.edited_business_message.map(|m| *m),
+            EditedBusinessMessage
+        );
+        set_content!(raw.deleted_business_messages, DeletedBusinessMessages);
 
         make_update(UpdateContent::Unknown)
     }
 }
 
 impl From<Update> for RawUpdate {
+    #[allow(clippy::too_many_lines)]
     fn from(update: Update) -> RawUpdate {
         let mut ret = Self {
             update_id: update.update_id,
@@ -113,23 +148,30 @@ impl From<Update> for RawUpdate {
             poll_answer: None,
             my_chat_member: None,
             chat_member: None,
+            message_reaction: None,
+            message_reaction_count: None,
+            chat_join_request: None,
+            business_connection: None,
+            business_message: None,
+            edited_business_message: None,
+            deleted_business_messages: None,
         };
 
         match update.content {
             UpdateContent::Message(c) => {
-                ret.message = Some(c.into());
+                ret.message = Some(Box::new(c.into()));
                 ret
             }
             UpdateContent::EditedMessage(c) => {
-                ret.edited_message = Some(c.into());
+                ret.edited_message = Some(Box::new(c.into()));
                 ret
             }
             UpdateContent::ChannelPost(c) => {
-                ret.channel_post = Some(c.into());
+                ret.channel_post = Some(Box::new(c.into()));
                 ret
             }
             UpdateContent::EditedChannelPost(c) => {
-                ret.edited_channel_post = Some(c.into());
+                ret.edited_channel_post = Some(Box::new(c.into()));
                 ret
             }
             UpdateContent::InlineQuery(c) => {
@@ -168,6 +210,34 @@ impl From<Update> for RawUpdate {
                 ret.chat_member = Some(c);
                 ret
             }
+            UpdateContent::MessageReaction(c) => {
+                ret.message_reaction = Some(c);
+                ret
+            }
+            UpdateContent::MessageReactionCount(c) => {
+                ret.message_reaction_count = Some(c);
+                ret
+            }
+            UpdateContent::ChatJoinRequest(c) => {
+                ret.chat_join_request = Some(c);
+                ret
+            }
+            UpdateContent::BusinessConnection(c) => {
+                ret.business_connection = Some(c);
+                ret
+            }
+            UpdateContent::BusinessMessage(c) => {
+                ret.business_message = Some(Box::new(c.into()));
+                ret
+            }
+            UpdateContent::EditedBusinessMessage(c) => {
+                ret.edited_business_message = Some(Box::new(c.into()));
+                ret
+            }
+            UpdateContent::DeletedBusinessMessages(c) => {
+                ret.deleted_business_messages = Some(c);
+                ret
+            }
             UpdateContent::Unknown => ret,
         }
     }
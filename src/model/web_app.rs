@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a [Web App].
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened with additional data as
+    /// specified in [Initializing Web Apps]
+    ///
+    /// [Initializing Web Apps]: https://core.telegram.org/bots/webapps#initializing-mini-apps
+    pub url: String,
+}
+
+impl WebAppInfo {
+    /// Creates a `WebAppInfo` for the Web App at the given url
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_owned(),
+        }
+    }
+}
+
+/// Describes data sent from a [Web App] to the bot.
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebAppData {
+    /// The data. Be aware that a bad client can send arbitrary data in this
+    /// field.
+    pub data: String,
+    /// Text of the `web_app` keyboard button from which the Web App was
+    /// opened. Be aware that a bad client can send arbitrary data in this
+    /// field.
+    pub button_text: String,
+}
+
+/// Describes an inline message sent by a [Web App] on behalf of a user.
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SentWebAppMessage {
+    /// Identifier of the sent inline message. Available only if there is an
+    /// inline keyboard attached to the message.
+    pub inline_message_id: Option<String>,
+}
+
+/// This object describes the bot's menu button in a private chat.
+/// If a menu button other than [`MenuButton::Default`] is set for a private
+/// chat, then it is applied in the chat. Otherwise the default menu button is
+/// applied. By default, the menu button opens the list of bot commands.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum MenuButton {
+    /// Represents a menu button, which opens the bot's list of commands.
+    #[serde(rename = "commands")]
+    Commands,
+    /// Represents a menu button, which launches a [Web App].
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    #[serde(rename = "web_app")]
+    WebApp {
+        /// Text on the button
+        text: String,
+        /// Description of the Web App that will be launched when the user
+        /// presses the button. The Web App will be able to send an arbitrary
+        /// message on behalf of the user using the method
+        /// [`answer_web_app_query`].
+        ///
+        /// [`answer_web_app_query`]: ../api/trait.API.html#method.answer_web_app_query
+        web_app: WebAppInfo,
+    },
+    /// Describes that no specific value for the menu button was set.
+    #[serde(rename = "default")]
+    Default,
+}
@@ -0,0 +1,72 @@
+use super::StateStorage;
+use crate::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use tokio::sync::Mutex;
+
+/// A [`StateStorage`] keeping the values in a json file, which is rewritten
+/// every time a value changes.
+///
+/// It is meant for bots with little state, use a database for anything
+/// bigger.
+#[derive(Debug)]
+pub struct JsonFileStorage {
+    path: PathBuf,
+    values: Mutex<HashMap<String, Value>>,
+}
+
+impl JsonFileStorage {
+    /// Opens the storage at the given path, reading the values stored in it
+    /// if the file exists
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        let values = match tokio::fs::read(&path).await {
+            Ok(content) => serde_json::from_slice(&content)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path,
+            values: Mutex::new(values),
+        })
+    }
+
+    // writes all values to a temporary file first, so the file isn't left
+    // half written when the bot stops during a write
+    async fn write(&self, values: &HashMap<String, Value>) -> Result<()> {
+        let content = serde_json::to_vec(values)?;
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+
+        tokio::fs::write(&temporary, content).await?;
+        tokio::fs::rename(&temporary, &self.path).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StateStorage for JsonFileStorage {
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        Ok(self.values.lock().await.get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: Value) -> Result<()> {
+        let mut values = self.values.lock().await;
+        values.insert(key.to_owned(), value);
+        self.write(&values).await
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let mut values = self.values.lock().await;
+        if values.remove(key).is_some() {
+            self.write(&values).await?;
+        }
+        Ok(())
+    }
+}
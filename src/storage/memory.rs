@@ -0,0 +1,36 @@
+use super::StateStorage;
+use crate::Result;
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A [`StateStorage`] keeping the values in memory, they are lost when the
+/// bot restarts
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    values: RwLock<HashMap<String, Value>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StateStorage for MemoryStorage {
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        Ok(self.values.read().get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: Value) -> Result<()> {
+        self.values.write().insert(key.to_owned(), value);
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.values.write().remove(key);
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Storage backends for state that should outlive a single update, like the
+//! settings of a chat, the progress of a user or the dialogues going on.
+//!
+//! A [`StateStorage`] stores json values under string keys. The [`Client`]
+//! uses a [`MemoryStorage`] by default, set another storage using
+//! [`ClientBuilder::set_storage`] to keep the state across restarts or to
+//! share it between multiple instances of a bot. The following storages are
+//! available behind cargo features:
+//!
+//! | Feature          | Storage             | Description                                   |
+//! |------------------|---------------------|-----------------------------------------------|
+//! |                  | [`MemoryStorage`]   | Keeps the values in memory, lost on restart   |
+//! | `json-storage`   | `JsonFileStorage`   | Keeps the values in a single json file        |
+//! | `sqlite-storage` | `SqliteStorage`     | Keeps the values in a table of a sqlite file  |
+//!
+//! Values are usually accessed using the typed accessors of the [`Context`],
+//! like [`Context::get_chat_state`] and [`Context::set_user_state`], which
+//! serialize them using serde.
+//!
+//! [`Client`]: ../client/struct.Client.html
+//! [`ClientBuilder::set_storage`]: ../client/struct.ClientBuilder.html#method.set_storage
+//! [`Context`]: ../client/struct.Context.html
+//! [`Context::get_chat_state`]: ../client/struct.Context.html#method.get_chat_state
+//! [`Context::set_user_state`]: ../client/struct.Context.html#method.set_user_state
+
+#[cfg(feature = "json-storage")]
+mod json_file;
+mod memory;
+#[cfg(feature = "sqlite-storage")]
+mod sqlite;
+
+#[cfg(feature = "json-storage")]
+pub use json_file::JsonFileStorage;
+pub use memory::MemoryStorage;
+#[cfg(feature = "sqlite-storage")]
+pub use sqlite::SqliteStorage;
+
+use crate::{dialogue::DialogueKey, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// A backend storing json values under string keys, used for the state of
+/// chats, users and dialogues.
+///
+/// Implement it to store the state in a database of your own.
+#[async_trait]
+pub trait StateStorage: Send + Sync {
+    /// Gets the value stored under the key, if there is one
+    async fn get(&self, key: &str) -> Result<Option<Value>>;
+
+    /// Stores the value under the key, replacing the value stored under it
+    /// before
+    async fn set(&self, key: &str, value: Value) -> Result<()>;
+
+    /// Removes the value stored under the key, if there is one
+    async fn remove(&self, key: &str) -> Result<()>;
+}
+
+/// Gets the key under which the state with the given name of a chat is stored
+pub fn chat_key(chat_id: i64, name: &str) -> String {
+    format!("chat:{chat_id}:{name}")
+}
+
+/// Gets the key under which the state with the given name of a user is stored
+pub fn user_key(user_id: i64, name: &str) -> String {
+    format!("user:{user_id}:{name}")
+}
+
+/// Gets the key under which the dialogue of a user in a chat is stored
+pub fn dialogue_key(key: DialogueKey) -> String {
+    format!("dialogue:{}:{}", key.chat_id, key.user_id)
+}
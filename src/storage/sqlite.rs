@@ -0,0 +1,92 @@
+use super::StateStorage;
+use crate::{Error, Result};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+use std::{path::Path, sync::Arc};
+
+/// A [`StateStorage`] keeping the values in the `state` table of a sqlite
+/// database
+#[derive(Debug, Clone)]
+pub struct SqliteStorage {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorage {
+    /// Opens the database at the given path, creating it and its `state`
+    /// table if they don't exist yet
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path).map_err(|e| storage_error(&e))?)
+    }
+
+    /// Uses an opened database connection, creating the `state` table if it
+    /// doesn't exist yet
+    pub fn from_connection(connection: Connection) -> Result<Self> {
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS state (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+                [],
+            )
+            .map_err(|e| storage_error(&e))?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    // runs the query on a blocking thread, as rusqlite is synchronous
+    async fn run<T, F>(&self, query: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || query(&connection.lock()))
+            .await
+            .map_err(|e| Error::Storage(e.to_string()))?
+            .map_err(|e| storage_error(&e))
+    }
+}
+
+fn storage_error(e: &rusqlite::Error) -> Error {
+    Error::Storage(e.to_string())
+}
+
+#[async_trait]
+impl StateStorage for SqliteStorage {
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        let key = key.to_owned();
+        let value: Option<String> = self
+            .run(move |c| {
+                c.query_row("SELECT value FROM state WHERE key = ?1", [key], |row| {
+                    row.get(0)
+                })
+                .optional()
+            })
+            .await?;
+
+        Ok(value.map(|v| serde_json::from_str(&v)).transpose()?)
+    }
+
+    async fn set(&self, key: &str, value: Value) -> Result<()> {
+        let key = key.to_owned();
+        let value = serde_json::to_string(&value)?;
+        self.run(move |c| {
+            c.execute(
+                "INSERT INTO state (key, value) VALUES (?1, ?2) \
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                params![key, value],
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let key = key.to_owned();
+        self.run(move |c| c.execute("DELETE FROM state WHERE key = ?1", [key]))
+            .await?;
+        Ok(())
+    }
+}
@@ -2,9 +2,27 @@
 /// registers all the given commands on it. Call it as in
 /// `create_framework!("bot_name", command1, command2, ...)`
 ///
+/// Groups of commands made using the `#[group]` macro can be registered after
+/// the commands, as in
+/// `create_framework!("bot_name", command1, ...; groups = [Group1, ...])`
+///
 /// [`Framework`]: framework/struct.Framework.html
 #[macro_export]
 macro_rules! create_framework {
+    ($bot_name:expr $(, $c:expr )* ; groups = [ $( $g:ident ),* $(,)? ]) => {
+        {
+            telexide_fork::paste_expr! {
+                let mut fr = telexide_fork::framework::Framework::new($bot_name);
+                $(
+                     fr.add_command(&(&[<$c _COMMAND>]));
+                )*
+                $(
+                     fr.add_group(&[<$g:snake:upper _GROUP>]);
+                )*
+                ::std::sync::Arc::new(fr)
+            }
+        }
+    };
     ($bot_name:expr, $( $c:expr ),*) => {
         {
             telexide_fork::paste_expr! {
@@ -15,5 +33,5 @@ macro_rules! create_framework {
                 ::std::sync::Arc::new(fr)
             }
         }
-    }
+    };
 }
@@ -1,4 +1,4 @@
-use crate::framework::types::CommandError;
+use crate::{dialogue::DialogueError, framework::types::CommandError, web_app::WebAppError};
 
 /// The common result type between most library functions.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -17,6 +17,12 @@ pub enum Error {
     JSON(serde_json::Error),
     /// An error happened in a command
     Command(CommandError),
+    /// A dialogue could not be started or continued
+    Dialogue(DialogueError),
+    /// An error from the state storage backend
+    Storage(String),
+    /// The init data of a Web App is invalid
+    WebApp(WebAppError),
 }
 
 /// An error enum returned by errors generated within the library itself
@@ -77,6 +83,9 @@ impl std::fmt::Display for Error {
             Error::HTTP(e) => std::fmt::Display::fmt(&e, f),
             Error::JSON(e) => std::fmt::Display::fmt(&e, f),
             Error::Command(e) => std::fmt::Display::fmt(&e.0, f),
+            Error::Dialogue(e) => std::fmt::Display::fmt(&e, f),
+            Error::Storage(e) => write!(f, "state storage error: {e}"),
+            Error::WebApp(e) => std::fmt::Display::fmt(&e, f),
         }
     }
 }
@@ -98,6 +107,9 @@ impl std::fmt::Debug for Error {
             Error::HTTP(e) => std::fmt::Debug::fmt(&e, f),
             Error::JSON(e) => std::fmt::Debug::fmt(&e, f),
             Error::Command(e) => std::fmt::Debug::fmt(&e, f),
+            Error::Dialogue(e) => std::fmt::Debug::fmt(&e, f),
+            Error::Storage(e) => f.debug_tuple("Storage").field(&e).finish(),
+            Error::WebApp(e) => std::fmt::Debug::fmt(&e, f),
         }
     }
 }
@@ -112,7 +124,9 @@ impl std::error::Error for Error {
             Error::IO(e) => e,
             Error::HTTP(e) => e,
             Error::JSON(e) => e,
-            Error::Command(_) => return None,
+            Error::Dialogue(e) => e,
+            Error::WebApp(e) => e,
+            Error::Command(_) | Error::Storage(_) => return None,
         })
     }
 }
@@ -123,6 +137,18 @@ impl From<TelegramError> for Error {
     }
 }
 
+impl From<DialogueError> for Error {
+    fn from(e: DialogueError) -> Self {
+        Self::Dialogue(e)
+    }
+}
+
+impl From<WebAppError> for Error {
+    fn from(e: WebAppError) -> Self {
+        Self::WebApp(e)
+    }
+}
+
 impl From<hyper::Error> for Error {
     fn from(e: hyper::Error) -> Self {
         Self::Hyper(e)
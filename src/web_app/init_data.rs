@@ -0,0 +1,198 @@
+use super::WebAppError;
+use crate::{
+    model::{utils::unix_date_formatting, ChatType},
+    Result,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use percent_encoding::percent_decode_str;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// The data that is transferred to a [Web App] when it is opened, available to
+/// it as `Telegram.WebApp.initData`. It should be sent to the server of the
+/// Web App and validated there using an [`InitDataValidator`] before being
+/// trusted.
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+/// [`InitDataValidator`]: struct.InitDataValidator.html
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebAppInitData {
+    /// A unique identifier for the Web App session, required for sending
+    /// messages via the [`answer_web_app_query`] method.
+    ///
+    /// [`answer_web_app_query`]: ../api/trait.API.html#method.answer_web_app_query
+    pub query_id: Option<String>,
+    /// An object containing data about the current user
+    pub user: Option<WebAppUser>,
+    /// An object containing data about the chat partner of the current user
+    /// in the chat where the bot was launched via the attachment menu. Returned
+    /// only for private chats and only for Web Apps launched via the
+    /// attachment menu.
+    pub receiver: Option<WebAppUser>,
+    /// An object containing data about the chat where the bot was launched via
+    /// the attachment menu. Returned for supergroups, channels and group chats
+    /// – only for Web Apps launched via the attachment menu.
+    pub chat: Option<WebAppChat>,
+    /// Type of the chat from which the Web App was opened. Returned only for
+    /// Web Apps launched from a direct link.
+    pub chat_type: Option<ChatType>,
+    /// Global identifier, uniquely corresponding to the chat from which the
+    /// Web App was opened. Returned only for Web Apps launched from a direct
+    /// link.
+    pub chat_instance: Option<String>,
+    /// The value of the startattach parameter, passed via link. Only returned
+    /// for Web Apps when launched from the attachment menu via link.
+    pub start_param: Option<String>,
+    /// Time in seconds, after which a message can be sent via the
+    /// [`answer_web_app_query`] method.
+    ///
+    /// [`answer_web_app_query`]: ../api/trait.API.html#method.answer_web_app_query
+    pub can_send_after: Option<i64>,
+    /// The date the Web App was opened
+    #[serde(with = "unix_date_formatting")]
+    pub auth_date: DateTime<Utc>,
+    /// A hash of all passed parameters, which the bot server can use to check
+    /// their validity
+    pub hash: Option<String>,
+    /// A signature of all passed parameters (except hash), which a third
+    /// party can use to check their validity
+    pub signature: Option<String>,
+}
+
+/// This object contains the data of the Web App user.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebAppUser {
+    /// A unique identifier for the user or bot
+    pub id: i64,
+    /// True, if this user is a bot. Returns in the receiver field only.
+    #[serde(default)]
+    pub is_bot: bool,
+    /// First name of the user or bot
+    pub first_name: String,
+    /// Last name of the user or bot
+    pub last_name: Option<String>,
+    /// Username of the user or bot
+    pub username: Option<String>,
+    /// [IETF language tag] of the user's language. Returns in user field only.
+    ///
+    /// [IETF language tag]: https://en.wikipedia.org/wiki/IETF_language_tag
+    pub language_code: Option<String>,
+    /// True, if this user is a Telegram Premium user
+    #[serde(default)]
+    pub is_premium: bool,
+    /// True, if this user added the bot to the attachment menu
+    #[serde(default)]
+    pub added_to_attachment_menu: bool,
+    /// True, if this user allowed the bot to message them
+    #[serde(default)]
+    pub allows_write_to_pm: bool,
+    /// URL of the user’s profile photo. The photo can be in .jpeg or .svg
+    /// formats.
+    pub photo_url: Option<String>,
+}
+
+/// This object represents a chat in the data of a Web App.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebAppChat {
+    /// Unique identifier for this chat
+    pub id: i64,
+    /// Type of chat
+    #[serde(rename = "type")]
+    pub chat_type: ChatType,
+    /// Title of the chat
+    pub title: String,
+    /// Username of the chat
+    pub username: Option<String>,
+    /// URL of the chat’s photo. The photo can be in .jpeg or .svg formats.
+    /// Only returned for Web Apps launched from the attachment menu.
+    pub photo_url: Option<String>,
+}
+
+impl WebAppInitData {
+    /// Parses the init data of a Web App without validating it, use an
+    /// [`InitDataValidator`] to make sure it was sent by Telegram.
+    ///
+    /// [`InitDataValidator`]: struct.InitDataValidator.html
+    pub fn parse(init_data: &str) -> Result<Self> {
+        Ok(Self::from_fields(&parse_fields(init_data)?)?)
+    }
+
+    pub(super) fn from_fields(
+        fields: &[(String, String)],
+    ) -> std::result::Result<Self, WebAppError> {
+        let get = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        let number = |key: &str| {
+            get(key)
+                .map(|v| {
+                    v.parse::<i64>()
+                        .map_err(|_| WebAppError::Malformed(format!("invalid {key}: {v}")))
+                })
+                .transpose()
+        };
+
+        let auth_date = number("auth_date")?
+            .ok_or_else(|| WebAppError::Malformed("missing auth_date".to_owned()))?;
+        let auth_date = Utc
+            .timestamp_opt(auth_date, 0)
+            .single()
+            .ok_or_else(|| WebAppError::Malformed(format!("invalid auth_date: {auth_date}")))?;
+
+        Ok(Self {
+            query_id: get("query_id").map(str::to_owned),
+            user: parse_json(get("user"), "user")?,
+            receiver: parse_json(get("receiver"), "receiver")?,
+            chat: parse_json(get("chat"), "chat")?,
+            chat_type: get("chat_type")
+                .map(|v| {
+                    serde_json::from_value(serde_json::Value::String(v.to_owned()))
+                        .map_err(|_| WebAppError::Malformed(format!("invalid chat_type: {v}")))
+                })
+                .transpose()?,
+            chat_instance: get("chat_instance").map(str::to_owned),
+            start_param: get("start_param").map(str::to_owned),
+            can_send_after: number("can_send_after")?,
+            auth_date,
+            hash: get("hash").map(str::to_owned),
+            signature: get("signature").map(str::to_owned),
+        })
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(
+    value: Option<&str>,
+    key: &str,
+) -> std::result::Result<Option<T>, WebAppError> {
+    value
+        .map(|v| {
+            serde_json::from_str(v)
+                .map_err(|e| WebAppError::Malformed(format!("invalid {key}: {e}")))
+        })
+        .transpose()
+}
+
+/// Splits the url encoded init data into its decoded key value pairs
+pub(super) fn parse_fields(
+    init_data: &str,
+) -> std::result::Result<Vec<(String, String)>, WebAppError> {
+    let decode = |s: &str| {
+        percent_decode_str(&s.replace('+', " "))
+            .decode_utf8()
+            .map(std::borrow::Cow::into_owned)
+            .map_err(|_| WebAppError::Malformed(format!("invalid encoding: {s}")))
+    };
+
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for pair in init_data.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let key = decode(key)?;
+        if fields.iter().any(|(k, _)| *k == key) {
+            return Err(WebAppError::Malformed(format!("duplicate field: {key}")));
+        }
+        fields.push((key, decode(value)?));
+    }
+    Ok(fields)
+}
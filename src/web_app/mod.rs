@@ -0,0 +1,60 @@
+//! Support for [Web Apps], also called Mini Apps, which are web pages opened
+//! inside of Telegram.
+//!
+//! Web Apps are opened from buttons with a [`WebAppInfo`], like
+//! [`InlineKeyboardButton::web_app`], or from the [`MenuButton`] of the bot.
+//! When opened, Telegram passes them init data describing the user and the
+//! chat, which the Web App should send to its server. Since a bad client can
+//! send anything, the server has to check that it was signed by Telegram
+//! using an [`InitDataValidator`] before trusting it.
+//!
+//! [Web Apps]: https://core.telegram.org/bots/webapps
+//! [`WebAppInfo`]: ../model/struct.WebAppInfo.html
+//! [`InlineKeyboardButton::web_app`]: ../model/struct.InlineKeyboardButton.html#method.web_app
+//! [`MenuButton`]: ../model/enum.MenuButton.html
+
+mod init_data;
+mod validator;
+
+pub use init_data::{WebAppChat, WebAppInitData, WebAppUser};
+pub use validator::{InitDataValidator, TELEGRAM_PUBLIC_KEY, TELEGRAM_TEST_PUBLIC_KEY};
+
+/// The error returned when the init data of a Web App is invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebAppError {
+    /// The init data could not be parsed
+    Malformed(String),
+    /// The init data has no hash
+    MissingHash,
+    /// The hash of the init data does not match the bot token
+    InvalidHash,
+    /// The init data has no signature
+    MissingSignature,
+    /// The signature of the init data does not match the public key
+    InvalidSignature,
+    /// The Web App was opened longer ago than the max age
+    Expired,
+    /// The init data claims the Web App was opened after the time it was
+    /// validated at
+    FutureAuthDate,
+}
+
+impl std::fmt::Display for WebAppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebAppError::Malformed(e) => write!(f, "malformed web app init data: {e}"),
+            WebAppError::MissingHash => f.write_str("the web app init data has no hash"),
+            WebAppError::InvalidHash => f.write_str("the web app init data has an invalid hash"),
+            WebAppError::MissingSignature => f.write_str("the web app init data has no signature"),
+            WebAppError::InvalidSignature => {
+                f.write_str("the web app init data has an invalid signature")
+            },
+            WebAppError::Expired => f.write_str("the web app init data has expired"),
+            WebAppError::FutureAuthDate => {
+                f.write_str("the web app init data has an auth_date in the future")
+            },
+        }
+    }
+}
+
+impl std::error::Error for WebAppError {}
@@ -0,0 +1,179 @@
+use super::{
+    init_data::{parse_fields, WebAppInitData},
+    WebAppError,
+};
+use crate::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use ring::{hmac, signature};
+use std::time::Duration;
+
+/// The public key Telegram signs the init data of Web Apps with, for
+/// validating it without the bot token
+pub const TELEGRAM_PUBLIC_KEY: &str =
+    "e7bf03a2fa4602af4580703d88dda5bb59f32ed8b02a56c187fe7d34caed242d";
+
+/// The public key Telegram signs the init data of Web Apps with on the test
+/// environment, for validating it without the bot token
+pub const TELEGRAM_TEST_PUBLIC_KEY: &str =
+    "40055058a4ee38156a06562e52eece92a771bcd8346a8c4615cb7376eddf72ec";
+
+// how far init data can seem to have been created in the future, as the
+// clock of the server can be a bit behind the one of Telegram
+const MAX_CLOCK_SKEW_SECS: i64 = 5;
+
+#[derive(Clone)]
+enum ValidationKey {
+    Token(hmac::Key),
+    PublicKey { bot_id: i64, key: Vec<u8> },
+}
+
+/// Validates that the init data received from a [Web App] was sent by Telegram
+/// and parses it into a [`WebAppInitData`].
+///
+/// The server of the bot itself validates the `hash` of the init data using
+/// the bot token, third parties that don't have the token validate the
+/// Ed25519 `signature` of the init data using the id of the bot instead.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use telexide_fork::web_app::InitDataValidator;
+///
+/// # fn main() -> telexide_fork::Result<()> {
+/// # let init_data = "";
+/// let mut validator = InitDataValidator::new("<bot token>");
+/// validator.set_max_age(Duration::from_secs(60 * 60));
+///
+/// let data = validator.validate(init_data)?;
+/// println!("opened by {:?}", data.user);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+#[derive(Clone)]
+pub struct InitDataValidator {
+    key: ValidationKey,
+    max_age: Option<Duration>,
+}
+
+impl InitDataValidator {
+    /// Creates a validator checking the `hash` of the init data using the
+    /// token of the bot
+    pub fn new(token: &str) -> Self {
+        let secret = hmac::sign(
+            &hmac::Key::new(hmac::HMAC_SHA256, b"WebAppData"),
+            token.as_bytes(),
+        );
+        Self {
+            key: ValidationKey::Token(hmac::Key::new(hmac::HMAC_SHA256, secret.as_ref())),
+            max_age: None,
+        }
+    }
+
+    /// Creates a validator checking the `signature` of the init data sent to
+    /// the bot with the given id, using the [`TELEGRAM_PUBLIC_KEY`]
+    pub fn third_party(bot_id: i64) -> Self {
+        Self::with_public_key(bot_id, &decode_hex(TELEGRAM_PUBLIC_KEY).unwrap_or_default())
+    }
+
+    /// Creates a validator checking the `signature` of the init data sent to
+    /// the bot with the given id, using the given Ed25519 public key, like the
+    /// [`TELEGRAM_TEST_PUBLIC_KEY`]
+    pub fn with_public_key(bot_id: i64, public_key: &[u8]) -> Self {
+        Self {
+            key: ValidationKey::PublicKey {
+                bot_id,
+                key: public_key.to_vec(),
+            },
+            max_age: None,
+        }
+    }
+
+    /// Sets how long ago the Web App can have been opened for its init data to
+    /// still be accepted, by default there is no limit
+    pub fn set_max_age(&mut self, max_age: Duration) -> &mut Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Gets how long ago the Web App can have been opened for its init data
+    /// to still be accepted
+    pub fn get_max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Validates the init data and parses it into a [`WebAppInitData`],
+    /// returning a [`WebAppError`] if it isn't valid
+    pub fn validate(&self, init_data: &str) -> Result<WebAppInitData> {
+        self.validate_at(init_data, Utc::now())
+    }
+
+    /// Validates the init data like [`validate`] does, checking its age as if
+    /// it's the given time. Init data that was created more than a few
+    /// seconds after that time is rejected.
+    ///
+    /// [`validate`]: #method.validate
+    pub fn validate_at(&self, init_data: &str, now: DateTime<Utc>) -> Result<WebAppInitData> {
+        let fields = parse_fields(init_data)?;
+        let get = |key: &str| fields.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+
+        // the fields are only parsed once they are known to be sent by Telegram
+        match &self.key {
+            ValidationKey::Token(key) => {
+                let hash = get("hash").ok_or(WebAppError::MissingHash)?;
+                let hash = decode_hex(hash).ok_or(WebAppError::InvalidHash)?;
+                hmac::verify(key, data_check_string(&fields, &["hash"]).as_bytes(), &hash)
+                    .map_err(|_| WebAppError::InvalidHash)?;
+            },
+            ValidationKey::PublicKey { bot_id, key } => {
+                let signature = get("signature").ok_or(WebAppError::MissingSignature)?;
+                let signature = URL_SAFE_NO_PAD
+                    .decode(signature.trim_end_matches('='))
+                    .map_err(|_| WebAppError::InvalidSignature)?;
+                let message = format!(
+                    "{bot_id}:WebAppData\n{}",
+                    data_check_string(&fields, &["hash", "signature"])
+                );
+                signature::UnparsedPublicKey::new(&signature::ED25519, key)
+                    .verify(message.as_bytes(), &signature)
+                    .map_err(|_| WebAppError::InvalidSignature)?;
+            },
+        }
+
+        let data = WebAppInitData::from_fields(&fields)?;
+        let age = now.signed_duration_since(data.auth_date);
+        if age < chrono::Duration::seconds(-MAX_CLOCK_SKEW_SECS) {
+            return Err(WebAppError::FutureAuthDate.into());
+        }
+        if let (Some(max_age), Ok(age)) = (self.max_age, age.to_std()) {
+            if age > max_age {
+                return Err(WebAppError::Expired.into());
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// Creates the string that is signed by Telegram, which are the fields sorted
+/// by their key, without the given ones, as `key=value` lines
+fn data_check_string(fields: &[(String, String)], without: &[&str]) -> String {
+    let mut fields: Vec<_> = fields
+        .iter()
+        .filter(|(k, _)| !without.contains(&k.as_str()))
+        .collect();
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    fields
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
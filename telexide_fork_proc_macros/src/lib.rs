@@ -10,14 +10,19 @@ extern crate proc_macro;
 
 use crate::structs::{CommandFunc, ListenerFunc};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::parse_macro_input;
-use utils::{add_suffix, PunctuatedNamedArgs};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Expr, ItemStruct};
+use utils::{
+    add_suffix, parse_cooldown, parse_scope, to_snake_case, NamedArgValue, NamedArgs,
+    PunctuatedNamedArgs,
+};
 
 /// A function attribute macro for making event listeners easier.
 ///
 /// This macro transforms an async function into a function returning a pinned box containing a future,
-/// which is used internally by telexide to store the function.
+/// which is used internally by telexide to store the function. It can also be used for preparing the
+/// state handlers of a dialogue flow, in which case the function returns the transition to make.
 #[proc_macro_attribute]
 pub fn prepare_listener(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let listener = parse_macro_input!(item as ListenerFunc);
@@ -44,28 +49,143 @@ pub fn prepare_listener(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// |-------------|----------------------------------|---------------------------------------------------------------------------------------------|
 /// | Description | description = "your description" | The description of the command as to be displayed in telegram, 3-256 characters             |
 /// | Name        | name = "the command name"        | The name to be used within telegram, 1-32 characters                                        |
+/// | Aliases     | aliases = ["alias", "other"]     | Other names the command can be called with, these are not shown in telegram                 |
+/// | Prefixes    | prefixes = ["!", "."]            | Other prefixes than `/` the command can be called with, like `!name`                        |
+/// | Mention     | mention = true                   | Allows calling the command by mentioning the bot, like `@bot_name name`                     |
+/// | Pattern     | pattern = "^roll (\\d+)"         | Calls the command for every message matching the regex, instead of by its name              |
+/// | Hidden      | hidden = true                    | Handles the command, without registering it in telegram                                     |
+/// | Scope       | scope = "all_private_chats"      | The scope(s) of users the command is shown to in telegram, a single one or a list of them   |
+/// | Language    | language_code = "en"             | The two-letter ISO 639-1 language code of the users the command is shown to in telegram     |
+/// | Checks      | checks = [AdminOnly, GroupOnly]  | The checks that have to pass for the command to be called, see the `Check` trait            |
+/// | Denial      | denial_reply = "not allowed!"    | The reply sent when a check fails instead of its reason, an empty string sends no reply     |
+/// | Cooldown    | cooldown = "10s per user"        | The time between two uses of the command, `per user`, `per chat` or `global`                |
+/// | On cooldown | cooldown_reply = "{remaining}"   | The reply sent when the command is on cooldown, `{remaining}` is replaced by the time left  |
+///
+/// The available scopes are `default`, `all_private_chats` (or `private`), `all_group_chats` (or `groups`),
+/// `all_chat_administrators` (or `group_admins`), `chat:<chat_id>`, `chat_administrators:<chat_id>` and
+/// `chat_member:<chat_id>:<user_id>`. The client will register the commands once for every scope and
+/// language they are given.
+///
+/// Prefixes and mentions are only recognised at the start of a message, while the framework itself
+/// can also be given prefixes and mention-as-prefix for all commands using `Framework::add_prefix`
+/// and `Framework::set_mention_prefix`. A command with a pattern is hidden, it is called for every
+/// message matching it and its typed arguments are parsed from the text following the match.
+///
+/// The duration of a cooldown is a number followed by `ms`, `s`, `m` or `h`. When no bucket is given,
+/// every user has their own cooldown. Without a `cooldown_reply`, uses of a command on cooldown are
+/// silently ignored.
+///
+/// # Arguments
+///
+/// Besides the `Context` and `Message`, a command can take typed arguments, which will be parsed from
+/// the text following the command using the `FromCommandArg` trait. Arguments are separated by
+/// whitespace, unless they are quoted. `Option<T>` arguments are optional and the `Rest` type takes
+/// all remaining text.
+/// ```rust,ignore
+/// #[command(description = "ban an user")]
+/// async fn ban(ctx: Context, message: Message, user: i64, reason: Option<Rest>) -> CommandResult { ... }
+/// ```
+///
+/// When the arguments cannot be parsed, the command won't be called and the user will instead get a
+/// reply telling them how to use the command, like `/ban <user> [reason...]`.
 ///
 /// # Notes
 ///
 /// - The description argument is required, because telegram requires it for a command to be displayed there.
 /// - The name argument defaults to the name of the command if not provided
+/// - Unknown options and values of the wrong type are compile errors
 #[proc_macro_attribute]
 pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
     let command_fun = parse_macro_input!(item as CommandFunc);
     let args: PunctuatedNamedArgs = parse_macro_input!(attr as PunctuatedNamedArgs);
+    expand_command(command_fun, args)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
 
+fn expand_command(
+    command_fun: CommandFunc,
+    args: PunctuatedNamedArgs,
+) -> syn::Result<TokenStream2> {
     let mut telegram_command_name = command_fun.name.to_string();
     let mut description = String::new();
+    let mut aliases: Vec<String> = Vec::new();
+    let mut prefixes: Vec<String> = Vec::new();
+    let mut mention = false;
+    let mut pattern = quote!(::std::option::Option::None);
+    let mut has_pattern = false;
+    let mut hidden = false;
+    let mut scopes = Vec::new();
+    let mut language_code = quote!(::std::option::Option::None);
+    let mut checks = Vec::new();
+    let mut denial_reply = quote!(::std::option::Option::None);
+    let mut cooldown = quote!(::std::option::Option::None);
+    let mut cooldown_reply = quote!(::std::option::Option::None);
 
     for arg in args.0 {
         match arg.name.as_str() {
-            "name" => telegram_command_name = arg.value.clone(),
-            "description" => description = arg.value.clone(),
-            _ => (),
+            "name" => {
+                telegram_command_name = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?
+            }
+            "description" => {
+                description = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?
+            }
+            "aliases" => {
+                aliases = arg
+                    .value
+                    .as_string_list()
+                    .ok_or_else(|| invalid_option(&arg))?
+            }
+            "prefixes" => {
+                prefixes = arg
+                    .value
+                    .as_string_list()
+                    .ok_or_else(|| invalid_option(&arg))?
+            }
+            "mention" => mention = arg.value.as_bool().ok_or_else(|| invalid_option(&arg))?,
+            "pattern" => {
+                let value = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?;
+                pattern = quote!(::std::option::Option::Some(#value));
+                has_pattern = true;
+            }
+            "hidden" => hidden = arg.value.as_bool().ok_or_else(|| invalid_option(&arg))?,
+            "scope" => {
+                scopes = arg
+                    .value
+                    .as_string_list()
+                    .ok_or_else(|| invalid_option(&arg))?
+                    .iter()
+                    .map(|s| parse_scope(s))
+                    .collect()
+            }
+            "language_code" => {
+                let code = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?;
+                language_code = quote!(::std::option::Option::Some(#code));
+            }
+            "checks" => match &arg.value {
+                NamedArgValue::List(items) => checks = items.clone(),
+                NamedArgValue::Literal(_) => return Err(invalid_option(&arg)),
+            },
+            "denial_reply" => {
+                let reply = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?;
+                denial_reply = quote!(::std::option::Option::Some(#reply));
+            }
+            "cooldown" => {
+                cooldown =
+                    parse_cooldown(&arg.value.as_string().ok_or_else(|| invalid_option(&arg))?)
+            }
+            "cooldown_reply" => {
+                let reply = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?;
+                cooldown_reply = quote!(::std::option::Option::Some(#reply));
+            }
+            _ => return Err(unknown_option(&arg)),
         }
     }
 
-    if description.len() < 3 {
+    // commands triggered by a pattern cannot be called as a telegram command
+    let hidden = hidden || has_pattern;
+
+    if description.len() < 3 && !hidden {
         panic!(
             "No description longer than 3 characters has been provided for the {} command, while descriptions are required by telegram",
             telegram_command_name
@@ -82,12 +202,34 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
     let command_struct_path = quote!(telexide_fork::framework::types::TelegramCommand);
     let options_struct_path = quote!(telexide_fork::framework::types::CommandOptions);
     let default_command_type_path = quote!(telexide_fork::framework::types::CommandTypes::Default);
+    let argument_struct_path = quote!(telexide_fork::framework::args::CommandArgument);
+    let from_arg_trait_path = quote!(telexide_fork::framework::FromCommandArg);
 
-    (quote! {
+    let argument_names = command_fun.typed_arg_names();
+    let argument_types = command_fun.typed_args.iter().map(|a| &a.ty);
+
+    Ok(quote! {
         #(#options_cooked)*
         pub static #options_name: #options_struct_path = #options_struct_path {
             name: #telegram_command_name,
             description: #description,
+            arguments: &[#(
+                #argument_struct_path {
+                    name: #argument_names,
+                    usage: <#argument_types as #from_arg_trait_path>::usage,
+                }
+            ),*],
+            aliases: &[#(#aliases),*],
+            prefixes: &[#(#prefixes),*],
+            mention: #mention,
+            pattern: #pattern,
+            hidden: #hidden,
+            scopes: &[#(#scopes),*],
+            language_code: #language_code,
+            checks: &[#(&#checks),*],
+            denial_reply: #denial_reply,
+            cooldown: #cooldown,
+            cooldown_reply: #cooldown_reply,
         };
 
         #(#command_cooked)*
@@ -98,5 +240,117 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
 
         #command_fun
     })
-    .into()
+}
+
+/// A struct attribute macro for making groups of commands.
+///
+/// This macro will create a group out of the given commands, which can then be added to your framework
+/// using the `create_framework!` macro in telexide itself, like
+/// `create_framework!("bot_name", command; groups = [Admin])`.
+///
+/// # Options
+///
+/// ```rust,ignore
+/// #[group(description = "commands for administrators", checks = [AdminOnly], commands = [ban, kick])]
+/// struct Admin;
+/// ```
+///
+/// | Option      | Usage                            | Description                                                                                 |
+/// |-------------|----------------------------------|---------------------------------------------------------------------------------------------|
+/// | Commands    | commands = [ban, kick]           | The commands in the group, created using the `#[command]` macro                             |
+/// | Name        | name = "admin"                   | The name of the group as shown in the help, defaults to the struct name in snake case       |
+/// | Description | description = "your description" | The description of the group, shown in the help and in telegram when it has a prefix        |
+/// | Prefix      | prefix = "config"                | Makes the commands subcommands of the prefix, called like `/config set key value`           |
+/// | Checks      | checks = [AdminOnly, GroupOnly]  | The checks that have to pass for any command of the group to be called                      |
+///
+/// # Notes
+///
+/// - The description argument is required when a prefix is given, because the prefix is registered as a
+///   command in telegram.
+/// - When a group with a prefix is called with an unknown subcommand, the help of the group is sent.
+/// - Unknown options and values of the wrong type are compile errors
+#[proc_macro_attribute]
+pub fn group(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let group_struct = parse_macro_input!(item as ItemStruct);
+    let args: PunctuatedNamedArgs = parse_macro_input!(attr as PunctuatedNamedArgs);
+    expand_group(group_struct, args)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_group(group_struct: ItemStruct, args: PunctuatedNamedArgs) -> syn::Result<TokenStream2> {
+    let snake_name = to_snake_case(&group_struct.ident);
+    let mut name = snake_name.clone();
+    let mut description = String::new();
+    let mut prefix = quote!(::std::option::Option::None);
+    let mut has_prefix = false;
+    let mut checks = Vec::new();
+    let mut commands = Vec::new();
+
+    for arg in args.0 {
+        match arg.name.as_str() {
+            "name" => name = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?,
+            "description" => {
+                description = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?
+            }
+            "prefix" => {
+                let value = arg.value.as_string().ok_or_else(|| invalid_option(&arg))?;
+                prefix = quote!(::std::option::Option::Some(#value));
+                has_prefix = true;
+            }
+            "checks" => match &arg.value {
+                NamedArgValue::List(items) => checks = items.clone(),
+                NamedArgValue::Literal(_) => return Err(invalid_option(&arg)),
+            },
+            "commands" => match &arg.value {
+                NamedArgValue::List(items) => {
+                    commands = items
+                        .iter()
+                        .map(|item| match item {
+                            Expr::Path(p) if p.path.get_ident().is_some() => {
+                                Ok(add_suffix(p.path.get_ident().unwrap(), "COMMAND"))
+                            }
+                            _ => Err(invalid_option(&arg)),
+                        })
+                        .collect::<syn::Result<_>>()?
+                }
+                NamedArgValue::Literal(_) => return Err(invalid_option(&arg)),
+            },
+            _ => return Err(unknown_option(&arg)),
+        }
+    }
+
+    if has_prefix && description.len() < 3 {
+        panic!(
+            "No description longer than 3 characters has been provided for the {} group, while descriptions are required by telegram for groups with a prefix",
+            name
+        )
+    }
+
+    let group_name = format_ident!("{}_GROUP", snake_name.to_uppercase());
+    let group_struct_path = quote!(telexide_fork::framework::types::CommandGroup);
+
+    Ok(quote! {
+        #[allow(dead_code)]
+        #group_struct
+
+        pub static #group_name: #group_struct_path = #group_struct_path {
+            name: #name,
+            description: #description,
+            prefix: #prefix,
+            checks: &[#(&#checks),*],
+            commands: &[#(&#commands),*],
+        };
+    })
+}
+
+fn invalid_option(arg: &NamedArgs) -> syn::Error {
+    syn::Error::new(
+        arg.span,
+        format!("invalid value provided for the {} option", arg.name),
+    )
+}
+
+fn unknown_option(arg: &NamedArgs) -> syn::Error {
+    syn::Error::new(arg.span, format!("unknown option {}", arg.name))
 }
@@ -1,8 +1,11 @@
-use super::utils::ParenthesisedItems;
+use super::utils::{add_suffix, ParenthesisedItems};
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Parse, ParseStream, Result};
-use syn::{braced, Attribute, Block, FnArg, Ident, ReturnType, Stmt, Token, Type, Visibility};
+use syn::{
+    braced, spanned::Spanned, Attribute, Block, Error, FnArg, Ident, Pat, PatType, ReturnType,
+    Stmt, Token, Type, Visibility,
+};
 
 #[derive(Debug)]
 pub struct ListenerFunc {
@@ -13,6 +16,7 @@ pub struct ListenerFunc {
     pub visibility: Visibility,
     pub name: Ident,
     pub args: Vec<FnArg>,
+    pub ret: Type,
     pub body: Vec<Stmt>,
 }
 
@@ -31,9 +35,9 @@ impl Parse for ListenerFunc {
 
         let ParenthesisedItems(args) = input.parse::<ParenthesisedItems<FnArg>>()?;
 
-        match input.parse::<ReturnType>()? {
-            ReturnType::Type(_, _) => return Err(input.error("expected a default return value")),
-            ReturnType::Default => (),
+        let ret = match input.parse::<ReturnType>()? {
+            ReturnType::Type(_, t) => *t,
+            ReturnType::Default => syn::parse_quote!(()),
         };
 
         let body_content;
@@ -48,6 +52,7 @@ impl Parse for ListenerFunc {
             visibility,
             name,
             args,
+            ret,
             body,
         })
     }
@@ -61,12 +66,13 @@ impl ToTokens for ListenerFunc {
             visibility,
             name,
             args,
+            ret,
             body,
         } = self;
 
         stream.extend(quote! {
             #(#cooked)*
-            #visibility fn #name (#(#args),*) -> ::std::pin::Pin<::std::boxed::Box<(dyn ::std::future::Future<Output = ()> + ::std::marker::Send )>> {
+            #visibility fn #name (#(#args),*) -> ::std::pin::Pin<::std::boxed::Box<(dyn ::std::future::Future<Output = #ret> + ::std::marker::Send )>> {
                 ::std::boxed::Box::pin(async move {
                     #(#body)*
             })
@@ -85,6 +91,9 @@ pub struct CommandFunc {
     pub name: Ident,
     pub ret: Type,
     pub args: Vec<FnArg>,
+    /// The typed arguments to be parsed from the command text, following the
+    /// context and message arguments.
+    pub typed_args: Vec<PatType>,
     pub body: Vec<Stmt>,
 }
 
@@ -112,7 +121,26 @@ impl Parse for CommandFunc {
         braced!(body_content in input);
         let body: Vec<Stmt> = body_content.call(Block::parse_within)?;
 
-        let args = args.into_iter().collect::<Vec<FnArg>>();
+        let mut args = args.into_iter().collect::<Vec<FnArg>>();
+        let typed_args = if args.len() > 2 {
+            if let Some(FnArg::Receiver(r)) = args.iter().find(|a| matches!(a, FnArg::Receiver(_)))
+            {
+                return Err(Error::new(r.span(), "commands cannot take self"));
+            }
+
+            args.split_off(2)
+                .into_iter()
+                .map(|arg| match arg {
+                    FnArg::Typed(t) if matches!(*t.pat, Pat::Ident(_)) => Ok(t),
+                    arg => Err(Error::new(
+                        arg.span(),
+                        "expected a named and typed command argument",
+                    )),
+                })
+                .collect::<Result<Vec<PatType>>>()?
+        } else {
+            Vec::new()
+        };
 
         Ok(Self {
             attributes,
@@ -121,11 +149,26 @@ impl Parse for CommandFunc {
             name,
             ret,
             args,
+            typed_args,
             body,
         })
     }
 }
 
+impl CommandFunc {
+    /// The names of the typed arguments, as displayed in the usage of the
+    /// command
+    pub fn typed_arg_names(&self) -> Vec<String> {
+        self.typed_args
+            .iter()
+            .map(|arg| match &*arg.pat {
+                Pat::Ident(i) => i.ident.to_string().trim_start_matches('_').to_owned(),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+}
+
 impl ToTokens for CommandFunc {
     fn to_tokens(&self, stream: &mut TokenStream2) {
         let Self {
@@ -135,9 +178,47 @@ impl ToTokens for CommandFunc {
             ret,
             name,
             args,
+            typed_args,
             body,
         } = self;
 
+        if !typed_args.is_empty() {
+            let options_name = add_suffix(name, "COMMAND_OPTIONS");
+            let ctx = format_ident!("__telexide_context");
+            let msg = format_ident!("__telexide_message");
+            let parsed_args = format_ident!("__telexide_args");
+
+            let (ctx_pat, ctx_type) = split_arg(&args[0]);
+            let (msg_pat, msg_type) = split_arg(&args[1]);
+            let arg_pats = typed_args.iter().map(|a| &a.pat);
+            let arg_types = typed_args.iter().map(|a| &a.ty);
+
+            stream.extend(quote! {
+                #(#cooked)*
+                #visibility fn #name (#ctx: #ctx_type, #msg: #msg_type) -> ::std::pin::Pin<::std::boxed::Box<(dyn ::std::future::Future<Output = #ret> + ::std::marker::Send )>> {
+                    ::std::boxed::Box::pin(async move {
+                        let mut #parsed_args = telexide_fork::framework::args::Args::for_command(&#ctx, &#msg, &#options_name);
+                        let parsed: ::std::result::Result<_, telexide_fork::framework::ArgError> = (|| ::std::result::Result::Ok((
+                            #(#parsed_args.parse::<#arg_types>()?,)*
+                            #parsed_args.finish()?,
+                        )))();
+                        let (#(#arg_pats,)* ()) = match parsed {
+                            ::std::result::Result::Ok(a) => a,
+                            ::std::result::Result::Err(e) => {
+                                telexide_fork::framework::args::reply_usage(&#ctx, &#msg, &#options_name, &e).await?;
+                                return ::std::result::Result::Ok(());
+                            }
+                        };
+                        let #ctx_pat = #ctx;
+                        let #msg_pat = #msg;
+
+                        #(#body)*
+                    })
+                }
+            });
+            return;
+        }
+
         stream.extend(quote! {
             #(#cooked)*
             #visibility fn #name (#(#args),*) -> ::std::pin::Pin<::std::boxed::Box<(dyn ::std::future::Future<Output = #ret> + ::std::marker::Send )>> {
@@ -148,3 +229,14 @@ impl ToTokens for CommandFunc {
         });
     }
 }
+
+fn split_arg(arg: &FnArg) -> (TokenStream2, TokenStream2) {
+    match arg {
+        FnArg::Typed(t) => {
+            let pat = &t.pat;
+            let ty = &t.ty;
+            (quote!(#pat), quote!(#ty))
+        }
+        FnArg::Receiver(_) => unreachable!("receivers are rejected while parsing"),
+    }
+}
@@ -1,11 +1,11 @@
-use proc_macro2::{Ident, Literal};
-use quote::format_ident;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 use syn::{
-    parenthesized,
+    bracketed, parenthesized,
     parse::{Parse, ParseStream, Result},
     punctuated::Punctuated,
     token::Comma,
-    Token,
+    Expr, Lit, Token,
 };
 
 pub struct ParenthesisedItems<T>(pub Punctuated<T, Comma>);
@@ -28,21 +28,165 @@ impl Parse for PunctuatedNamedArgs {
 
 pub struct NamedArgs {
     pub name: String,
-    pub value: String,
+    /// The span of the name, which errors about the option point at
+    pub span: Span,
+    pub value: NamedArgValue,
+}
+
+/// The value of a named argument, either a single literal or a list of
+/// expressions wrapped in brackets
+pub enum NamedArgValue {
+    Literal(Lit),
+    List(Vec<Expr>),
+}
+
+impl NamedArgValue {
+    /// The value of a string literal
+    pub fn as_string(&self) -> Option<String> {
+        match self {
+            Self::Literal(Lit::Str(s)) => Some(s.value()),
+            _ => None,
+        }
+    }
+
+    /// The value as a list of string literals, a single string literal being
+    /// treated as a list of one
+    pub fn as_string_list(&self) -> Option<Vec<String>> {
+        match self {
+            Self::Literal(Lit::Str(s)) => Some(vec![s.value()]),
+            Self::Literal(_) => None,
+            Self::List(items) => items
+                .iter()
+                .map(|e| match e {
+                    Expr::Lit(l) => match &l.lit {
+                        Lit::Str(s) => Some(s.value()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// The value of a boolean literal
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Literal(Lit::Bool(b)) => Some(b.value),
+            _ => None,
+        }
+    }
 }
 
 impl Parse for NamedArgs {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let name = input.parse::<Ident>()?.to_string();
+        let ident = input.parse::<Ident>()?;
         input.parse::<Token![=]>()?;
-        let mut value = input.parse::<Literal>()?.to_string();
-        value = value.trim_start_matches('\"').to_owned();
-        value = value.trim_end_matches('\"').to_owned();
 
-        Ok(Self { name, value })
+        let value = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            NamedArgValue::List(
+                content
+                    .parse_terminated::<Expr, Comma>(Expr::parse)?
+                    .into_iter()
+                    .collect(),
+            )
+        } else {
+            NamedArgValue::Literal(input.parse::<Lit>()?)
+        };
+
+        Ok(Self {
+            name: ident.to_string(),
+            span: ident.span(),
+            value,
+        })
     }
 }
 
 pub fn add_suffix(ident: &Ident, suffix: &str) -> Ident {
     format_ident!("{}_{}", ident.to_string(), suffix)
 }
+
+/// Turns a command scope as written in the `#[command]` options into the
+/// matching `BotCommandScope`
+pub fn parse_scope(scope: &str) -> TokenStream2 {
+    let path = quote!(telexide_fork::api::types::BotCommandScope);
+    let parts: Vec<&str> = scope.split(':').map(str::trim).collect();
+    let id = |i: usize| -> i64 {
+        parts
+            .get(i)
+            .and_then(|p| p.parse().ok())
+            .unwrap_or_else(|| panic!("the {} command scope requires a valid id", scope))
+    };
+
+    match parts[0] {
+        "default" => quote!(#path::Default),
+        "all_private_chats" | "private" => quote!(#path::AllPrivateChats),
+        "all_group_chats" | "groups" => quote!(#path::AllGroupChats),
+        "all_chat_administrators" | "group_admins" => quote!(#path::AllChatAdministrators),
+        "chat" => {
+            let chat_id = id(1);
+            quote!(#path::Chat { chat_id: #chat_id })
+        }
+        "chat_administrators" => {
+            let chat_id = id(1);
+            quote!(#path::ChatAdministrators { chat_id: #chat_id })
+        }
+        "chat_member" => {
+            let chat_id = id(1);
+            let user_id = id(2);
+            quote!(#path::ChatMember { chat_id: #chat_id, user_id: #user_id })
+        }
+        _ => panic!("unknown command scope: {}", scope),
+    }
+}
+
+/// Turns a cooldown as written in the `#[command]` options, like
+/// `10s per user`, into the matching `Cooldown`
+pub fn parse_cooldown(cooldown: &str) -> TokenStream2 {
+    let invalid = || -> ! { panic!("invalid command cooldown: {}", cooldown) };
+    let mut parts = cooldown.split_whitespace();
+
+    let duration = parts.next().unwrap_or_else(|| invalid());
+    let unit_start = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let amount: u64 = duration[..unit_start].parse().unwrap_or_else(|_| invalid());
+    let millis = match &duration[unit_start..] {
+        "ms" => amount,
+        "s" | "" => amount * 1000,
+        "m" => amount * 60_000,
+        "h" => amount * 3_600_000,
+        _ => invalid(),
+    };
+
+    let bucket = match (parts.next(), parts.next(), parts.next()) {
+        (None, ..) => quote!(User),
+        (Some("per"), Some("user"), None) => quote!(User),
+        (Some("per"), Some("chat"), None) => quote!(Chat),
+        (Some("global"), None, _) | (Some("per"), Some("global"), None) => quote!(Global),
+        _ => invalid(),
+    };
+
+    quote! {
+        ::std::option::Option::Some(telexide_fork::framework::Cooldown {
+            duration: ::std::time::Duration::from_millis(#millis),
+            bucket: telexide_fork::framework::CooldownBucket::#bucket,
+        })
+    }
+}
+
+/// Converts an ident to snake case the same way the `:snake` modifier of
+/// `paste` does, so `create_framework!` can find the statics generated for it
+pub fn to_snake_case(ident: &Ident) -> String {
+    let mut snake = String::new();
+    let mut prev = '_';
+    for c in ident.to_string().chars() {
+        if c.is_uppercase() && prev != '_' {
+            snake.push('_');
+        }
+        snake.push(c);
+        prev = c;
+    }
+    snake.to_lowercase()
+}
@@ -0,0 +1,36 @@
+use telexide_fork::framework::{ArgError, Args, Rest};
+
+#[test]
+fn parse_typed_args() -> Result<(), ArgError> {
+    let mut args = Args::new(" 42 \"quoted argument\" yes and the rest ");
+
+    assert_eq!(args.parse::<i64>()?, 42);
+    assert_eq!(args.parse::<String>()?, "quoted argument");
+    assert!(args.parse::<bool>()?);
+    assert_eq!(args.parse::<Rest>()?, Rest("and the rest".to_owned()));
+    assert_eq!(args.parse::<Option<u8>>()?, None);
+    args.finish()
+}
+
+#[test]
+fn parse_mixed_quotes() -> Result<(), ArgError> {
+    let mut args = Args::new("“a \"b” \"c ”d\" e");
+
+    assert_eq!(args.parse::<String>()?, "a \"b");
+    assert_eq!(args.parse::<String>()?, "c ”d");
+    assert_eq!(args.parse::<String>()?, "e");
+    args.finish()
+}
+
+#[test]
+fn parse_invalid_args() {
+    let mut args = Args::new("abc");
+    assert!(matches!(args.parse::<i32>(), Err(ArgError::Invalid(_))));
+    assert_eq!(args.parse::<i32>(), Err(ArgError::Missing));
+
+    let mut args = Args::new("“unclosed quote");
+    assert_eq!(args.parse::<String>(), Err(ArgError::UnclosedQuote));
+
+    let args = Args::new("left over");
+    assert_eq!(args.finish(), Err(ArgError::TooMany));
+}
@@ -0,0 +1,302 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use telexide_fork::{
+    callback::{
+        encode_callback_data,
+        menus::{Calendar, Checklist, ConfirmDialog, Confirmation, MenuItem, PaginatedList},
+        Callback, CallbackData, CallbackRouter,
+    },
+    client::{ClientBuilder, Context},
+    framework::CommandResult,
+    macros::prepare_listener,
+    model::{CallbackQuery, InlineKeyboardMarkup, Update, UpdateContent, User},
+    Result,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Vote {
+    poll: u32,
+    up: bool,
+}
+
+impl CallbackData for Vote {
+    const PREFIX: &'static str = "vote";
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+enum Menu {
+    Open { page: u8, name: String },
+    Close,
+}
+
+impl CallbackData for Menu {
+    const PREFIX: &'static str = "menu";
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Refresh;
+
+impl CallbackData for Refresh {
+    const PREFIX: &'static str = "refresh";
+}
+
+#[test]
+fn encode_callback_data_compactly() -> Result<()> {
+    let vote = Vote { poll: 12, up: true };
+    assert_eq!(vote.encode()?, "vote:[12,true]");
+    assert_eq!(Vote::decode("vote:[12,true]"), Some(vote));
+
+    let open = Menu::Open {
+        page: 2,
+        name: "settings".to_owned(),
+    };
+    assert_eq!(open.encode()?, r#"menu:{"Open":[2,"settings"]}"#);
+    assert_eq!(Menu::decode(&open.encode()?), Some(open));
+    assert_eq!(Menu::decode(&Menu::Close.encode()?), Some(Menu::Close));
+
+    assert_eq!(Refresh.encode()?, "refresh");
+    assert_eq!(Refresh::decode("refresh"), Some(Refresh));
+
+    assert_eq!(Vote::decode("vote:[12]"), None);
+    assert_eq!(Vote::decode("voter:[12,true]"), None);
+    assert!(encode_callback_data("long", &"a".repeat(64)).is_err());
+
+    let button = Vote { poll: 1, up: false }.button("down")?;
+    assert_eq!(button.text, "down");
+    assert_eq!(button.callback_data.as_deref(), Some("vote:[1,false]"));
+    Ok(())
+}
+
+static VOTE_B: AtomicUsize = AtomicUsize::new(0);
+static PAGE_B: AtomicUsize = AtomicUsize::new(0);
+
+#[prepare_listener]
+async fn vote(_c: Context, callback: Callback, vote: Vote) -> CommandResult {
+    assert_eq!(callback.query.from.id, 60);
+    if vote.up {
+        VOTE_B.fetch_add(vote.poll as usize, Ordering::Acquire);
+    }
+    Ok(())
+}
+
+#[prepare_listener]
+async fn page(_c: Context, callback: Callback, page: String) -> CommandResult {
+    // a query that could not be answered can be answered again
+    assert!(callback.answer().await.is_err());
+    assert!(!callback.is_answered());
+
+    PAGE_B.fetch_add(page.parse()?, Ordering::Acquire);
+    Ok(())
+}
+
+fn callback_query(data: &str) -> Update {
+    Update {
+        update_id: 10,
+        content: UpdateContent::CallbackQuery(CallbackQuery {
+            id: "query".to_owned(),
+            from: User {
+                id: 60,
+                is_bot: false,
+                first_name: "test".to_owned(),
+                last_name: None,
+                username: None,
+                language_code: None,
+                can_join_groups: None,
+                can_read_all_group_messages: None,
+                supports_inline_queries: None,
+            },
+            message: None,
+            inline_message_id: None,
+            chat_instance: "instance".to_owned(),
+            data: Some(data.to_owned()),
+            game_short_name: None,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn test_using_callback_router() -> Result<()> {
+    let mut router = CallbackRouter::new();
+    router.add_handler(vote).add_prefix_handler("page", page);
+    assert_eq!(router.get_prefixes(), vec!["vote", "page"]);
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_callback_router(Arc::new(router))
+        .build();
+
+    for data in [
+        "vote:[5,true]",
+        "vote:[7,false]",
+        "vote:invalid",
+        "page:3",
+        "pages:4",
+        "other",
+    ] {
+        c.fire_handlers(callback_query(data));
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(VOTE_B.load(Ordering::Relaxed), 5);
+    assert_eq!(PAGE_B.load(Ordering::Relaxed), 3);
+    Ok(())
+}
+
+static FRUIT_B: AtomicUsize = AtomicUsize::new(0);
+static CONFIRM_B: AtomicUsize = AtomicUsize::new(0);
+static CHECKLIST_B: AtomicUsize = AtomicUsize::new(0);
+static DATE_B: AtomicUsize = AtomicUsize::new(0);
+
+fn fruit_items() -> Vec<MenuItem> {
+    ["apple", "pear", "plum", "kiwi", "fig"]
+        .iter()
+        .map(|f| MenuItem::new(&f.to_uppercase(), f))
+        .collect()
+}
+
+#[prepare_listener]
+async fn fruits(_c: Context, _callback: Callback) -> Result<Vec<MenuItem>> {
+    Ok(fruit_items())
+}
+
+#[prepare_listener]
+async fn fruit_picked(_c: Context, _callback: Callback, fruit: String) -> CommandResult {
+    if fruit == "plum" {
+        FRUIT_B.fetch_add(1, Ordering::Acquire);
+    }
+    Ok(())
+}
+
+#[prepare_listener]
+async fn confirmed(_c: Context, _callback: Callback, answer: Confirmation) -> CommandResult {
+    if answer.confirmed {
+        CONFIRM_B.fetch_add(answer.payload.parse()?, Ordering::Acquire);
+    }
+    Ok(())
+}
+
+#[prepare_listener]
+async fn fruits_checked(_c: Context, _callback: Callback, items: Vec<MenuItem>) -> CommandResult {
+    let values: Vec<_> = items.into_iter().map(|i| i.value).collect();
+    if values == ["pear", "fig"] {
+        CHECKLIST_B.fetch_add(1, Ordering::Acquire);
+    }
+    Ok(())
+}
+
+#[prepare_listener]
+async fn date_picked(_c: Context, _callback: Callback, date: NaiveDate) -> CommandResult {
+    DATE_B.fetch_add(date.day() as usize, Ordering::Acquire);
+    Ok(())
+}
+
+fn button_data(markup: &InlineKeyboardMarkup) -> Vec<Vec<String>> {
+    markup
+        .inline_keyboard
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|b| b.callback_data.clone().unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn render_menus() -> Result<()> {
+    let mut list = PaginatedList::new("fruit", fruits, fruit_picked);
+    list.set_page_size(2);
+    assert_eq!(list.page_count(5), 3);
+    assert_eq!(
+        button_data(&list.render(&fruit_items(), 1)?),
+        vec![
+            vec!["fruit:s:plum"],
+            vec!["fruit:s:kiwi"],
+            vec!["fruit:p:0", "fruit:i", "fruit:p:2"],
+        ]
+    );
+    assert_eq!(
+        button_data(&list.render(&fruit_items(), 7)?),
+        vec![vec!["fruit:s:fig"], vec!["fruit:p:1", "fruit:i"]]
+    );
+
+    let confirm = ConfirmDialog::new("delete", confirmed);
+    assert_eq!(
+        button_data(&confirm.render("12")?),
+        vec![vec!["delete:y:12", "delete:n:12"]]
+    );
+
+    let checklist = Checklist::new("check", fruits, fruits_checked);
+    let markup = checklist.render(&fruit_items()[..2], &[1])?;
+    assert_eq!(markup.inline_keyboard[1][0].text, "✅ PEAR");
+    assert_eq!(
+        button_data(&markup),
+        vec![vec!["check:t:3"], vec!["check:t:0"], vec!["check:d:2"]]
+    );
+
+    let mut calendar = Calendar::new("date", date_picked);
+    calendar.set_min_date(NaiveDate::from_ymd_opt(2024, 2, 10).expect("invalid date"));
+    let markup = calendar.render(NaiveDate::from_ymd_opt(2024, 2, 20).expect("invalid date"))?;
+    assert_eq!(markup.inline_keyboard[0][0].text, "February 2024");
+    // the 1st of February 2024 is a Thursday, 29 days and 3 blanks make 5 weeks
+    assert_eq!(markup.inline_keyboard.len(), 2 + 5 + 1);
+    assert_eq!(
+        markup.inline_keyboard[2][3].callback_data.as_deref(),
+        Some("date:i")
+    );
+    assert_eq!(
+        markup.inline_keyboard[3][5].callback_data.as_deref(),
+        Some("date:d:2024-02-10")
+    );
+    assert_eq!(
+        button_data(&markup)[7],
+        vec!["date:i", "date:i", "date:m:2024-03"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_menus() -> Result<()> {
+    let mut confirm = ConfirmDialog::new("delete", confirmed);
+    confirm.set_remove_keyboard(false);
+    let mut calendar = Calendar::new("date", date_picked);
+    calendar.set_max_date(NaiveDate::from_ymd_opt(2024, 2, 10).expect("invalid date"));
+
+    let mut router = CallbackRouter::new();
+    router
+        .add_menu(PaginatedList::new("fruit", fruits, fruit_picked))
+        .add_menu(confirm)
+        .add_menu(Checklist::new("check", fruits, fruits_checked))
+        .add_menu(calendar);
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_callback_router(Arc::new(router))
+        .build();
+
+    for data in [
+        "fruit:s:plum",
+        "fruit:i",
+        "delete:y:12",
+        "delete:n:30",
+        "check:d:12",
+        "date:d:2024-02-07",
+        "date:d:2024-02-11",
+    ] {
+        c.fire_handlers(callback_query(data));
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(FRUIT_B.load(Ordering::Relaxed), 1);
+    assert_eq!(CONFIRM_B.load(Ordering::Relaxed), 12);
+    assert_eq!(CHECKLIST_B.load(Ordering::Relaxed), 1);
+    assert_eq!(DATE_B.load(Ordering::Relaxed), 7);
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 use telexide_fork::{
+    api::types::{ApproveChatJoinRequest, UpdateType},
     client::{ClientBuilder, Context},
-    model::{Update, UpdateContent},
+    macros::prepare_listener,
+    model::{ChatJoinRequest, MessageReactionUpdated, ReactionType, Update, UpdateContent},
     Result,
 };
 
@@ -54,3 +56,164 @@ async fn test_using_func() -> Result<()> {
     assert_eq!(FUNC_B.load(Ordering::Relaxed), 10);
     Ok(())
 }
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+struct Multiplier(usize);
+
+fn counting_func(
+    c: Context,
+    u: Update,
+) -> ::std::pin::Pin<Box<dyn Send + ::std::future::Future<Output = ()>>> {
+    ::std::boxed::Box::pin(async move {
+        let multiplier = c.try_get::<Multiplier>().map_or(1, |m| m.0);
+        c.get::<Counter>()
+            .0
+            .fetch_add(u.update_id as usize * multiplier, Ordering::Acquire);
+    })
+}
+
+#[tokio::test]
+async fn test_using_extensions() -> Result<()> {
+    let mut c = ClientBuilder::new()
+        .set_token("test")
+        .add_extension(Counter::default())
+        .add_handler_func(counting_func)
+        .build();
+
+    c.fire_handlers(Update {
+        update_id: 10,
+        content: UpdateContent::Unknown,
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    c.add_extension(Multiplier(3));
+    c.fire_handlers(Update {
+        update_id: 10,
+        content: UpdateContent::Unknown,
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    let counter = c.extensions.get::<Counter>().expect("no counter");
+    assert_eq!(counter.0.load(Ordering::Relaxed), 40);
+    assert!(c.extensions.get::<String>().is_none());
+    Ok(())
+}
+
+static REACTION_B: AtomicUsize = AtomicUsize::new(0);
+
+#[prepare_listener]
+async fn reaction_listener(_c: Context, reaction: MessageReactionUpdated) {
+    if reaction.added_reactions().len() == 2
+        && reaction.removed_reactions() == vec![&ReactionType::emoji("👍")]
+    {
+        REACTION_B.fetch_add(reaction.message_id as usize, Ordering::Acquire);
+    }
+}
+
+#[tokio::test]
+async fn test_reaction_handlers() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .add_allowed_updates(UpdateType::Message)
+        .add_reaction_handler_func(reaction_listener)
+        .build();
+    assert!(c.allowed_updates.contains(&UpdateType::MessageReaction));
+    assert!(!c
+        .allowed_updates
+        .contains(&UpdateType::MessageReactionCount));
+
+    let update: Update = serde_json::from_str(
+        r#"{
+            "update_id": 10,
+            "message_reaction": {
+                "chat": {"id": -100, "type": "supergroup", "title": "test"},
+                "message_id": 30,
+                "user": {"id": 60, "is_bot": false, "first_name": "test"},
+                "date": 1585772722,
+                "old_reaction": [{"type": "emoji", "emoji": "👍"}],
+                "new_reaction": [
+                    {"type": "emoji", "emoji": "🔥"},
+                    {"type": "custom_emoji", "custom_emoji_id": "5368324170671202286"}
+                ]
+            }
+        }"#,
+    )?;
+    if let UpdateContent::MessageReaction(r) = &update.content {
+        assert_eq!(
+            r.new_reaction[1],
+            ReactionType::custom_emoji("5368324170671202286")
+        );
+    } else {
+        panic!("no message reaction")
+    }
+
+    c.fire_handlers(update.clone());
+    c.fire_handlers(Update {
+        update_id: 11,
+        content: UpdateContent::Unknown,
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(REACTION_B.load(Ordering::Relaxed), 30);
+    Ok(())
+}
+
+static JOIN_REQUEST_B: AtomicUsize = AtomicUsize::new(0);
+
+#[prepare_listener]
+async fn join_request_listener(_c: Context, request: ChatJoinRequest) {
+    let approve = ApproveChatJoinRequest::from(&request);
+    if approve.chat_id == -100 && request.invite_link.is_some_and(|l| l.creates_join_request) {
+        JOIN_REQUEST_B.fetch_add(approve.user_id as usize, Ordering::Acquire);
+    }
+}
+
+#[tokio::test]
+async fn test_join_request_handlers() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .add_allowed_updates(UpdateType::Message)
+        .add_join_request_handler_func(join_request_listener)
+        .build();
+    assert!(c.allowed_updates.contains(&UpdateType::ChatJoinRequest));
+
+    let update: Update = serde_json::from_str(
+        r#"{
+            "update_id": 10,
+            "chat_join_request": {
+                "chat": {"id": -100, "type": "supergroup", "title": "test"},
+                "from": {"id": 60, "is_bot": false, "first_name": "test"},
+                "user_chat_id": 60,
+                "date": 1585772722,
+                "bio": "hello",
+                "invite_link": {
+                    "invite_link": "https://t.me/+AbCdEf...",
+                    "creator": {"id": 1, "is_bot": true, "first_name": "bot"},
+                    "creates_join_request": true,
+                    "is_primary": false,
+                    "is_revoked": false,
+                    "name": "captcha",
+                    "pending_join_request_count": 3
+                }
+            }
+        }"#,
+    )?;
+    if let UpdateContent::ChatJoinRequest(r) = &update.content {
+        let link = r
+            .invite_link
+            .as_ref()
+            .expect("the request has an invite link");
+        assert_eq!(link.name.as_deref(), Some("captcha"));
+        assert_eq!(link.pending_join_request_count, Some(3));
+    } else {
+        panic!("no chat join request")
+    }
+
+    c.fire_handlers(update);
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(JOIN_REQUEST_B.load(Ordering::Relaxed), 60);
+    Ok(())
+}
@@ -0,0 +1,226 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use telexide_fork::{
+    client::{ClientBuilder, Context},
+    dialogue::{Dialogue, DialogueFlow, DialogueInput, DialogueKey, Transition},
+    framework::CommandResult,
+    macros::{command, create_framework, prepare_listener},
+    model::{
+        Chat, Message, MessageContent, MessageEntity, PrivateChat, TextBlock, Update, UpdateContent,
+    },
+    storage::{dialogue_key, MemoryStorage, StateStorage},
+    Result,
+};
+
+static ORDER_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "starts an order")]
+async fn order(ctx: Context, m: Message) -> CommandResult {
+    ctx.start_dialogue(m, "order")?;
+    Ok(())
+}
+
+#[prepare_listener]
+async fn order_start(_c: Context, _d: Dialogue, _i: DialogueInput) -> Result<Transition> {
+    Ok(Transition::next("amount"))
+}
+
+#[prepare_listener]
+async fn order_amount(_c: Context, d: Dialogue, input: DialogueInput) -> Result<Transition> {
+    let amount: usize = match input.get_text().and_then(|t| t.parse().ok()) {
+        Some(amount) => amount,
+        None => return Ok(Transition::Stay),
+    };
+    d.set("amount", &amount)?;
+
+    let confirmation = d.next_message().await?;
+    if confirmation.get_text().as_deref() == Some("yes") {
+        ORDER_B.fetch_add(
+            d.get::<usize>("amount").unwrap_or_default(),
+            Ordering::Acquire,
+        );
+    }
+    Ok(Transition::Finish)
+}
+
+static SLOW_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "starts a slow dialogue")]
+async fn slow(ctx: Context, m: Message) -> CommandResult {
+    ctx.start_dialogue(m, "slow")?;
+    Ok(())
+}
+
+#[prepare_listener]
+async fn slow_start(_c: Context, _d: Dialogue, _i: DialogueInput) -> Result<Transition> {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    Ok(Transition::next("count"))
+}
+
+#[prepare_listener]
+async fn slow_count(_c: Context, _d: Dialogue, input: DialogueInput) -> Result<Transition> {
+    let amount: usize = input
+        .get_text()
+        .and_then(|t| t.parse().ok())
+        .unwrap_or_default();
+    SLOW_B.fetch_add(amount, Ordering::Acquire);
+    Ok(Transition::Stay)
+}
+
+#[command(description = "starts a dialogue that is left alone")]
+async fn idle(ctx: Context, m: Message) -> CommandResult {
+    ctx.start_dialogue(m, "idle")?;
+    Ok(())
+}
+
+#[prepare_listener]
+async fn idle_start(_c: Context, _d: Dialogue, _i: DialogueInput) -> Result<Transition> {
+    Ok(Transition::Stay)
+}
+
+fn text_message(text: &str) -> Update {
+    let entities = if text.starts_with('/') {
+        vec![MessageEntity::BotCommand(TextBlock {
+            offset: 0,
+            length: text.len(),
+        })]
+    } else {
+        Vec::new()
+    };
+
+    Update {
+        update_id: 10,
+        content: UpdateContent::Message(Message {
+            message_id: 30,
+            from: None,
+            date: chrono::offset::Utc::now(),
+            business_connection_id: None,
+            chat: Chat::Private(PrivateChat {
+                id: 40,
+                username: None,
+                first_name: None,
+                bio: None,
+                last_name: None,
+                photo: None,
+            }),
+            sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
+            forward_data: None,
+            reply_to_message: None,
+            external_reply: None,
+            quote: None,
+            via_bot: None,
+            edit_date: None,
+            author_signature: None,
+            has_protected_content: false,
+            connected_website: None,
+            passport_data: None,
+            reply_markup: None,
+            content: MessageContent::Text {
+                content: text.to_owned(),
+                entities,
+            },
+            link_preview_options: None,
+        }),
+    }
+}
+
+fn order_flow() -> DialogueFlow {
+    let mut flow = DialogueFlow::new("order", "start", order_start);
+    flow.add_state("amount", order_amount)
+        .set_timeout(Duration::from_secs(60));
+    flow
+}
+
+#[tokio::test]
+async fn test_using_dialogues() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", order))
+        .add_dialogue_flow(order_flow())
+        .build();
+    let key = DialogueKey::new(40, 40);
+
+    for text in ["/order", "not a number", "12", "yes"] {
+        c.fire_handlers(text_message(text));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(ORDER_B.load(Ordering::Relaxed), 12);
+    assert!(!c.dialogues.is_active(key));
+
+    // cancelling the dialogue of another bot is just input
+    for text in ["/order", "/cancel@other_bot", "5"] {
+        c.fire_handlers(text_message(text));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        c.dialogues.get_state(key),
+        Some(("order".to_owned(), "amount".to_owned()))
+    );
+
+    c.fire_handlers(text_message("/cancel@test_bot"));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert!(!c.dialogues.is_active(key));
+    assert_eq!(ORDER_B.load(Ordering::Relaxed), 12);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_queueing_input_of_busy_dialogues() -> Result<()> {
+    let mut flow = DialogueFlow::new("slow", "start", slow_start);
+    flow.add_state("count", slow_count);
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", slow))
+        .add_dialogue_flow(flow)
+        .build();
+
+    c.fire_handlers(text_message("/slow"));
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    c.fire_handlers(text_message("3"));
+    c.fire_handlers(text_message("4"));
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    assert_eq!(SLOW_B.load(Ordering::Relaxed), 7);
+    assert_eq!(
+        c.dialogues.get_state(DialogueKey::new(40, 40)),
+        Some(("slow".to_owned(), "count".to_owned()))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ending_dialogues_without_input_on_timeout() -> Result<()> {
+    let storage = Arc::new(MemoryStorage::new());
+    let key = DialogueKey::new(40, 40);
+    let mut flow = DialogueFlow::new("idle", "start", idle_start);
+    flow.set_timeout(Duration::from_millis(100))
+        .set_timeout_reply("Too late");
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", idle))
+        .set_storage(storage.clone())
+        .add_dialogue_flow(flow)
+        .build();
+
+    c.fire_handlers(text_message("/idle"));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(c.dialogues.is_active(key));
+    assert!(storage.get(&dialogue_key(key)).await?.is_some());
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(!c.dialogues.is_active(key));
+    assert_eq!(storage.get(&dialogue_key(key)).await?, None);
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use telexide_fork::{
+    api::types::{SendMessage, SendPhoto},
+    model::{MessageEntity, ParseMode, Text, TextBlock, TextLink, User},
+};
+
+fn user() -> User {
+    User {
+        id: 60,
+        is_bot: false,
+        first_name: "test".to_owned(),
+        last_name: None,
+        username: None,
+        language_code: None,
+        can_join_groups: None,
+        can_read_all_group_messages: None,
+        supports_inline_queries: None,
+    }
+}
+
+#[test]
+fn format_markdown_v2() {
+    let text = Text::new()
+        .bold("1.5 * 2")
+        .plain(" (see ")
+        .link("docs", "https://example.com/a_(b)")
+        .plain(")\n")
+        .code("let `x` = 1;")
+        .plain(" ")
+        .italic(Text::new().underline("both"))
+        .plain(" ")
+        .underline(Text::new().italic("both"))
+        .mention(" hi", &user());
+
+    assert_eq!(
+        text.to_markdown_v2(),
+        "*1\\.5 \\* 2* \\(see [docs](https://example.com/a_(b\\))\\)\n`let \\`x\\` = 1;` \
+         _\r__both__\r_ __\r_both_\r__[ hi](tg://user?id=60)"
+    );
+
+    let quote = Text::new()
+        .blockquote("first\nsecond")
+        .plain("\nafter")
+        .pre("fn main() {}", Some("rust"));
+    assert_eq!(
+        quote.to_markdown_v2(),
+        ">first\n>second\nafter```rust\nfn main() {}\n```"
+    );
+
+    let quote = Text::new()
+        .plain("a")
+        .blockquote("b\nc")
+        .blockquote("d")
+        .bold("e");
+    assert_eq!(quote.to_markdown_v2(), "a\n>b\n>c\n>d\n*e*");
+}
+
+#[test]
+fn format_html() {
+    let text = Text::new()
+        .bold("a < b & c")
+        .plain(" ")
+        .link(
+            Text::new().spoiler("secret"),
+            "https://example.com/?a=1&b=\"2\"",
+        )
+        .pre("x > 1", Some("python"))
+        .custom_emoji("👍", "5368324170671202286");
+
+    assert_eq!(
+        text.to_html(),
+        "<b>a &lt; b &amp; c</b> <a href=\"https://example.com/?a=1&amp;b=&quot;2&quot;\">\
+         <tg-spoiler>secret</tg-spoiler></a><pre><code class=\"language-python\">x &gt; \
+         1</code></pre><tg-emoji emoji-id=\"5368324170671202286\">👍</tg-emoji>"
+    );
+}
+
+#[test]
+fn format_entities() {
+    let text = Text::new()
+        .plain("😀 ")
+        .bold(Text::new().plain("bold ").italic("both"))
+        .plain(" ")
+        .link("link", "https://example.com")
+        .strikethrough("");
+
+    let (plain, entities) = text.to_entities();
+    assert_eq!(plain, "😀 bold both link");
+    assert_eq!(
+        entities,
+        vec![
+            MessageEntity::Bold(TextBlock {
+                offset: 3,
+                length: 9
+            }),
+            MessageEntity::Italic(TextBlock {
+                offset: 8,
+                length: 4
+            }),
+            MessageEntity::TextLink(TextLink {
+                text_block: TextBlock {
+                    offset: 13,
+                    length: 4
+                },
+                url: "https://example.com".to_owned()
+            }),
+        ]
+    );
+    if let MessageEntity::Italic(block) = &entities[1] {
+        assert_eq!(block.get_text(&plain), "both");
+    }
+
+    let mut message = SendMessage::new(1, "");
+    message
+        .set_parse_mode(&ParseMode::HTML)
+        .set_formatted_text(&text);
+    assert_eq!(message.text, plain);
+    assert_eq!(message.enitites, Some(entities));
+    assert_eq!(message.parse_mode, None);
+
+    let mut photo = SendPhoto::new(1, "file-id".into());
+    photo.set_formatted_caption(&Text::from("plain caption"));
+    assert_eq!(photo.caption.as_deref(), Some("plain caption"));
+    assert_eq!(photo.caption_entities, None);
+}
@@ -0,0 +1,198 @@
+use parking_lot::RwLock;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use telexide_fork::{
+    api::{
+        types::{
+            InlineQueryResult, InlineQueryResultArticle, InputMessageContent,
+            InputTextMessageContent,
+        },
+        APIClient,
+    },
+    client::{ClientBuilder, Context},
+    inline::{inline_results, InlineRequest, InlineResults, InlineRoute, InlineRouter},
+    macros::prepare_listener,
+    model::{InlineQuery, Update, UpdateContent, User},
+    Result,
+};
+use typemap::ShareMap;
+
+static NUMBERS_B: AtomicUsize = AtomicUsize::new(0);
+
+fn article(id: &str) -> InlineQueryResult {
+    InlineQueryResult::Article(InlineQueryResultArticle {
+        id: id.to_owned(),
+        title: id.to_owned(),
+        input_message_content: InputMessageContent::Text(InputTextMessageContent {
+            message_text: id.to_owned(),
+            parse_mode: None,
+            disable_web_page_preview: false,
+        }),
+        reply_markup: None,
+        url: None,
+        hide_url: false,
+        description: None,
+        thumb_url: None,
+        thumb_width: None,
+        thumb_height: None,
+    })
+}
+
+fn result_ids(results: &[InlineQueryResult]) -> Vec<String> {
+    results
+        .iter()
+        .map(|r| match r {
+            InlineQueryResult::Article(a) => a.id.clone(),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+#[prepare_listener]
+async fn numbers(_c: Context, _request: InlineRequest) -> Result<InlineResults> {
+    NUMBERS_B.fetch_add(1, Ordering::Acquire);
+    Ok(inline_results((0..120).map(|i| article(&i.to_string()))))
+}
+
+#[prepare_listener]
+async fn mention(_c: Context, request: InlineRequest) -> Result<InlineResults> {
+    Ok(inline_results(vec![article(&request.captures.join("-"))]))
+}
+
+#[prepare_listener]
+async fn echo(_c: Context, request: InlineRequest) -> Result<InlineResults> {
+    Ok(inline_results(vec![article(&request.text)]))
+}
+
+fn inline_query(id: &str, query: &str, offset: &str) -> InlineQuery {
+    InlineQuery {
+        id: id.to_owned(),
+        from: User {
+            id: 60,
+            is_bot: false,
+            first_name: "test".to_owned(),
+            last_name: None,
+            username: None,
+            language_code: None,
+            can_join_groups: None,
+            can_read_all_group_messages: None,
+            supports_inline_queries: None,
+        },
+        location: None,
+        query: query.to_owned(),
+        offset: offset.to_owned(),
+        chat_type: None,
+    }
+}
+
+fn context() -> Context {
+    Context::new(
+        Arc::new(Box::new(APIClient::new(None, &"test"))),
+        Arc::new(RwLock::new(ShareMap::custom())),
+    )
+}
+
+#[tokio::test]
+async fn test_routing_inline_queries() -> Result<()> {
+    let mut numbers_route = InlineRoute::prefix("num", numbers);
+    numbers_route.set_personal(true);
+
+    let mut router = InlineRouter::new();
+    router
+        .add_route(numbers_route)
+        .add_pattern_handler(r"^(\w+)@(\w+)$", mention)
+        .add_prefix_handler("", echo)
+        .set_cache_time(60);
+
+    let answer = router
+        .get_answer(context(), &inline_query("1", "num", ""))
+        .await?
+        .expect("the query should be answered");
+    assert_eq!(answer.inline_query_id, "1");
+    assert_eq!(answer.results.len(), 50);
+    assert_eq!(result_ids(&answer.results)[49], "49");
+    assert_eq!(answer.next_offset.as_deref(), Some("50"));
+    assert_eq!(answer.cache_time, Some(60));
+    assert!(answer.is_personal);
+
+    let answer = router
+        .get_answer(context(), &inline_query("2", "num", "100"))
+        .await?
+        .expect("the query should be answered");
+    assert_eq!(answer.results.len(), 20);
+    assert_eq!(result_ids(&answer.results)[0], "100");
+    assert_eq!(answer.next_offset, None);
+    assert_eq!(NUMBERS_B.load(Ordering::Relaxed), 1);
+
+    let answer = router
+        .get_answer(context(), &inline_query("3", "alice@bob", ""))
+        .await?
+        .expect("the query should be answered");
+    assert_eq!(result_ids(&answer.results), vec!["alice-bob"]);
+    assert!(!answer.is_personal);
+
+    let answer = router
+        .get_answer(context(), &inline_query("4", "numbers 5", ""))
+        .await?
+        .expect("the query should be answered");
+    assert_eq!(result_ids(&answer.results), vec!["numbers 5"]);
+
+    assert!(router
+        .get_answer(context(), &inline_query("5", "num", "invalid"))
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_debouncing_inline_queries() -> Result<()> {
+    let mut router = InlineRouter::new();
+    router
+        .add_prefix_handler("", echo)
+        .set_debounce(Duration::from_millis(50));
+
+    let (first, second) = (inline_query("1", "he", ""), inline_query("2", "hello", ""));
+    let (first, second) = tokio::join!(router.get_answer(context(), &first), async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        router.get_answer(context(), &second).await
+    });
+    assert!(first?.is_none());
+    let answer = second?.expect("the last query should be answered");
+    assert_eq!(result_ids(&answer.results), vec!["hello"]);
+    Ok(())
+}
+
+static FIRED_B: AtomicUsize = AtomicUsize::new(0);
+
+#[prepare_listener]
+async fn fired(_c: Context, _request: InlineRequest) -> Result<InlineResults> {
+    FIRED_B.fetch_add(1, Ordering::Acquire);
+    Ok(inline_results(Vec::new()))
+}
+
+#[tokio::test]
+async fn test_using_inline_router() -> Result<()> {
+    let mut router = InlineRouter::new();
+    router.add_prefix_handler("fire", fired);
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_inline_router(Arc::new(router))
+        .build();
+
+    for query in ["fire", "fire away", "fireworks"] {
+        c.fire_handlers(Update {
+            update_id: 10,
+            content: UpdateContent::InlineQuery(inline_query("1", query, "")),
+        });
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(FIRED_B.load(Ordering::Relaxed), 2);
+    Ok(())
+}
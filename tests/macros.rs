@@ -1,10 +1,18 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use telexide_fork::{
+    api::types::{BotCommandScope, DeleteMyCommands, SetMyCommands},
     client::{ClientBuilder, Context},
-    framework::CommandResult,
-    macros::{command, create_framework, prepare_listener},
+    framework::{
+        format_remaining, render_help, CommandResult, CommandTriggers, CooldownBucket, Framework,
+        PrivateOnly, Rest,
+    },
+    macros::{command, create_framework, group, prepare_listener},
     model::{
-        Chat, Message, MessageContent, MessageEntity, PrivateChat, TextBlock, Update, UpdateContent,
+        Chat, GroupChat, Message, MessageContent, MessageEntity, PrivateChat, TextBlock, Update,
+        UpdateContent,
     },
     Result,
 };
@@ -55,6 +63,7 @@ async fn test_using_command() -> Result<()> {
             message_id: 30,
             from: None,
             date: chrono::offset::Utc::now(),
+            business_connection_id: None,
             chat: Chat::Private(PrivateChat {
                 id: 40,
                 username: None,
@@ -64,15 +73,22 @@ async fn test_using_command() -> Result<()> {
                 photo: None,
             }),
             sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
             forward_data: None,
             reply_to_message: None,
+            external_reply: None,
+            quote: None,
             via_bot: None,
             edit_date: None,
             author_signature: None,
+            has_protected_content: false,
             connected_website: None,
             passport_data: None,
             reply_markup: None,
             content: MessageContent::Unknown,
+            link_preview_options: None,
         }),
     });
 
@@ -86,6 +102,7 @@ async fn test_using_command() -> Result<()> {
             message_id: 30,
             from: None,
             date: chrono::offset::Utc::now(),
+            business_connection_id: None,
             chat: Chat::Private(PrivateChat {
                 id: 40,
                 username: None,
@@ -95,11 +112,17 @@ async fn test_using_command() -> Result<()> {
                 photo: None,
             }),
             sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
             forward_data: None,
             reply_to_message: None,
+            external_reply: None,
+            quote: None,
             via_bot: None,
             edit_date: None,
             author_signature: None,
+            has_protected_content: false,
             connected_website: None,
             passport_data: None,
             reply_markup: None,
@@ -110,6 +133,7 @@ async fn test_using_command() -> Result<()> {
                     length: 16,
                 })],
             },
+            link_preview_options: None,
         }),
     });
 
@@ -118,3 +142,608 @@ async fn test_using_command() -> Result<()> {
     assert_eq!(COMMAND_B.load(Ordering::Relaxed), 30);
     Ok(())
 }
+
+static TYPED_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing typed arguments")]
+async fn typed_command(
+    _c: Context,
+    _m: Message,
+    amount: usize,
+    reason: Option<Rest>,
+) -> CommandResult {
+    if reason.as_deref() == Some("for testing purposes") {
+        TYPED_COMMAND_B.fetch_add(amount, Ordering::Acquire);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_typed_command() -> Result<()> {
+    assert_eq!(
+        typed_command_COMMAND_OPTIONS.usage(),
+        "/typed_command <amount> [reason...]"
+    );
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", typed_command))
+        .build();
+
+    c.fire_handlers(Update {
+        update_id: 10,
+        content: UpdateContent::Message(Message {
+            message_id: 30,
+            from: None,
+            date: chrono::offset::Utc::now(),
+            business_connection_id: None,
+            chat: Chat::Private(PrivateChat {
+                id: 40,
+                username: None,
+                first_name: None,
+                bio: None,
+                last_name: None,
+                photo: None,
+            }),
+            sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
+            forward_data: None,
+            reply_to_message: None,
+            external_reply: None,
+            quote: None,
+            via_bot: None,
+            edit_date: None,
+            author_signature: None,
+            has_protected_content: false,
+            connected_website: None,
+            passport_data: None,
+            reply_markup: None,
+            content: MessageContent::Text {
+                content: "/typed_command 12  for testing purposes".to_owned(),
+                entities: vec![MessageEntity::BotCommand(TextBlock {
+                    offset: 0,
+                    length: 14,
+                })],
+            },
+            link_preview_options: None,
+        }),
+    });
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(TYPED_COMMAND_B.load(Ordering::Relaxed), 12);
+    Ok(())
+}
+
+fn command_message(text: &str, command_length: usize) -> Update {
+    Update {
+        update_id: 10,
+        content: UpdateContent::Message(Message {
+            message_id: 30,
+            from: None,
+            date: chrono::offset::Utc::now(),
+            business_connection_id: None,
+            chat: Chat::Private(PrivateChat {
+                id: 40,
+                username: None,
+                first_name: None,
+                bio: None,
+                last_name: None,
+                photo: None,
+            }),
+            sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
+            forward_data: None,
+            reply_to_message: None,
+            external_reply: None,
+            quote: None,
+            via_bot: None,
+            edit_date: None,
+            author_signature: None,
+            has_protected_content: false,
+            connected_website: None,
+            passport_data: None,
+            reply_markup: None,
+            content: MessageContent::Text {
+                content: text.to_owned(),
+                entities: vec![MessageEntity::BotCommand(TextBlock {
+                    offset: 0,
+                    length: command_length,
+                })],
+            },
+            link_preview_options: None,
+        }),
+    }
+}
+
+static ALIASED_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(
+    description = "testing aliases",
+    aliases = ["alias", "other_alias"],
+    scope = ["all_private_chats", "chat:-100"],
+    language_code = "en"
+)]
+async fn aliased_command(_c: Context, m: Message) -> CommandResult {
+    ALIASED_COMMAND_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[command(hidden = true)]
+async fn hidden_command(_c: Context, _m: Message) -> CommandResult {
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_command_aliases() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", aliased_command))
+        .build();
+
+    c.fire_handlers(command_message("/alias", 6));
+    c.fire_handlers(command_message("/other_alias@test_bot", 21));
+    c.fire_handlers(command_message("/alias@other_bot", 16));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(ALIASED_COMMAND_B.load(Ordering::Relaxed), 60);
+    Ok(())
+}
+
+#[test]
+fn test_command_scopes() {
+    let framework = create_framework!("test_bot", testing_command, aliased_command, hidden_command);
+    let registered = SetMyCommands::from_commands(framework.get_commands());
+
+    assert_eq!(registered.len(), 3);
+    assert_eq!(registered[0].scope, Some(BotCommandScope::Default));
+    assert_eq!(registered[0].commands.len(), 1);
+    assert_eq!(registered[1].scope, Some(BotCommandScope::AllPrivateChats));
+    assert_eq!(registered[1].language_code, Some("en".to_owned()));
+    assert_eq!(registered[1].commands.len(), 2);
+    assert_eq!(
+        registered[2].scope,
+        Some(BotCommandScope::Chat { chat_id: -100 })
+    );
+    assert_eq!(registered[2].commands[0].command, "testing_command");
+    assert_eq!(registered[2].commands[1].command, "aliased_command");
+
+    let removed_chat = DeleteMyCommands {
+        scope: Some(BotCommandScope::Chat { chat_id: -200 }),
+        language_code: None,
+    };
+    let stale = DeleteMyCommands::stale(
+        &registered,
+        vec![removed_chat.clone(), DeleteMyCommands::from(&registered[1])],
+    );
+    assert_eq!(stale.len(), 4);
+    assert_eq!(stale[0].scope, Some(BotCommandScope::AllPrivateChats));
+    assert_eq!(stale[0].language_code, None);
+    assert_eq!(stale[3], removed_chat);
+
+    let framework = create_framework!("test_bot", hidden_command);
+    let registered = SetMyCommands::from_commands(framework.get_commands());
+    assert_eq!(registered.len(), 1);
+    assert_eq!(registered[0].scope, Some(BotCommandScope::Default));
+    assert!(registered[0].commands.is_empty());
+}
+
+static CHECKED_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing checks", checks = [PrivateOnly], denial_reply = "")]
+async fn checked_command(_c: Context, m: Message) -> CommandResult {
+    CHECKED_COMMAND_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_command_checks() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", checked_command))
+        .build();
+
+    let mut group_update = command_message("/checked_command", 16);
+    if let UpdateContent::Message(ref mut m) = group_update.content {
+        m.chat = Chat::Group(GroupChat {
+            id: 50,
+            title: "group".to_owned(),
+            username: None,
+            photo: None,
+            description: None,
+            invite_link: None,
+            pinned_message: None,
+            permissions: None,
+        });
+    }
+
+    c.fire_handlers(group_update);
+    c.fire_handlers(command_message("/checked_command", 16));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(CHECKED_COMMAND_B.load(Ordering::Relaxed), 30);
+    Ok(())
+}
+
+static COOLDOWN_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing cooldowns", cooldown = "1m per chat")]
+async fn cooldown_command(_c: Context, m: Message) -> CommandResult {
+    COOLDOWN_COMMAND_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_command_cooldowns() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", cooldown_command))
+        .build();
+
+    let cooldown = cooldown_command_COMMAND_OPTIONS.cooldown.unwrap();
+    assert_eq!(cooldown.duration, std::time::Duration::from_secs(60));
+    assert_eq!(cooldown.bucket, CooldownBucket::Chat);
+    assert_eq!(
+        format_remaining(std::time::Duration::from_millis(65_500)),
+        "1m 6s"
+    );
+
+    c.fire_handlers(command_message("/cooldown_command", 17));
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    c.fire_handlers(command_message("/cooldown_command", 17));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(COOLDOWN_COMMAND_B.load(Ordering::Relaxed), 30);
+    Ok(())
+}
+
+static TYPED_COOLDOWN_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(
+    description = "testing cooldowns with arguments",
+    cooldown = "1m per chat"
+)]
+async fn typed_cooldown(_c: Context, _m: Message, amount: usize) -> CommandResult {
+    TYPED_COOLDOWN_B.fetch_add(amount, Ordering::Acquire);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cooldown_released_on_argument_error() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", typed_cooldown))
+        .build();
+
+    c.fire_handlers(command_message("/typed_cooldown many", 15));
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    c.fire_handlers(command_message("/typed_cooldown 7", 15));
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    c.fire_handlers(command_message("/typed_cooldown 7", 15));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(TYPED_COOLDOWN_B.load(Ordering::Relaxed), 7);
+    Ok(())
+}
+
+static GROUPED_COOLDOWN_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(
+    name = "reset",
+    description = "resets things",
+    cooldown = "1m per chat"
+)]
+async fn reset_first(_c: Context, m: Message) -> CommandResult {
+    GROUPED_COOLDOWN_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[command(
+    name = "reset",
+    description = "resets things",
+    cooldown = "1m per chat"
+)]
+async fn reset_second(_c: Context, m: Message) -> CommandResult {
+    GROUPED_COOLDOWN_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[group(prefix = "first", description = "first things", commands = [reset_first])]
+struct FirstCooldowns;
+
+#[group(prefix = "second", description = "second things", commands = [reset_second])]
+struct SecondCooldowns;
+
+#[tokio::test]
+async fn test_cooldowns_of_grouped_commands() -> Result<()> {
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!(
+            "test_bot",
+            testing_command;
+            groups = [FirstCooldowns, SecondCooldowns]
+        ))
+        .build();
+
+    c.fire_handlers(command_message("/first reset", 6));
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    c.fire_handlers(command_message("/second reset", 7));
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    c.fire_handlers(command_message("/first reset", 6));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(GROUPED_COOLDOWN_B.load(Ordering::Relaxed), 60);
+    Ok(())
+}
+
+static GROUP_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(name = "set", description = "changes a setting")]
+async fn set_setting(_c: Context, _m: Message, _key: String, value: usize) -> CommandResult {
+    GROUP_COMMAND_B.fetch_add(value, Ordering::Acquire);
+    Ok(())
+}
+
+#[command(description = "testing groups")]
+async fn grouped_command(_c: Context, m: Message) -> CommandResult {
+    GROUP_COMMAND_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[group(prefix = "config", description = "changes the settings", commands = [set_setting])]
+struct ConfigCommands;
+
+#[group(checks = [PrivateOnly], commands = [grouped_command])]
+struct Admin;
+
+fn get_message(update: Update) -> Message {
+    match update.content {
+        UpdateContent::Message(m) => m,
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test]
+async fn test_using_command_groups() -> Result<()> {
+    let framework =
+        create_framework!("test_bot", testing_command; groups = [ConfigCommands, Admin]);
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(framework.clone())
+        .build();
+
+    c.fire_handlers(command_message("/config set volume 7", 7));
+    c.fire_handlers(command_message("/grouped_command", 16));
+    c.fire_handlers(command_message("/set volume 5", 4));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(GROUP_COMMAND_B.load(Ordering::Relaxed), 37);
+
+    let registered = SetMyCommands::from_framework(&framework);
+    let names: Vec<&str> = registered[0]
+        .commands
+        .iter()
+        .map(|c| c.command.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["testing_command", "config", "grouped_command", "help"]
+    );
+
+    let help = render_help(&framework, &get_message(command_message("/help", 5)));
+    assert!(help.contains("/config set <key> <value> - changes a setting"));
+    assert!(help.contains("admin:\n/grouped_command - testing groups"));
+
+    let help = render_help(
+        &framework,
+        &get_message(command_message("/help config set", 5)),
+    );
+    assert_eq!(
+        help,
+        "/config set <key> <value>\nchanges a setting\nCategory: config_commands"
+    );
+    Ok(())
+}
+
+static TRIGGERED_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing triggers")]
+async fn triggered_command(_c: Context, m: Message, amount: usize) -> CommandResult {
+    TRIGGERED_COMMAND_B.fetch_add(m.message_id as usize * amount, Ordering::Acquire);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_command_triggers() -> Result<()> {
+    let mut framework = Framework::new("test_bot");
+    framework.add_command(&triggered_command_COMMAND);
+    framework.disable_help();
+    framework.set_triggers(CommandTriggers {
+        edited_messages: true,
+        captions: true,
+        anywhere: true,
+        case_insensitive: true,
+        ..CommandTriggers::default()
+    });
+    framework.set_bot_name("Real_Bot");
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(Arc::new(framework))
+        .build();
+
+    let mut caption_update = command_message("", 0);
+    if let UpdateContent::Message(ref mut m) = caption_update.content {
+        m.content = MessageContent::Photo {
+            content: Vec::new(),
+            caption: Some("look /triggered_command 1".to_owned()),
+            caption_entities: Some(vec![MessageEntity::BotCommand(TextBlock {
+                offset: 5,
+                length: 18,
+            })]),
+            media_group_id: None,
+        };
+    }
+
+    let mut edited_update = command_message("/Triggered_Command@real_bot 2", 27);
+    edited_update.content = match edited_update.content {
+        UpdateContent::Message(m) => UpdateContent::EditedMessage(m),
+        content => content,
+    };
+
+    c.fire_handlers(caption_update);
+    c.fire_handlers(edited_update);
+    c.fire_handlers(command_message("/triggered_command@test_bot 4", 27));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(TRIGGERED_COMMAND_B.load(Ordering::Relaxed), 90);
+    Ok(())
+}
+
+static CASED_GROUP_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing case-insensitive groups")]
+async fn toggle(_c: Context, m: Message) -> CommandResult {
+    CASED_GROUP_B.fetch_add(m.message_id as usize, Ordering::Acquire);
+    Ok(())
+}
+
+#[group(prefix = "Settings", description = "changes things", commands = [toggle])]
+struct CasedGroup;
+
+#[tokio::test]
+async fn test_case_insensitive_group_prefix() -> Result<()> {
+    let mut framework = Framework::new("test_bot");
+    framework.add_group(&CASED_GROUP_GROUP);
+    framework.disable_help();
+    framework.set_triggers(CommandTriggers {
+        case_insensitive: true,
+        ..CommandTriggers::default()
+    });
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(Arc::new(framework))
+        .build();
+
+    c.fire_handlers(command_message("/settings toggle", 9));
+    c.fire_handlers(command_message("/SETTINGS Toggle", 9));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(CASED_GROUP_B.load(Ordering::Relaxed), 60);
+    Ok(())
+}
+
+static PREFIXED_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing prefixes", prefixes = ["!"], mention = true)]
+async fn prefixed_command(_c: Context, _m: Message, amount: usize) -> CommandResult {
+    PREFIXED_COMMAND_B.fetch_add(amount, Ordering::Acquire);
+    Ok(())
+}
+
+#[command(pattern = r"^roll (\d+)d")]
+async fn roll(_c: Context, _m: Message, sides: usize) -> CommandResult {
+    PREFIXED_COMMAND_B.fetch_add(sides * 100, Ordering::Acquire);
+    Ok(())
+}
+
+fn text_message(text: &str, entities: Vec<MessageEntity>) -> Update {
+    let mut update = command_message(text, 0);
+    if let UpdateContent::Message(ref mut m) = update.content {
+        m.content = MessageContent::Text {
+            content: text.to_owned(),
+            entities,
+        };
+    }
+    update
+}
+
+#[tokio::test]
+async fn test_using_command_prefixes() -> Result<()> {
+    let mut framework = Framework::new("test_bot");
+    framework.add_command(&prefixed_command_COMMAND);
+    framework.add_command(&roll_COMMAND);
+    framework.add_command(&testing_command_COMMAND);
+    framework.add_prefix(".");
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(Arc::new(framework))
+        .build();
+
+    c.fire_handlers(text_message("!prefixed_command 1", Vec::new()));
+    c.fire_handlers(text_message(".prefixed_command 2", Vec::new()));
+    c.fire_handlers(text_message(
+        "@test_bot prefixed_command 4",
+        vec![MessageEntity::Mention(TextBlock {
+            offset: 0,
+            length: 9,
+        })],
+    ));
+    c.fire_handlers(text_message(
+        "@other_bot prefixed_command 8",
+        vec![MessageEntity::Mention(TextBlock {
+            offset: 0,
+            length: 10,
+        })],
+    ));
+    c.fire_handlers(text_message("?prefixed_command 16", Vec::new()));
+    c.fire_handlers(text_message("roll 2d6", Vec::new()));
+    c.fire_handlers(text_message("!roll 3d6", Vec::new()));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(PREFIXED_COMMAND_B.load(Ordering::Relaxed), 607);
+    Ok(())
+}
+
+static ANYWHERE_COMMAND_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "testing commands anywhere")]
+async fn anywhere_command(_c: Context, _m: Message, amount: usize) -> CommandResult {
+    ANYWHERE_COMMAND_B.fetch_add(amount, Ordering::Acquire);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_arguments_of_commands_anywhere() -> Result<()> {
+    let mut framework = Framework::new("test_bot");
+    framework.add_command(&anywhere_command_COMMAND);
+    framework.add_prefix("!");
+    framework.set_triggers(CommandTriggers {
+        anywhere: true,
+        ..CommandTriggers::default()
+    });
+
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(Arc::new(framework))
+        .build();
+
+    c.fire_handlers(text_message(
+        "\"hey\" /anywhere_command 5",
+        vec![MessageEntity::BotCommand(TextBlock {
+            offset: 6,
+            length: 17,
+        })],
+    ));
+    c.fire_handlers(text_message("!anywhere_command 10", Vec::new()));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    assert_eq!(ANYWHERE_COMMAND_B.load(Ordering::Relaxed), 15);
+    Ok(())
+}
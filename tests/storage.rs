@@ -0,0 +1,181 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use telexide_fork::{
+    client::{ClientBuilder, Context},
+    dialogue::{Dialogue, DialogueFlow, DialogueInput, DialogueKey, Transition},
+    framework::CommandResult,
+    macros::{command, create_framework, prepare_listener},
+    model::{
+        Chat, Message, MessageContent, MessageEntity, PrivateChat, TextBlock, Update, UpdateContent,
+    },
+    storage::{dialogue_key, MemoryStorage, StateStorage},
+    Result,
+};
+
+static VISITS_B: AtomicUsize = AtomicUsize::new(0);
+static SURVEY_B: AtomicUsize = AtomicUsize::new(0);
+
+#[command(description = "counts the visits of the chat")]
+async fn visit(ctx: Context, m: Message) -> CommandResult {
+    let chat_id = m.chat.get_id();
+    let visits = ctx
+        .get_chat_state::<usize>(chat_id, "visits")
+        .await?
+        .unwrap_or_default()
+        + 1;
+    ctx.set_chat_state(chat_id, "visits", &visits).await?;
+    VISITS_B.store(visits, Ordering::Release);
+    Ok(())
+}
+
+#[command(description = "starts a survey")]
+async fn survey(ctx: Context, m: Message) -> CommandResult {
+    ctx.start_dialogue(m, "survey")?;
+    Ok(())
+}
+
+#[prepare_listener]
+async fn survey_start(_c: Context, d: Dialogue, _i: DialogueInput) -> Result<Transition> {
+    d.set("question", &"age")?;
+    Ok(Transition::next("answer"))
+}
+
+#[prepare_listener]
+async fn survey_answer(_c: Context, d: Dialogue, input: DialogueInput) -> Result<Transition> {
+    if d.get::<String>("question").as_deref() == Some("age") {
+        let answer: usize = input
+            .get_text()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or_default();
+        SURVEY_B.fetch_add(answer, Ordering::Acquire);
+    }
+    Ok(Transition::Finish)
+}
+
+fn text_message(text: &str) -> Update {
+    let entities = if text.starts_with('/') {
+        vec![MessageEntity::BotCommand(TextBlock {
+            offset: 0,
+            length: text.len(),
+        })]
+    } else {
+        Vec::new()
+    };
+
+    Update {
+        update_id: 10,
+        content: UpdateContent::Message(Message {
+            message_id: 30,
+            from: None,
+            date: chrono::offset::Utc::now(),
+            business_connection_id: None,
+            chat: Chat::Private(PrivateChat {
+                id: 50,
+                username: None,
+                first_name: None,
+                bio: None,
+                last_name: None,
+                photo: None,
+            }),
+            sender_chat: None,
+            sender_business_bot: None,
+            message_thread_id: None,
+            is_topic_message: false,
+            forward_data: None,
+            reply_to_message: None,
+            external_reply: None,
+            quote: None,
+            via_bot: None,
+            edit_date: None,
+            author_signature: None,
+            has_protected_content: false,
+            connected_website: None,
+            passport_data: None,
+            reply_markup: None,
+            content: MessageContent::Text {
+                content: text.to_owned(),
+                entities,
+            },
+            link_preview_options: None,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn test_using_memory_storage() -> Result<()> {
+    let storage = MemoryStorage::new();
+    assert_eq!(storage.get("key").await?, None);
+
+    storage.set("key", serde_json::json!({"a": 1})).await?;
+    assert_eq!(storage.get("key").await?, Some(serde_json::json!({"a": 1})));
+
+    storage.remove("key").await?;
+    assert_eq!(storage.get("key").await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_using_chat_state() -> Result<()> {
+    let storage = Arc::new(MemoryStorage::new());
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", visit))
+        .set_storage(storage.clone())
+        .build();
+
+    for _ in 0..3 {
+        c.fire_handlers(text_message("/visit"));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(VISITS_B.load(Ordering::Relaxed), 3);
+    assert_eq!(
+        storage.get("chat:50:visits").await?,
+        Some(serde_json::json!(3))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restoring_dialogues() -> Result<()> {
+    let storage = Arc::new(MemoryStorage::new());
+    let key = DialogueKey::new(50, 50);
+    let mut flow = DialogueFlow::new("survey", "start", survey_start);
+    flow.add_state("answer", survey_answer);
+
+    let first = ClientBuilder::new()
+        .set_token("test")
+        .set_framework(create_framework!("test_bot", survey))
+        .set_storage(storage.clone())
+        .add_dialogue_flow(flow.clone())
+        .build();
+    let c = ClientBuilder::new()
+        .set_token("test")
+        .set_storage(storage.clone())
+        .add_dialogue_flow(flow)
+        .build();
+
+    c.fire_handlers(text_message("7"));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!c.dialogues.is_active(key));
+
+    first.fire_handlers(text_message("/survey"));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(storage.get(&dialogue_key(key)).await?.is_some());
+
+    // another client with the same storage continues the dialogue, even
+    // though it has seen the user before it was saved
+    assert!(!c.dialogues.is_active(key));
+    c.fire_handlers(text_message("42"));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(SURVEY_B.load(Ordering::Relaxed), 42);
+    assert!(!c.dialogues.is_active(key));
+    assert_eq!(storage.get(&dialogue_key(key)).await?, None);
+    Ok(())
+}
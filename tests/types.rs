@@ -1,4 +1,20 @@
-use telexide_fork::model::{Chat, Message, MessageContent, User};
+use telexide_fork::{
+    api::types::{
+        AddStickerToSet, BotCommandScope, CreateNewStickerSet, DeleteMyCommands,
+        EditMessageReplyMarkup, EditMessageText, InlineQueryResult,
+        InlineQueryResultCachedDocument, InlineQueryResultCachedPhoto,
+        InlineQueryResultCachedSticker, InputFile, InputPaidMedia, InputPaidMediaPhoto,
+        InputSticker, ReplyParameters, SendInvoice, SendMessage, SendPaidMedia,
+        SetMyDefaultAdministratorRights, SetStickerSetThumbnail,
+    },
+    model::{
+        CallbackQuery, Chat, ChatAdministratorRights, KeyboardButton, KeyboardButtonRequestChat,
+        KeyboardButtonRequestUsers, LinkPreviewOptions, Message, MessageContent, MessageOrigin,
+        PaidMedia, ParseMode, RevenueWithdrawalState, StarTransactions, Sticker, StickerFormat,
+        StickerSet, StickerType, SuccessfulPayment, TransactionPartner, Update, UpdateContent,
+        User,
+    },
+};
 
 #[test]
 fn decode_user() -> serde_json::Result<()> {
@@ -50,3 +66,633 @@ fn decode_message() -> serde_json::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn decode_forum_topic_message() -> serde_json::Result<()> {
+    let t = r#"{
+            "message_id": 20,
+            "message_thread_id": 20,
+            "date": 1585772722,
+            "chat": {
+                "id": -100538733,
+                "type": "supergroup",
+                "title": "forum",
+                "is_forum": true
+            },
+            "forum_topic_created": {
+                "name": "topic",
+                "icon_color": 7322096
+            }
+        }"#;
+
+    let m: Message = serde_json::from_str(t)?;
+
+    assert_eq!(m.message_thread_id, Some(20));
+    assert!(!m.is_topic_message);
+    if let MessageContent::ForumTopicCreated { content } = m.content.clone() {
+        assert_eq!(content.name, "topic");
+        assert_eq!(content.icon_custom_emoji_id, None);
+    } else {
+        panic!("no forum topic created")
+    }
+
+    if let Chat::SuperGroup(c) = m.chat.clone() {
+        assert!(c.is_forum);
+    } else {
+        panic!("no supergroup chat")
+    }
+
+    let encoded = serde_json::to_value(&m)?;
+    assert_eq!(encoded["forum_topic_created"]["icon_color"], 7322096);
+    assert_eq!(encoded["chat"]["is_forum"], true);
+    Ok(())
+}
+
+#[test]
+fn encode_cached_inline_query_results() -> serde_json::Result<()> {
+    let mut photo = InlineQueryResultCachedPhoto::new("1", "photo-file");
+    photo
+        .set_caption("*cat*")
+        .set_parse_mode(&ParseMode::MarkdownV2);
+    let photo: InlineQueryResult = photo.into();
+
+    assert_eq!(
+        serde_json::to_value(&photo)?,
+        serde_json::json!({
+            "type": "photo",
+            "id": "1",
+            "photo_file_id": "photo-file",
+            "caption": "*cat*",
+            "parse_mode": "MarkdownV2"
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<InlineQueryResult>(serde_json::to_value(&photo)?)?,
+        photo
+    );
+
+    let document: InlineQueryResult =
+        InlineQueryResultCachedDocument::new("2", "document-file", "report").into();
+    assert_eq!(
+        serde_json::to_value(&document)?,
+        serde_json::json!({
+            "type": "document",
+            "id": "2",
+            "title": "report",
+            "document_file_id": "document-file"
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn decode_inline_query_results() -> serde_json::Result<()> {
+    let t = r#"[
+            {
+                "type": "photo",
+                "id": "1",
+                "photo_url": "https://example.com/cat.jpg",
+                "thumb_url": "https://example.com/cat_thumb.jpg"
+            },
+            {
+                "type": "photo",
+                "id": "2",
+                "photo_file_id": "photo-file"
+            },
+            {
+                "type": "sticker",
+                "id": "3",
+                "sticker_file_id": "sticker-file"
+            },
+            {
+                "type": "voice",
+                "id": "4",
+                "voice_file_id": "voice-file",
+                "title": "hello"
+            }
+        ]"#;
+
+    let results: Vec<InlineQueryResult> = serde_json::from_str(t)?;
+
+    assert!(matches!(results[0], InlineQueryResult::Photo(_)));
+    assert!(matches!(results[1], InlineQueryResult::CachedPhoto(_)));
+    assert_eq!(
+        results[2],
+        InlineQueryResult::CachedSticker(InlineQueryResultCachedSticker::new("3", "sticker-file"))
+    );
+    if let InlineQueryResult::CachedVoice(v) = &results[3] {
+        assert_eq!(v.title, "hello");
+    } else {
+        panic!("no cached voice")
+    }
+
+    assert!(
+        serde_json::from_str::<InlineQueryResult>(r#"{"type": "unknown", "id": "5"}"#).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn encode_bot_profile_payloads() -> serde_json::Result<()> {
+    let rights = SetMyDefaultAdministratorRights {
+        rights: Some(ChatAdministratorRights {
+            can_delete_messages: true,
+            can_pin_messages: Some(true),
+            ..ChatAdministratorRights::default()
+        }),
+        for_channels: None,
+    };
+    let value = serde_json::to_value(&rights)?;
+    assert_eq!(value["rights"]["can_delete_messages"], true);
+    assert_eq!(value["rights"]["can_pin_messages"], true);
+    assert!(value["rights"].get("can_post_messages").is_none());
+    assert!(value.get("for_channels").is_none());
+
+    let rights: ChatAdministratorRights =
+        serde_json::from_str(r#"{"can_manage_chat": true, "can_manage_topics": false}"#)?;
+    assert!(rights.can_manage_chat);
+    assert_eq!(rights.can_manage_topics, Some(false));
+
+    let delete = DeleteMyCommands {
+        scope: Some(BotCommandScope::ChatAdministrators { chat_id: -100 }),
+        language_code: Some("en".to_owned()),
+    };
+    assert_eq!(
+        serde_json::to_value(&delete)?,
+        serde_json::json!({
+            "scope": {"type": "chat_administrators", "chat_id": -100},
+            "language_code": "en"
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(DeleteMyCommands::default())?,
+        serde_json::json!({})
+    );
+    Ok(())
+}
+
+#[test]
+fn encode_sticker_sets() -> serde_json::Result<()> {
+    let mut sticker = InputSticker::new("file-id", StickerFormat::Video, &["🔥", "👍"]);
+    sticker.keywords = Some(vec!["fire".to_owned()]);
+    let set = CreateNewStickerSet {
+        user_id: 60,
+        name: "fire_by_test_bot".to_owned(),
+        title: "fire".to_owned(),
+        stickers: vec![sticker],
+        sticker_type: Some(StickerType::CustomEmoji),
+        needs_repainting: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&set)?,
+        serde_json::json!({
+            "user_id": 60,
+            "name": "fire_by_test_bot",
+            "title": "fire",
+            "stickers": [{
+                "sticker": "file-id",
+                "format": "video",
+                "emoji_list": ["🔥", "👍"],
+                "keywords": ["fire"]
+            }],
+            "sticker_type": "custom_emoji"
+        })
+    );
+
+    let sticker: Sticker = serde_json::from_str(
+        r#"{
+            "file_id": "file-id",
+            "file_unique_id": "unique-id",
+            "type": "custom_emoji",
+            "width": 100,
+            "height": 100,
+            "is_animated": false,
+            "is_video": true,
+            "custom_emoji_id": "5368324170671202286",
+            "needs_repainting": true,
+            "thumbnail": {"file_id": "thumb-id", "file_unique_id": "thumb", "width": 10, "height": 10}
+        }"#,
+    )?;
+    assert_eq!(sticker.sticker_type, StickerType::CustomEmoji);
+    assert!(sticker.is_video && sticker.needs_repainting);
+    assert_eq!(
+        sticker.thumb.map(|t| t.file_id).as_deref(),
+        Some("thumb-id")
+    );
+
+    let set: StickerSet = serde_json::from_str(
+        r#"{
+            "name": "fire_by_test_bot",
+            "title": "fire",
+            "sticker_type": "mask",
+            "stickers": [],
+            "thumbnail": {"file_id": "set-thumb-id", "file_unique_id": "thumb", "width": 10, "height": 10}
+        }"#,
+    )?;
+    assert_eq!(set.sticker_type, StickerType::Mask);
+    assert!(!set.contains_masks);
+    assert_eq!(
+        set.thumb.map(|t| t.file_id).as_deref(),
+        Some("set-thumb-id")
+    );
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn convert_deprecated_sticker_types() {
+    let set = CreateNewStickerSet::png_sticker(60, "fire_by_test_bot", "fire", "file-id", "🔥");
+    assert_eq!(
+        set,
+        CreateNewStickerSet::new(
+            60,
+            "fire_by_test_bot",
+            "fire",
+            vec![InputSticker::new("file-id", StickerFormat::Static, &["🔥"])]
+        )
+    );
+
+    let added = AddStickerToSet::tgs_sticker(60, "fire_by_test_bot", "file-id", "🔥");
+    assert_eq!(added.sticker.format, StickerFormat::Animated);
+
+    let thumbnail: SetStickerSetThumbnail = telexide_fork::api::types::SetStickerSetThumb {
+        name: "fire_by_test_bot".to_owned(),
+        user_id: 60,
+        thumb: Some(InputFile::String("thumb-id".to_owned())),
+    }
+    .into();
+    assert_eq!(thumbnail.format, StickerFormat::Static);
+    assert_eq!(
+        thumbnail.thumbnail,
+        Some(InputFile::String("thumb-id".to_owned()))
+    );
+}
+
+#[test]
+fn encode_reply_parameters() -> serde_json::Result<()> {
+    let mut reply = ReplyParameters::new(30);
+    reply.chat_id = Some(-100);
+    reply.set_quote("hello");
+
+    let mut message = SendMessage::new(60, "hi");
+    message
+        .set_reply_parameters(reply)
+        .set_link_preview_options(LinkPreviewOptions::disabled())
+        .toggle_protect_content();
+
+    let value = serde_json::to_value(&message)?;
+    assert_eq!(
+        value["reply_parameters"],
+        serde_json::json!({"message_id": 30, "chat_id": -100, "quote": "hello"})
+    );
+    assert_eq!(value["link_preview_options"]["is_disabled"], true);
+    assert_eq!(value["protect_content"], true);
+    assert!(value.get("message_effect_id").is_none());
+    Ok(())
+}
+
+#[test]
+fn decode_external_reply() -> serde_json::Result<()> {
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 31,
+            "date": 1585772722,
+            "chat": {"id": 60, "type": "private", "first_name": "test"},
+            "text": "see https://example.com",
+            "has_protected_content": true,
+            "link_preview_options": {"url": "https://example.com", "show_above_text": true},
+            "external_reply": {
+                "origin": {
+                    "type": "channel",
+                    "date": 1585772000,
+                    "chat": {"id": -100, "type": "channel", "title": "news"},
+                    "message_id": 12
+                },
+                "chat": {"id": -100, "type": "channel", "title": "news"},
+                "message_id": 12,
+                "has_media_spoiler": true,
+                "photo": [{"file_id": "a", "file_unique_id": "b", "width": 1, "height": 1}]
+            },
+            "quote": {"text": "hello", "position": 4, "is_manual": true}
+        }"#,
+    )?;
+
+    assert!(message.has_protected_content);
+    let options = message
+        .link_preview_options
+        .expect("no link preview options");
+    assert_eq!(options.url.as_deref(), Some("https://example.com"));
+    assert!(options.show_above_text && !options.is_disabled);
+
+    let reply = message.external_reply.expect("no external reply");
+    assert!(matches!(
+        reply.origin,
+        MessageOrigin::Channel { message_id: 12, .. }
+    ));
+    assert!(reply.has_media_spoiler);
+    assert_eq!(reply.photo.map(|p| p.len()), Some(1));
+
+    let quote = message.quote.expect("no quote");
+    assert_eq!(quote.text, "hello");
+    assert!(quote.is_manual);
+    Ok(())
+}
+
+#[test]
+fn encode_request_buttons() -> serde_json::Result<()> {
+    let mut users = KeyboardButtonRequestUsers::new(1);
+    users.user_is_bot = Some(false);
+    users.max_quantity = Some(3);
+    users.request_name = true;
+    assert_eq!(
+        serde_json::to_value(KeyboardButton::request_users("users", users))?,
+        serde_json::json!({
+            "text": "users",
+            "request_users": {
+                "request_id": 1,
+                "user_is_bot": false,
+                "max_quantity": 3,
+                "request_name": true,
+                "request_username": false,
+                "request_photo": false
+            },
+            "request_contact": false,
+            "request_location": false
+        })
+    );
+
+    let mut chat = KeyboardButtonRequestChat::new(2, false);
+    chat.user_administrator_rights = Some(ChatAdministratorRights {
+        can_invite_users: true,
+        ..ChatAdministratorRights::default()
+    });
+    chat.bot_is_member = true;
+    let value = serde_json::to_value(KeyboardButton::request_chat("chat", chat))?;
+    assert_eq!(value["request_chat"]["chat_is_channel"], false);
+    assert_eq!(
+        value["request_chat"]["user_administrator_rights"]["can_invite_users"],
+        true
+    );
+    assert_eq!(value["request_chat"]["bot_is_member"], true);
+    assert!(value["request_chat"].get("chat_is_forum").is_none());
+    assert!(value.get("request_users").is_none());
+    Ok(())
+}
+
+#[test]
+fn decode_shared_peers() -> serde_json::Result<()> {
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 32,
+            "date": 1585772722,
+            "chat": {"id": 60, "type": "private", "first_name": "test"},
+            "users_shared": {
+                "request_id": 1,
+                "users": [{"user_id": 61, "first_name": "a"}, {"user_id": 62}]
+            }
+        }"#,
+    )?;
+    if let MessageContent::UsersShared { content } = message.content {
+        assert_eq!(content.request_id, 1);
+        assert_eq!(content.users.len(), 2);
+        assert_eq!(content.users[0].first_name.as_deref(), Some("a"));
+        assert_eq!(content.users[1].user_id, 62);
+    } else {
+        panic!("no users shared")
+    }
+
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 33,
+            "date": 1585772722,
+            "chat": {"id": 60, "type": "private", "first_name": "test"},
+            "chat_shared": {"request_id": 2, "chat_id": -100, "title": "group"}
+        }"#,
+    )?;
+    if let MessageContent::ChatShared { content } = message.content {
+        assert_eq!(content.chat_id, -100);
+        assert_eq!(content.title.as_deref(), Some("group"));
+        assert!(content.photo.is_none());
+    } else {
+        panic!("no chat shared")
+    }
+    Ok(())
+}
+
+#[test]
+fn encode_star_payments() -> serde_json::Result<()> {
+    let invoice = SendInvoice::stars(60, "pack", "a sticker pack", "pack-1", 50);
+    let value = serde_json::to_value(&invoice)?;
+    assert_eq!(value["currency"], "XTR");
+    assert_eq!(
+        value["prices"],
+        serde_json::json!([{"label": "pack", "amount": 50}])
+    );
+    assert!(value.get("provider_token").is_none());
+
+    let mut paid = SendPaidMedia::new(
+        60,
+        25,
+        vec![InputPaidMedia::Photo(InputPaidMediaPhoto {
+            media: InputFile::new("photo-id"),
+        })],
+    );
+    paid.payload = Some("photo-1".to_owned());
+    assert_eq!(
+        serde_json::to_value(&paid)?["media"],
+        serde_json::json!([{"type": "photo", "media": "photo-id"}])
+    );
+    Ok(())
+}
+
+#[test]
+fn decode_star_payments() -> serde_json::Result<()> {
+    let payment: SuccessfulPayment = serde_json::from_str(
+        r#"{
+            "currency": "XTR",
+            "total_amount": 50,
+            "invoice_payload": "sub-1",
+            "subscription_expiration_date": 1588364722,
+            "is_recurring": true,
+            "is_first_recurring": true,
+            "telegram_payment_charge_id": "charge",
+            "provider_payment_charge_id": ""
+        }"#,
+    )?;
+    assert!(payment.subscription_expiration_date.is_some());
+    assert!(payment.is_recurring && payment.is_first_recurring);
+
+    let transactions: StarTransactions = serde_json::from_str(
+        r#"{"transactions": [
+            {
+                "id": "charge",
+                "amount": 50,
+                "date": 1585772722,
+                "source": {
+                    "type": "user",
+                    "user": {"id": 60, "is_bot": false, "first_name": "test"},
+                    "invoice_payload": "sub-1",
+                    "subscription_period": 2592000
+                }
+            },
+            {
+                "id": "withdrawal",
+                "amount": 1000,
+                "date": 1585772800,
+                "receiver": {
+                    "type": "fragment",
+                    "withdrawal_state": {
+                        "type": "succeeded",
+                        "date": 1585772900,
+                        "url": "https://fragment.com"
+                    }
+                }
+            },
+            {"id": "ads", "amount": 5, "date": 1585773000, "receiver": {"type": "telegram_ads"}}
+        ]}"#,
+    )?;
+    let transactions = transactions.transactions;
+    assert!(matches!(
+        transactions[0].source,
+        Some(TransactionPartner::User {
+            subscription_period: Some(2_592_000),
+            ..
+        })
+    ));
+    assert!(matches!(
+        transactions[1].receiver,
+        Some(TransactionPartner::Fragment {
+            withdrawal_state: Some(RevenueWithdrawalState::Succeeded { .. })
+        })
+    ));
+    assert_eq!(
+        transactions[2].receiver,
+        Some(TransactionPartner::TelegramAds)
+    );
+
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 34,
+            "date": 1585772722,
+            "chat": {"id": 60, "type": "private", "first_name": "test"},
+            "caption": "unlock",
+            "paid_media": {
+                "star_count": 25,
+                "paid_media": [{"type": "preview", "width": 100, "height": 100}]
+            }
+        }"#,
+    )?;
+    assert_eq!(message.get_text().as_deref(), Some("unlock"));
+    if let MessageContent::PaidMedia { content, .. } = message.content {
+        assert_eq!(content.star_count, 25);
+        assert_eq!(
+            content.paid_media,
+            vec![PaidMedia::Preview {
+                width: Some(100),
+                height: Some(100),
+                duration: None,
+            }]
+        );
+    } else {
+        panic!("no paid media")
+    }
+    Ok(())
+}
+
+#[test]
+fn decode_business_updates() -> serde_json::Result<()> {
+    let update: Update = serde_json::from_str(
+        r#"{
+            "update_id": 1,
+            "business_connection": {
+                "id": "conn",
+                "user": {"id": 60, "is_bot": false, "first_name": "shop"},
+                "user_chat_id": 60,
+                "date": 1585772722,
+                "can_reply": true,
+                "is_enabled": true
+            }
+        }"#,
+    )?;
+    if let UpdateContent::BusinessConnection(connection) = update.content {
+        assert_eq!(connection.id, "conn");
+        assert!(connection.can_reply && connection.is_enabled);
+    } else {
+        panic!("no business connection")
+    }
+
+    let update: Update = serde_json::from_str(
+        r#"{
+            "update_id": 2,
+            "business_message": {
+                "message_id": 35,
+                "date": 1585772722,
+                "business_connection_id": "conn",
+                "chat": {"id": 61, "type": "private", "first_name": "customer"},
+                "text": "hello"
+            }
+        }"#,
+    )?;
+    let message = if let UpdateContent::BusinessMessage(message) = update.content {
+        message
+    } else {
+        panic!("no business message")
+    };
+    assert_eq!(message.business_connection_id.as_deref(), Some("conn"));
+
+    let mut reply = SendMessage::new(61, "hi");
+    reply.reply_to_message(&message);
+    assert_eq!(
+        serde_json::to_value(&reply)?["business_connection_id"],
+        "conn"
+    );
+    let edit = EditMessageText::from_message(&message, "hi");
+    assert_eq!(edit.business_connection_id.as_deref(), Some("conn"));
+    assert!(serde_json::to_value(SendMessage::new(61, "hi"))?
+        .get("business_connection_id")
+        .is_none());
+
+    let update: Update = serde_json::from_str(
+        r#"{
+            "update_id": 3,
+            "deleted_business_messages": {
+                "business_connection_id": "conn",
+                "chat": {"id": 61, "type": "private", "first_name": "customer"},
+                "message_ids": [35, 36]
+            }
+        }"#,
+    )?;
+    if let UpdateContent::DeletedBusinessMessages(deleted) = update.content.clone() {
+        assert_eq!(deleted.message_ids, vec![35, 36]);
+    } else {
+        panic!("no deleted business messages")
+    }
+    let value = serde_json::to_value(&update)?;
+    assert_eq!(
+        value["deleted_business_messages"]["business_connection_id"],
+        "conn"
+    );
+    Ok(())
+}
+
+#[test]
+fn decode_inline_callback_query() -> serde_json::Result<()> {
+    let query: CallbackQuery = serde_json::from_str(
+        r#"{
+            "id": "query",
+            "from": {"id": 60, "is_bot": false, "first_name": "x"},
+            "inline_message_id": "inline",
+            "chat_instance": "instance",
+            "data": "page:2"
+        }"#,
+    )?;
+    assert_eq!(query.message, None);
+
+    let id = query.inline_message_id.as_deref().unwrap_or_default();
+    let edit = serde_json::to_value(EditMessageReplyMarkup::from_inline_message(id, None))?;
+    assert_eq!(edit, serde_json::json!({"inline_message_id": "inline"}));
+    let edit = serde_json::to_value(EditMessageText::from_inline_message(id, "hi"))?;
+    assert_eq!(edit["inline_message_id"], "inline");
+    assert!(edit.get("chat_id").is_none());
+    Ok(())
+}
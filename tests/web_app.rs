@@ -0,0 +1,174 @@
+use chrono::{TimeZone, Utc};
+use std::time::Duration;
+use telexide_fork::{
+    api::types::SetChatMenuButton,
+    model::{
+        ChatType, InlineKeyboardButton, KeyboardButton, MenuButton, Message, MessageContent,
+        WebAppInfo,
+    },
+    web_app::{InitDataValidator, WebAppError, WebAppInitData},
+    Error, Result,
+};
+
+const TOKEN: &str = "123456:TEST-token";
+const BOT_ID: i64 = 123_456;
+const PUBLIC_KEY: &str = "03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8";
+
+const INIT_DATA: &str = "query_id=AAHdF6IQAAAAAN0XohDhrOrc&user=%7B%22id%22%3A60%2C%22first_name%\
+                         22%3A%22T%C3%ABst%22%2C%22last_name%22%3A%22User%22%2C%22username%22%3A%\
+                         22test_user%22%2C%22language_code%22%3A%22en%22%2C%22is_premium%22%3Atru\
+                         e%2C%22allows_write_to_pm%22%3Atrue%7D&auth_date=1700000000&chat_type=se\
+                         nder&chat_instance=-3788475317572404878&start_param=ref%2042&signature=m\
+                         14X68rSYBWwWwJw5wem2RyX1w7GDjr_MM1GRXYFHfz3Icmjpb64DgCcgoHTXepH8nFJm2NyY\
+                         toSbzXe_oLfCg&hash=0053291214e6d9eed9d42d928fe87a9e8ab75a91dae88b0b6b401\
+                         94f8af06969";
+
+fn public_key() -> Vec<u8> {
+    (0..PUBLIC_KEY.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&PUBLIC_KEY[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn web_app_error(result: Result<WebAppInitData>) -> WebAppError {
+    match result {
+        Err(Error::WebApp(e)) => e,
+        other => panic!("expected a web app error, got {:?}", other),
+    }
+}
+
+#[test]
+fn validate_init_data_hash() -> Result<()> {
+    let data = InitDataValidator::new(TOKEN).validate(INIT_DATA)?;
+
+    let user = data.user.expect("the init data has a user");
+    assert_eq!(user.id, 60);
+    assert_eq!(user.first_name, "Tëst");
+    assert!(user.is_premium);
+    assert!(!user.added_to_attachment_menu);
+    assert_eq!(data.query_id.as_deref(), Some("AAHdF6IQAAAAAN0XohDhrOrc"));
+    assert_eq!(data.chat_type, Some(ChatType::Sender));
+    assert_eq!(data.start_param.as_deref(), Some("ref 42"));
+    assert_eq!(data.auth_date, Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+
+    assert_eq!(
+        web_app_error(InitDataValidator::new("123456:other-token").validate(INIT_DATA)),
+        WebAppError::InvalidHash
+    );
+    assert_eq!(
+        web_app_error(
+            InitDataValidator::new(TOKEN).validate(&INIT_DATA.replace("ref%2042", "ref%2043"))
+        ),
+        WebAppError::InvalidHash
+    );
+    assert_eq!(
+        web_app_error(InitDataValidator::new(TOKEN).validate("auth_date=1700000000")),
+        WebAppError::MissingHash
+    );
+    assert_eq!(
+        web_app_error(InitDataValidator::new(TOKEN).validate("user=%7B&hash=00")),
+        WebAppError::InvalidHash
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_init_data_signature() -> Result<()> {
+    let data = InitDataValidator::with_public_key(BOT_ID, &public_key()).validate(INIT_DATA)?;
+    assert_eq!(data.user.map(|u| u.id), Some(60));
+
+    let without_hash = INIT_DATA.split("&hash=").next().unwrap();
+    InitDataValidator::with_public_key(BOT_ID, &public_key()).validate(without_hash)?;
+
+    assert_eq!(
+        web_app_error(InitDataValidator::with_public_key(1, &public_key()).validate(INIT_DATA)),
+        WebAppError::InvalidSignature
+    );
+    assert_eq!(
+        web_app_error(InitDataValidator::third_party(BOT_ID).validate(INIT_DATA)),
+        WebAppError::InvalidSignature
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_init_data_age() -> Result<()> {
+    let opened = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let mut validator = InitDataValidator::new(TOKEN);
+    validator.set_max_age(Duration::from_secs(60));
+
+    validator.validate_at(INIT_DATA, opened + chrono::Duration::seconds(60))?;
+    assert_eq!(
+        web_app_error(validator.validate_at(INIT_DATA, opened + chrono::Duration::seconds(61))),
+        WebAppError::Expired
+    );
+    // the clocks can differ a bit
+    validator.validate_at(INIT_DATA, opened - chrono::Duration::seconds(5))?;
+    assert_eq!(
+        web_app_error(validator.validate_at(INIT_DATA, opened - chrono::Duration::seconds(6))),
+        WebAppError::FutureAuthDate
+    );
+
+    // init data from the future is rejected without a max age as well
+    assert_eq!(
+        web_app_error(
+            InitDataValidator::new(TOKEN)
+                .validate_at(INIT_DATA, opened - chrono::Duration::seconds(60))
+        ),
+        WebAppError::FutureAuthDate
+    );
+    Ok(())
+}
+
+#[test]
+fn encode_web_app_types() -> serde_json::Result<()> {
+    let button = InlineKeyboardButton::web_app("open", "https://example.com/app");
+    assert_eq!(
+        serde_json::to_value(&button)?,
+        serde_json::json!({"text": "open", "web_app": {"url": "https://example.com/app"}})
+    );
+    let button = KeyboardButton::web_app("open", "https://example.com/app");
+    assert_eq!(
+        button.web_app,
+        Some(WebAppInfo::new("https://example.com/app"))
+    );
+
+    let menu = SetChatMenuButton {
+        chat_id: Some(60),
+        menu_button: Some(MenuButton::WebApp {
+            text: "open".to_owned(),
+            web_app: WebAppInfo::new("https://example.com/app"),
+        }),
+    };
+    assert_eq!(
+        serde_json::to_value(&menu)?,
+        serde_json::json!({
+            "chat_id": 60,
+            "menu_button": {
+                "type": "web_app",
+                "text": "open",
+                "web_app": {"url": "https://example.com/app"}
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::from_str::<MenuButton>(r#"{"type": "commands"}"#)?,
+        MenuButton::Commands
+    );
+
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 30,
+            "date": 1585772722,
+            "chat": {"id": 60, "type": "private", "first_name": "test"},
+            "web_app_data": {"data": "{\"order\":1}", "button_text": "open"}
+        }"#,
+    )?;
+    if let MessageContent::WebAppData { content } = message.content {
+        assert_eq!(content.data, r#"{"order":1}"#);
+        assert_eq!(content.button_text, "open");
+    } else {
+        panic!("no web app data")
+    }
+    Ok(())
+}